@@ -0,0 +1,71 @@
+//! Number of heap allocations made by detection of medium-length texts, counted with
+//! a global allocator. It prints the numbers rather than timing anything:
+//!
+//! ```text
+//! cargo bench --bench allocations
+//! ```
+
+extern crate whatlang;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use whatlang::detect;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// Sentences of medium length, scored with trigrams of all languages of their script.
+const TEXTS: [(&str, &str); 4] = [
+    (
+        "english",
+        "I think that it is going to rain today, so you should take an umbrella.",
+    ),
+    (
+        "spanish",
+        "Además de todo lo anteriormente dicho, también encontramos un problema grave.",
+    ),
+    (
+        "polish",
+        "Myślę, że dzisiaj będzie padać, więc powinieneś wziąć ze sobą parasol.",
+    ),
+    (
+        "russian",
+        "Я думаю, что сегодня будет дождь, так что тебе стоит взять зонт.",
+    ),
+];
+
+// Allocations and reallocations made by a single detection of the text.
+fn count_allocations(text: &str) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let info = detect(text);
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+    assert!(info.is_some());
+    after - before
+}
+
+fn main() {
+    for &(name, text) in TEXTS.iter() {
+        println!("{:<10} {:>4} allocations", name, count_allocations(text));
+    }
+}
@@ -0,0 +1,51 @@
+//! Alphabet scoring alone, without trigrams. It needs the `dev` feature:
+//!
+//! ```text
+//! cargo bench --features dev --bench alphabets
+//! ```
+
+#[macro_use]
+extern crate bencher;
+extern crate whatlang;
+
+use bencher::Bencher;
+use whatlang::dev::{detect_with_options, Method, Options};
+
+// Short texts of languages sharing a script, where alphabets of all candidates are scored
+const SHORT_TEXTS: [&str; 7] = [
+    "Zażółć gęślą jaźń",
+    "Příliš žluťoučký kůň",
+    "Árvíztűrő tükörfúrógép",
+    "Jeg bor i Oslo",
+    "Їжак і єнот",
+    "Съешь же ещё",
+    "װי גײט עס",
+];
+
+const LATIN_PARAGRAPH: &str = "Wszyscy ludzie rodzą się wolni i równi pod względem swej \
+    godności i swych praw. Są oni obdarzeni rozumem i sumieniem i powinni postępować wobec \
+    innych w duchu braterstwa. Każdy człowiek posiada wszystkie prawa i wolności zawarte \
+    w niniejszej Deklaracji bez względu na jakiekolwiek różnice rasy, koloru skóry, płci, \
+    języka, wyznania, poglądów politycznych i innych, narodowości, pochodzenia społecznego, \
+    majątku, urodzenia lub jakiegokolwiek innego stanu.";
+
+fn bench_alphabets_short_texts(bench: &mut Bencher) {
+    let options = Options::new().set_method(Method::Alphabet);
+    bench.iter(|| {
+        for text in SHORT_TEXTS.iter() {
+            detect_with_options(text, &options);
+        }
+    })
+}
+
+fn bench_alphabets_latin_paragraph(bench: &mut Bencher) {
+    let options = Options::new().set_method(Method::Alphabet);
+    bench.iter(|| detect_with_options(LATIN_PARAGRAPH, &options))
+}
+
+benchmark_group!(
+    benches,
+    bench_alphabets_short_texts,
+    bench_alphabets_latin_paragraph
+);
+benchmark_main!(benches);
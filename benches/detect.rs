@@ -0,0 +1,105 @@
+#[macro_use]
+extern crate bencher;
+extern crate whatlang;
+
+use bencher::Bencher;
+use whatlang::{detect, detect_with_options, Options};
+
+// Representative inputs of different length: a word, a sentence and a paragraph.
+const ENGLISH_WORD: &str = "weather";
+const ENGLISH_SENTENCE: &str =
+    "I think that it is going to rain today, so you should take an umbrella.";
+const ENGLISH_PARAGRAPH: &str = "All human beings are born free and equal in dignity and rights. \
+    They are endowed with reason and conscience and should act towards one another in a spirit \
+    of brotherhood. Everyone is entitled to all the rights and freedoms set forth in this \
+    Declaration, without distinction of any kind, such as race, colour, sex, language, religion, \
+    political or other opinion, national or social origin, property, birth or other status.";
+
+// Sentences of medium length in languages of the Latin script, which has the most languages
+// to compare trigrams with.
+const LATIN_SENTENCES: [&str; 4] = [
+    "Además de todo lo anteriormente dicho, también encontramos un problema grave.",
+    "Ich möchte heute Abend ins Theater gehen, aber ich bin wirklich zu müde.",
+    "Je pense qu'il va pleuvoir aujourd'hui, alors tu devrais prendre un parapluie.",
+    "Myślę, że dzisiaj będzie padać, więc powinieneś wziąć ze sobą parasol.",
+];
+
+const RUSSIAN_WORD: &str = "погода";
+const RUSSIAN_SENTENCE: &str = "Я думаю, что сегодня будет дождь, так что тебе стоит взять зонт.";
+const RUSSIAN_PARAGRAPH: &str = "Все люди рождаются свободными и равными в своем достоинстве \
+    и правах. Они наделены разумом и совестью и должны поступать в отношении друг друга в духе \
+    братства. Каждый человек должен обладать всеми правами и всеми свободами, провозглашенными \
+    настоящей Декларацией, без какого бы то ни было различия, как-то в отношении расы, цвета \
+    кожи, пола, языка, религии, политических или иных убеждений, национального или социального \
+    происхождения, имущественного, сословного или иного положения.";
+
+const CHINESE_WORD: &str = "天气";
+const CHINESE_SENTENCE: &str = "我觉得今天会下雨，所以你应该带一把伞。";
+const CHINESE_PARAGRAPH: &str = "人人生而自由，在尊严和权利上一律平等。他们赋有理性和良心，\
+    并应以兄弟关系的精神相对待。人人有资格享有本宣言所载的一切权利和自由，不分种族、肤色、性别、\
+    语言、宗教、政治或其他见解、国籍或社会出身、财产、出生或其他身分等任何区别。";
+
+fn bench_detect_english_word(bench: &mut Bencher) {
+    bench.iter(|| detect(ENGLISH_WORD))
+}
+
+fn bench_detect_english_sentence(bench: &mut Bencher) {
+    bench.iter(|| detect(ENGLISH_SENTENCE))
+}
+
+fn bench_detect_english_paragraph(bench: &mut Bencher) {
+    bench.iter(|| detect(ENGLISH_PARAGRAPH))
+}
+
+fn bench_detect_english_paragraph_with_max_candidates(bench: &mut Bencher) {
+    let options = Options::new().set_max_candidates(5);
+    bench.iter(|| detect_with_options(ENGLISH_PARAGRAPH, &options))
+}
+
+fn bench_detect_latin_sentences(bench: &mut Bencher) {
+    bench.iter(|| {
+        for sentence in LATIN_SENTENCES.iter() {
+            detect(sentence);
+        }
+    })
+}
+
+fn bench_detect_russian_word(bench: &mut Bencher) {
+    bench.iter(|| detect(RUSSIAN_WORD))
+}
+
+fn bench_detect_russian_sentence(bench: &mut Bencher) {
+    bench.iter(|| detect(RUSSIAN_SENTENCE))
+}
+
+fn bench_detect_russian_paragraph(bench: &mut Bencher) {
+    bench.iter(|| detect(RUSSIAN_PARAGRAPH))
+}
+
+fn bench_detect_chinese_word(bench: &mut Bencher) {
+    bench.iter(|| detect(CHINESE_WORD))
+}
+
+fn bench_detect_chinese_sentence(bench: &mut Bencher) {
+    bench.iter(|| detect(CHINESE_SENTENCE))
+}
+
+fn bench_detect_chinese_paragraph(bench: &mut Bencher) {
+    bench.iter(|| detect(CHINESE_PARAGRAPH))
+}
+
+benchmark_group!(
+    benches,
+    bench_detect_english_word,
+    bench_detect_english_sentence,
+    bench_detect_english_paragraph,
+    bench_detect_english_paragraph_with_max_candidates,
+    bench_detect_latin_sentences,
+    bench_detect_russian_word,
+    bench_detect_russian_sentence,
+    bench_detect_russian_paragraph,
+    bench_detect_chinese_word,
+    bench_detect_chinese_sentence,
+    bench_detect_chinese_paragraph
+);
+benchmark_main!(benches);
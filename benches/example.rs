@@ -5,7 +5,9 @@ extern crate whatlang;
 
 use bencher::Bencher;
 use std::collections::HashMap;
-use whatlang::{detect, detect_script};
+use whatlang::{
+    detect, detect_batch, detect_script, detect_with_options, Detector, FilterList, Lang, Options,
+};
 
 fn bench_detect(bench: &mut Bencher) {
     let example_data = include_str!("../tests/examples.json");
@@ -18,6 +20,112 @@ fn bench_detect(bench: &mut Bencher) {
     })
 }
 
+fn bench_detect_batch(bench: &mut Bencher) {
+    let example_data = include_str!("../tests/examples.json");
+    let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
+    let texts: Vec<&str> = examples.values().map(|text| text.as_str()).collect();
+    let options = Options::default();
+
+    bench.iter(|| detect_batch(&texts, &options))
+}
+
+// The same texts as `bench_detect_batch`, detected one by one
+fn bench_detect_batch_naive(bench: &mut Bencher) {
+    let example_data = include_str!("../tests/examples.json");
+    let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
+    let texts: Vec<&str> = examples.values().map(|text| text.as_str()).collect();
+    let options = Options::default();
+
+    bench.iter(|| {
+        texts
+            .iter()
+            .map(|text| detect_with_options(text, &options))
+            .collect::<Vec<_>>()
+    })
+}
+
+fn bench_detector_with_denylist(bench: &mut Bencher) {
+    let example_data = include_str!("../tests/examples.json");
+    let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
+    let denylist = vec![Lang::Nob, Lang::Dan, Lang::Jav, Lang::Ind, Lang::Aka];
+    let detector = Detector::with_denylist(denylist);
+
+    bench.iter(|| {
+        for text in examples.values() {
+            detector.detect(text);
+        }
+    })
+}
+
+// The same as `bench_detector_with_denylist`, but the options are built for every text
+fn bench_detect_with_denylist_options_per_call(bench: &mut Bencher) {
+    let example_data = include_str!("../tests/examples.json");
+    let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
+    let denylist = vec![Lang::Nob, Lang::Dan, Lang::Jav, Lang::Ind, Lang::Aka];
+
+    bench.iter(|| {
+        for text in examples.values() {
+            let options = Options::new().set_filter_list(FilterList::deny(denylist.clone()));
+            detect_with_options(text, &options);
+        }
+    })
+}
+
+// 10k lines of English text
+fn english_corpus() -> Vec<String> {
+    let sentences = [
+        "I think that it is going to rain today, so you should take an umbrella with you.",
+        "The committee has decided to postpone the meeting until the end of the month.",
+        "She was walking along the river when she noticed a small boat drifting away.",
+        "Please let me know if you have any questions about the report for last quarter.",
+        "All human beings are born free and equal in dignity and rights.",
+    ];
+    (0..10_000)
+        .map(|i| format!("{} (line {})", sentences[i % sentences.len()], i))
+        .collect()
+}
+
+fn bench_english_corpus(bench: &mut Bencher) {
+    let lines = english_corpus();
+    let options = Options::default();
+
+    bench.iter(|| {
+        for line in &lines {
+            detect_with_options(line, &options);
+        }
+    })
+}
+
+fn bench_english_corpus_fast_ascii(bench: &mut Bencher) {
+    let lines = english_corpus();
+    let options = Options::new().set_fast_ascii(true);
+
+    bench.iter(|| {
+        for line in &lines {
+            detect_with_options(line, &options);
+        }
+    })
+}
+
+// Short texts of languages sharing a script, where alphabets of all candidates are scored
+fn bench_detect_short_multi_script(bench: &mut Bencher) {
+    let texts = [
+        "Zażółć gęślą jaźń",
+        "Příliš žluťoučký kůň",
+        "Árvíztűrő tükörfúrógép",
+        "Jeg bor i Oslo",
+        "Їжак і єнот",
+        "Съешь же ещё",
+        "װי גײט עס",
+    ];
+
+    bench.iter(|| {
+        for text in texts.iter() {
+            detect(text);
+        }
+    })
+}
+
 fn bench_detect_script(bench: &mut Bencher) {
     let example_data = include_str!("../tests/examples.json");
     let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
@@ -29,5 +137,16 @@ fn bench_detect_script(bench: &mut Bencher) {
     })
 }
 
-benchmark_group!(benches, bench_detect, bench_detect_script);
+benchmark_group!(
+    benches,
+    bench_detect,
+    bench_detect_batch,
+    bench_detect_batch_naive,
+    bench_detector_with_denylist,
+    bench_detect_with_denylist_options_per_call,
+    bench_english_corpus,
+    bench_english_corpus_fast_ascii,
+    bench_detect_short_multi_script,
+    bench_detect_script
+);
 benchmark_main!(benches);
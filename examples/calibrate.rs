@@ -0,0 +1,166 @@
+//! Reports accuracy of detection over a labeled corpus, to calibrate confidence.
+//!
+//! The corpus is a TSV file with a text and the code of its language (e.g. `eng`)
+//! separated by a tab on every line:
+//!
+//! ```text
+//! There is no reason not to learn Esperanto.<TAB>eng
+//! Та нічого, все нормально. А в тебе як?<TAB>ukr
+//! ```
+//!
+//! Run it with:
+//!
+//! ```text
+//! cargo run --release --example calibrate -- corpus.tsv
+//! ```
+//!
+//! It prints the confusion matrix (for every expected language, how many texts are detected
+//! as every other language or not detected at all) and the calibration curve: texts are grouped
+//! into buckets by confidence, and the accuracy of every bucket is shown next to its average
+//! confidence. For a well calibrated detector they are close.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::process;
+
+use whatlang::{detect, Lang};
+
+const BUCKETS: usize = 10;
+
+#[derive(Default, Clone, Copy)]
+struct Bucket {
+    total: usize,
+    correct: usize,
+    confidence_sum: f64,
+}
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: cargo run --release --example calibrate -- <corpus.tsv>");
+            process::exit(1);
+        }
+    };
+    let corpus = fs::read_to_string(&path).unwrap_or_else(|err| {
+        eprintln!("Failed to read {}: {}", path, err);
+        process::exit(1);
+    });
+
+    // Expected language -> detected language (None if nothing is detected) -> number of texts
+    let mut confusion: BTreeMap<Lang, BTreeMap<Option<Lang>, usize>> = BTreeMap::new();
+    let mut buckets = [Bucket::default(); BUCKETS];
+
+    for (line_number, line) in corpus.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (text, code) = match line.rsplit_once('\t') {
+            Some(pair) => pair,
+            None => {
+                eprintln!("Skip line {}: no tab", line_number + 1);
+                continue;
+            }
+        };
+        let expected = match Lang::from_code(code.trim()) {
+            Some(lang) => lang,
+            None => {
+                eprintln!("Skip line {}: unknown language {:?}", line_number + 1, code);
+                continue;
+            }
+        };
+
+        let info = detect(text);
+        let detected = info.as_ref().map(|info| info.lang());
+        *confusion
+            .entry(expected)
+            .or_default()
+            .entry(detected)
+            .or_default() += 1;
+
+        if let Some(info) = info {
+            let confidence = info.confidence().value();
+            let index = ((confidence * BUCKETS as f64) as usize).min(BUCKETS - 1);
+            let bucket = &mut buckets[index];
+            bucket.total += 1;
+            bucket.confidence_sum += confidence;
+            if info.lang() == expected {
+                bucket.correct += 1;
+            }
+        }
+    }
+
+    print_confusion(&confusion);
+    println!();
+    print_calibration(&buckets);
+}
+
+fn print_confusion(confusion: &BTreeMap<Lang, BTreeMap<Option<Lang>, usize>>) {
+    println!("Confusion matrix (expected: accuracy, detected languages):");
+    let (mut total, mut correct) = (0, 0);
+    for (&expected, detections) in confusion {
+        let lang_total: usize = detections.values().sum();
+        let lang_correct = detections.get(&Some(expected)).copied().unwrap_or(0);
+        total += lang_total;
+        correct += lang_correct;
+
+        // The most frequent mistakes go first
+        let mut mistakes: Vec<(Option<Lang>, usize)> = detections
+            .iter()
+            .filter(|&(&detected, _)| detected != Some(expected))
+            .map(|(&detected, &count)| (detected, count))
+            .collect();
+        mistakes.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        let mistakes: Vec<String> = mistakes
+            .iter()
+            .map(|&(detected, count)| {
+                let code = detected.map_or("none", |lang| lang.code());
+                format!("{} {}", code, count)
+            })
+            .collect();
+
+        println!(
+            "{}: {:6.2}% ({}/{}) {}",
+            expected.code(),
+            percent(lang_correct, lang_total),
+            lang_correct,
+            lang_total,
+            mistakes.join(", ")
+        );
+    }
+    println!(
+        "Total: {:.2}% ({}/{})",
+        percent(correct, total),
+        correct,
+        total
+    );
+}
+
+fn print_calibration(buckets: &[Bucket]) {
+    println!("Calibration (confidence: texts, average confidence, accuracy):");
+    for (index, bucket) in buckets.iter().enumerate() {
+        let from = index as f64 / buckets.len() as f64;
+        let to = (index + 1) as f64 / buckets.len() as f64;
+        if bucket.total == 0 {
+            println!("{:.1}..{:.1}: 0", from, to);
+            continue;
+        }
+        println!(
+            "{:.1}..{:.1}: {} {:.3} {:.3}",
+            from,
+            to,
+            bucket.total,
+            bucket.confidence_sum / bucket.total as f64,
+            bucket.correct as f64 / bucket.total as f64
+        );
+    }
+}
+
+fn percent(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / total as f64
+    }
+}
@@ -11,7 +11,7 @@ fn main() {
         .expect("Failed to read line");
 
     if let Some(info) = detect(&text) {
-        println!("Language: {}", info.lang());
+        println!("Language: {}", info.lang().eng_name());
         println!("Info: {:?}", info);
     } else {
         println!("Cannot recognize a language :(");
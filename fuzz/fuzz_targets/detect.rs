@@ -0,0 +1,34 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use whatlang::{detect, detect_with_options, Options};
+
+// The first byte chooses options, the rest is the text (decoded lossily, so malformed
+// UTF-8 gives replacement characters).
+fuzz_target!(|data: &[u8]| {
+    let (flags, bytes) = match data.split_first() {
+        Some((&flags, bytes)) => (flags, bytes),
+        None => (0, data),
+    };
+    let text = String::from_utf8_lossy(bytes);
+
+    if let Some(info) = detect(&text) {
+        check(info.confidence().value());
+    }
+
+    let mut options = Options::new()
+        .set_ignore_digits(flags & 1 != 0)
+        .set_boundary_padding(flags & 2 == 0)
+        .set_fast_ascii(flags & 4 != 0)
+        .set_min_trigram_count(usize::from(flags >> 5));
+    if flags & 8 != 0 {
+        options = options.set_alphabet_weight(f64::from(flags) / 255.0);
+    }
+    if let Some(info) = detect_with_options(&text, &options) {
+        check(info.confidence().value());
+    }
+});
+
+fn check(confidence: f64) {
+    assert!((0.0..=1.0).contains(&confidence), "confidence: {}", confidence);
+}
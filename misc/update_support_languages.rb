@@ -18,10 +18,28 @@ TRIGRAM_COUNT = 300
 OUTPUT_FILE = File.expand_path("../../SUPPORTED_LANGUAGES.md", __FILE__)
 
 IGNORE_LANGS = {
-  # Do not generate cyrillic trigrams for Turkmen and Azerbaijani
-  "Cyrillic" => ["tuk", "aze"]
+  # Do not generate cyrillic trigrams for Turkmen
+  "Cyrillic" => ["tuk"]
 }
 
+# Serbian is written with both Cyrillic and Latin (Latinica), but there are only Cyrillic trigrams,
+# so Latin ones are derived from them. The transliteration is one-to-one except the digraphs
+# (lj, nj, dž), which make a trigram 4 or 5 chars long, so every 3 chars window of it is taken.
+SERBIAN_CYRILLIC_TO_LATIN = {
+  "а" => "a", "б" => "b", "в" => "v", "г" => "g", "д" => "d", "ђ" => "đ", "е" => "e",
+  "ж" => "ž", "з" => "z", "и" => "i", "ј" => "j", "к" => "k", "л" => "l", "љ" => "lj",
+  "м" => "m", "н" => "n", "њ" => "nj", "о" => "o", "п" => "p", "р" => "r", "с" => "s",
+  "т" => "t", "ћ" => "ć", "у" => "u", "ф" => "f", "х" => "h", "ц" => "c", "ч" => "č",
+  "џ" => "dž", "ш" => "š"
+}
+
+def transliterate_trigrams(trigrams, table)
+  trigrams
+    .flat_map { |t| t.chars.map { |c| table[c] || c }.join.chars.each_cons(3).map(&:join) }
+    .uniq
+    .first(TRIGRAM_COUNT)
+end
+
 class Lang
   attr_reader :code, :eng_name, :name, :native_speakers, :script, :trigrams
 
@@ -62,6 +80,13 @@ class Lang
       end
     end
 
+    srp = scripts["Cyrillic"].find { |l| l[:code] == "srp" }
+    scripts["Latin"] << {
+      code: "srp",
+      script: "Latin",
+      trigrams: transliterate_trigrams(srp[:trigrams], SERBIAN_CYRILLIC_TO_LATIN)
+    }
+
     # Filter out scripts with only one language
     scripts.select! {|script, langs| langs.size > 1 }
 
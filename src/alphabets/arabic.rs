@@ -0,0 +1,90 @@
+use super::{calculate_scores, RawOutcome};
+use crate::core::LangSet;
+use crate::{Lang, Script};
+
+// Letters shared by all three languages.
+const LETTERS: &str = "ءآأؤإئابتثجحخدذرزسشصضطظعغفقلمنو";
+
+// Arabic uses teh marbuta, alef maksura, Arabic kaf and yeh, which Persian and Urdu
+// replace with keheh (ک) and farsi yeh (ی).
+const ARA_LETTERS: &str = "ةىكيه";
+
+// Persian adds pe, che, zhe and gaf, keheh and farsi yeh.
+const PES_LETTERS: &str = "پچژگکیه";
+
+// Urdu has all letters of Persian except heh (ه), which is replaced with heh goal (ہ)
+// and heh doachashmee (ھ), and adds retroflex tteh, ddal and rreh, noon ghunna
+// and yeh barree.
+const URD_LETTERS: &str = "پچژگکیٹڈڑںھہۂۃےۓ";
+
+pub fn alphabet_calculate_scores(
+    char_counts: &[(char, i32)],
+    allowed_langs: LangSet,
+) -> RawOutcome {
+    calculate_scores(
+        Script::Arabic,
+        char_counts,
+        allowed_langs,
+        get_lang_chars,
+        is_relevant,
+    )
+}
+
+fn is_relevant(ch: char) -> bool {
+    LETTERS.contains(ch)
+        || ARA_LETTERS.contains(ch)
+        || PES_LETTERS.contains(ch)
+        || URD_LETTERS.contains(ch)
+}
+
+pub(crate) fn get_lang_chars(lang: Lang) -> [&'static str; 2] {
+    match lang {
+        Lang::Ara => [LETTERS, ARA_LETTERS],
+        Lang::Pes => [LETTERS, PES_LETTERS],
+        Lang::Urd => [LETTERS, URD_LETTERS],
+
+        _ => panic!("No alphabet for {}", lang),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabets::char_counts_of;
+
+    fn fetch<T: Copy>(lang: &Lang, scores: &[(Lang, T)]) -> T {
+        scores.iter().find(|(l, _)| l == lang).unwrap().1
+    }
+
+    #[test]
+    fn test_when_common_letters_are_given() {
+        let char_counts = char_counts_of("سلام");
+        let RawOutcome {
+            count, raw_scores, ..
+        } = alphabet_calculate_scores(&char_counts, LangSet::all());
+
+        assert_eq!(count, 4);
+        assert_eq!(fetch(&Lang::Ara, &raw_scores), 4);
+        assert_eq!(fetch(&Lang::Pes, &raw_scores), 4);
+        assert_eq!(fetch(&Lang::Urd, &raw_scores), 4);
+    }
+
+    #[test]
+    fn test_when_persian_specific_chars_are_given() {
+        // "چگونه" - che and gaf are not used in Arabic, heh is not used in Urdu
+        let char_counts = char_counts_of("چگونه");
+        let outcome = alphabet_calculate_scores(&char_counts, LangSet::all());
+        assert_eq!(outcome.raw_scores[0], (Lang::Pes, 5));
+        assert_eq!(fetch(&Lang::Urd, &outcome.raw_scores), 3);
+        assert_eq!(fetch(&Lang::Ara, &outcome.raw_scores), 1);
+    }
+
+    #[test]
+    fn test_when_urdu_specific_chars_are_given() {
+        // "بڑے" - rreh and yeh barree are used only in Urdu
+        let char_counts = char_counts_of("بڑے");
+        let outcome = alphabet_calculate_scores(&char_counts, LangSet::all());
+        assert_eq!(outcome.scores[0], (Lang::Urd, 1.0));
+        assert_eq!(fetch(&Lang::Pes, &outcome.raw_scores), 0);
+    }
+}
@@ -1,98 +1,62 @@
-use super::RawOutcome;
-use crate::core::{FilterList, LowercaseText};
+use super::{calculate_scores, RawOutcome};
+use crate::core::LangSet;
 use crate::{Lang, Script};
 
-const BUL: &str = "абвгдежзийклмнопрстуфхцчшщъьюя";
-const RUS: &str = "абвгдежзийклмнопрстуфхцчшщъыьэюяё";
-const UKR: &str = "абвгдежзийклмнопрстуфхцчшщьюяєіїґ";
-const BEL: &str = "абвгдежзйклмнопрстуфхцчшыьэюяёіў";
-const SRP: &str = "абвгдежзиклмнопрстуфхцчшђјљњћџ";
-const MKD: &str = "абвгдежзиклмнопрстуфхцчшѓѕјљњќџ";
-
-const ALL: &str = "абвгдежзийклмнопрстуфхцчшщъыьэюяёєіїґўђјљњћџѓѕќ";
-
-pub fn alphabet_calculate_scores(text: &LowercaseText, filter_list: &FilterList) -> RawOutcome {
-    let mut raw_scores: Vec<(Lang, i32)> = Script::Cyrillic
-        .langs()
-        .iter()
-        .filter(|&&l| filter_list.is_allowed(l))
-        .map(|&l| (l, 0i32))
-        .collect();
-
-    let max_raw_score = text.chars().filter(|&ch| is_relevant(ch)).count();
-
-    for (lang, score) in &mut raw_scores {
-        let alphabet = get_lang_chars(*lang);
-
-        for ch in text.chars() {
-            if !is_relevant(ch) {
-                continue;
-            } else if alphabet.contains(&ch) {
-                *score += 1;
-            } else {
-                *score -= 1;
-            }
-        }
-    }
-
-    raw_scores.sort_by(|a, b| b.1.cmp(&a.1));
-
-    let raw_scores: Vec<(Lang, usize)> = raw_scores
-        .into_iter()
-        .map(|(l, s)| {
-            let score = if s < 0 { 0usize } else { s as usize };
-            (l, score)
-        })
-        .collect();
-
-    let mut normalized_scores = vec![];
-
-    for &(lang, raw_score) in &raw_scores {
-        // avoid devision by zero
-        let normalized_score = if raw_score == 0 {
-            0.0
-        } else {
-            raw_score as f64 / max_raw_score as f64
-        };
-        normalized_scores.push((lang, normalized_score));
-    }
-
-    RawOutcome {
-        count: max_raw_score,
-        raw_scores,
-        scores: normalized_scores,
-    }
+// Uppercase letters are included for case-sensitive detection, see `Options::set_case_sensitive`.
+const BUL: &str = "абвгдежзийклмнопрстуфхцчшщъьюяАБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЬЮЯ";
+const RUS: &str = "абвгдежзийклмнопрстуфхцчшщъыьэюяёАБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯЁ";
+const UKR: &str = "абвгдежзийклмнопрстуфхцчшщьюяєіїґАБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЬЮЯЄІЇҐ";
+const BEL: &str = "абвгдежзйклмнопрстуфхцчшыьэюяёіўАБВГДЕЖЗЙКЛМНОПРСТУФХЦЧШЫЬЭЮЯЁІЎ";
+const SRP: &str = "абвгдежзиклмнопрстуфхцчшђјљњћџАБВГДЕЖЗИКЛМНОПРСТУФХЦЧШЂЈЉЊЋЏ";
+const MKD: &str = "абвгдежзиклмнопрстуфхцчшѓѕјљњќџАБВГДЕЖЗИКЛМНОПРСТУФХЦЧШЃЅЈЉЊЌЏ";
+const AZE: &str = "абвгғдеәжзиыјкҝлмноөпрстуүфхһчҹшАБВГҒДЕӘЖЗИЫЈКҜЛМНОӨПРСТУҮФХҺЧҸШ";
+
+const ALL: &str = "абвгдежзийклмнопрстуфхцчшщъыьэюяёєіїґўђјљњћџѓѕќғәҝөүһҹАБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯЁЄІЇҐЎЂЈЉЊЋЏЃЅЌҒӘҜӨҮҺҸ";
+
+pub fn alphabet_calculate_scores(
+    char_counts: &[(char, i32)],
+    allowed_langs: LangSet,
+) -> RawOutcome {
+    calculate_scores(
+        Script::Cyrillic,
+        char_counts,
+        allowed_langs,
+        |lang| [get_lang_chars(lang)],
+        is_relevant,
+    )
 }
 
 fn is_relevant(ch: char) -> bool {
     ALL.chars().any(|c| c == ch)
 }
 
-fn get_lang_chars(lang: Lang) -> Vec<char> {
-    let alphabet = match lang {
+pub(crate) fn get_lang_chars(lang: Lang) -> &'static str {
+    match lang {
         Lang::Bul => BUL,
         Lang::Rus => RUS,
         Lang::Ukr => UKR,
         Lang::Bel => BEL,
         Lang::Srp => SRP,
         Lang::Mkd => MKD,
+        Lang::Aze => AZE,
 
         _ => panic!("No alphabet for {}", lang),
-    };
-    alphabet.chars().collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::alphabets::char_counts_of;
 
-    const CYRILLIC_LANGS: [Lang; 6] = [
+    const CYRILLIC_LANGS: [Lang; 7] = [
         Lang::Rus,
         Lang::Ukr,
         Lang::Srp,
         Lang::Bel,
         Lang::Mkd,
         Lang::Bul,
+        Lang::Aze,
     ];
 
     fn fetch<T: Copy>(lang: &Lang, scores: &[(Lang, T)]) -> T {
@@ -101,12 +65,12 @@ mod tests {
 
     #[test]
     fn test_when_latin_is_given() {
-        let text = LowercaseText::new("Foobar, hoh");
+        let char_counts = char_counts_of("Foobar, hoh");
         let RawOutcome {
             count,
             raw_scores,
             scores,
-        } = alphabet_calculate_scores(&text, &FilterList::default());
+        } = alphabet_calculate_scores(&char_counts, LangSet::all());
 
         assert_eq!(count, 0);
         assert_eq!(raw_scores.len(), CYRILLIC_LANGS.len());
@@ -125,12 +89,12 @@ mod tests {
 
     #[test]
     fn test_when_common_cyrllic_is_given() {
-        let text = LowercaseText::new("абвг ww");
+        let char_counts = char_counts_of("абвг ww");
         let RawOutcome {
             count,
             raw_scores,
             scores,
-        } = alphabet_calculate_scores(&text, &FilterList::default());
+        } = alphabet_calculate_scores(&char_counts, LangSet::all());
 
         assert_eq!(count, 4);
 
@@ -147,12 +111,12 @@ mod tests {
 
     #[test]
     fn test_when_ukrainian_specific_chars_given() {
-        let text = LowercaseText::new("Дуже цікаво");
+        let char_counts = char_counts_of("Дуже цікаво");
         let RawOutcome {
             count,
             raw_scores,
             scores,
-        } = alphabet_calculate_scores(&text, &FilterList::default());
+        } = alphabet_calculate_scores(&char_counts, LangSet::all());
 
         assert_eq!(count, 10);
 
@@ -162,4 +126,21 @@ mod tests {
         assert_eq!(fetch(&Lang::Ukr, &scores), 1.0);
         assert_eq!(fetch(&Lang::Rus, &scores), 0.8);
     }
+
+    #[test]
+    fn test_top_score_language() {
+        let char_counts = char_counts_of("Съешь же ещё этих мягких французских булок");
+        let outcome = alphabet_calculate_scores(&char_counts, LangSet::all());
+        assert_eq!(outcome.scores.len(), CYRILLIC_LANGS.len());
+        assert_eq!(outcome.scores[0], (Lang::Rus, 1.0));
+
+        let char_counts = char_counts_of("Їжак побачив ґанок і єнота");
+        let outcome = alphabet_calculate_scores(&char_counts, LangSet::all());
+        assert_eq!(outcome.scores.len(), CYRILLIC_LANGS.len());
+        assert_eq!(outcome.scores[0], (Lang::Ukr, 1.0));
+
+        let char_counts = char_counts_of("Һәр бир шәхсин һүгуглары вар");
+        let outcome = alphabet_calculate_scores(&char_counts, LangSet::all());
+        assert_eq!(outcome.scores[0], (Lang::Aze, 1.0));
+    }
 }
@@ -0,0 +1,94 @@
+use super::{calculate_scores, RawOutcome};
+use crate::core::LangSet;
+use crate::{Lang, Script};
+
+// Letters shared by both languages.
+const LETTERS: &str = "אבגדהוזחטיכךלמםנןסעפףצץקרשת";
+
+// Hebrew is usually written without points, but pointed text uses the whole set of them.
+const HEB_POINTS: &str = "\u{05B0}\u{05B1}\u{05B2}\u{05B3}\u{05B4}\u{05B5}\u{05B6}\u{05B7}\u{05B8}\u{05B9}\u{05BA}\u{05BB}\u{05BC}\u{05BD}\u{05BF}\u{05C1}\u{05C2}\u{05C7}";
+
+// Yiddish uses the double letters (װ, ױ, ײ) and only a few points
+// (pasekh, komets, khirik, dagesh, rafe and kholem).
+const YID_LETTERS: &str = "\u{05F0}\u{05F1}\u{05F2}";
+const YID_POINTS: &str = "\u{05B4}\u{05B7}\u{05B8}\u{05B9}\u{05BC}\u{05BF}";
+
+pub fn alphabet_calculate_scores(
+    char_counts: &[(char, i32)],
+    allowed_langs: LangSet,
+) -> RawOutcome {
+    calculate_scores(
+        Script::Hebrew,
+        char_counts,
+        allowed_langs,
+        get_lang_chars,
+        is_relevant,
+    )
+}
+
+fn is_relevant(ch: char) -> bool {
+    LETTERS.contains(ch)
+        || HEB_POINTS.contains(ch)
+        || YID_LETTERS.contains(ch)
+        || YID_POINTS.contains(ch)
+}
+
+pub(crate) fn get_lang_chars(lang: Lang) -> [&'static str; 3] {
+    match lang {
+        Lang::Heb => [LETTERS, "", HEB_POINTS],
+        Lang::Yid => [LETTERS, YID_LETTERS, YID_POINTS],
+
+        _ => panic!("No alphabet for {}", lang),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabets::char_counts_of;
+
+    fn fetch<T: Copy>(lang: &Lang, scores: &[(Lang, T)]) -> T {
+        scores.iter().find(|(l, _)| l == lang).unwrap().1
+    }
+
+    #[test]
+    fn test_when_common_letters_are_given() {
+        let char_counts = char_counts_of("שלום עליכם");
+        let RawOutcome {
+            count,
+            raw_scores,
+            scores,
+        } = alphabet_calculate_scores(&char_counts, LangSet::all());
+
+        assert_eq!(count, 9);
+        assert_eq!(fetch(&Lang::Heb, &raw_scores), 9);
+        assert_eq!(fetch(&Lang::Yid, &raw_scores), 9);
+        assert_eq!(fetch(&Lang::Heb, &scores), 1.0);
+        assert_eq!(fetch(&Lang::Yid, &scores), 1.0);
+    }
+
+    #[test]
+    fn test_when_yiddish_specific_chars_are_given() {
+        // "װי גײט עס" - double vov and double yud are used only in Yiddish
+        let char_counts = char_counts_of("\u{05F0}י ג\u{05F2}ט עס");
+        let RawOutcome {
+            count,
+            raw_scores,
+            scores,
+        } = alphabet_calculate_scores(&char_counts, LangSet::all());
+
+        assert_eq!(count, 7);
+        assert_eq!(raw_scores[0], (Lang::Yid, 7));
+        assert_eq!(fetch(&Lang::Heb, &raw_scores), 3);
+        assert_eq!(scores[0], (Lang::Yid, 1.0));
+    }
+
+    #[test]
+    fn test_when_pointed_hebrew_is_given() {
+        // "בְּרֵאשִׁית" - shva, tsere and shin dot are not used in Yiddish
+        let char_counts = char_counts_of("בְּרֵאשִׁית");
+        let outcome = alphabet_calculate_scores(&char_counts, LangSet::all());
+        assert_eq!(outcome.scores[0], (Lang::Heb, 1.0));
+        assert!(fetch(&Lang::Yid, &outcome.scores) < 1.0);
+    }
+}
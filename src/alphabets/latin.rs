@@ -1,47 +1,58 @@
-use super::RawOutcome;
-use crate::core::{FilterList, LowercaseText};
-use crate::utils::is_stop_char;
+use super::{calculate_scores, RawOutcome};
+use crate::core::LangSet;
 use crate::{Lang, Script};
 
-const AFR: &str = "abcdefghijklmnopqrstuvwxyzáèéêëíîïóôúû";
-const AKA: &str = "abdefghiklmnoprstuwyɔɛ";
-const AZE: &str = "abcdefghijklmnopqrstuvxyzçöüğışə̇";
-const CAT: &str = "abcdefghijklmnopqrstuvwxyz·àçèéíïòóúü";
-const CES: &str = "abcdefghijklmnopqrstuvwxyzáéóúýčďěňřšťůž";
-const DAN: &str = "abcdefghijklmnopqrstuvwxyzåæø";
-const DEU: &str = "abcdefghijklmnopqrstuvwxyzßäöü";
-const ENG: &str = "abcdefghijklmnopqrstuvwxyz";
-const EPO: &str = "abcdefghijklmnoprstuvzĉĝĥĵŝŭ";
-const EST: &str = "abcdefghijklmnopqrstuvwxyzäõöü";
-const FIN: &str = "abcdefghijklmnopqrstuvwxyzäöšž";
-const FRA: &str = "abcdefghijklmnopqrstuvwxyzàâçèéêëîïôùûüÿœ";
-const HRV: &str = "abcdefghijklmnopqrstuvwxyzćčđšž";
-const HUN: &str = "abcdefghijklmnopqrstuvwxyzáéíóöúüőű";
-const IND: &str = "abcdefghijklmnopqrstuvwxyz";
-const ITA: &str = "abcdefghijklmnopqrstuvwxyzàèéìòù";
-const JAV: &str = "abcdefghijklmnopqrstuvwxyzèé";
-const LAT: &str = "abcdefghijklmnopqrstuvwxyz";
-const LAV: &str = "abcdefghijklmnopqrstuvwxyzāčēģīķļņōŗšūž";
-const LIT: &str = "abcdefghijklmnopqrstuvwxyząčėęįšūųž";
-const NLD: &str = "abcdefghijklmnopqrstuvwxyzàèéëïĳ";
-const NOB: &str = "abcdefghijklmnopqrstuvwxyzåæø";
-const POL: &str = "abcdefghijklmnopqrstuvwxyzóąćęłńśźż";
-const POR: &str = "abcdefghijklmnopqrstuvwxyzàáâãçéêíóôõú";
-const RON: &str = "abcdefghijklmnopqrstuvwxyzâîăşţ";
-const SLK: &str = "abcdefghijklmnopqrstuvwxyzáäéíóôúýčďĺľňŕšťž";
-const SLV: &str = "abcdefghijklmnopqrstuvwxyzčšž";
-const SNA: &str = "abcdefghijklmnopqrstuvwxyz";
-const SPA: &str = "abcdefghijklmnopqrstuvwxyz¡¿áéíñóúü";
-const SWE: &str = "abcdefghijklmnopqrstuvwxyzäåö";
-const TUK: &str = "abdefghijklmnoprstuwyzäçöüýňşž";
-const TUR: &str = "abcdefghijklmnopqrstuvwxyzçöüğış̇";
-const UZB: &str = "abcdefghijklmnopqrstuvxyzʻ";
-const VIE: &str =
-    "abcdefghijklmnopqrstuvwxyzàáâãèéêìíòóôõùúýăđĩũơưạảấầẩẫậắằẳẵặẹẻẽếềểễệỉịọỏốồổỗộớờởỡợụủứừửữựỳỵỷỹ";
-const ZUL: &str = "abcdefghijklmnopqrstuvwxyz";
+// Uppercase letters are included for case-sensitive detection, see `Options::set_case_sensitive`.
+const AFR: &str = "abcdefghijklmnopqrstuvwxyzáèéêëíîïóôúûABCDEFGHIJKLMNOPQRSTUVWXYZÁÈÉÊËÍÎÏÓÔÚÛ";
+const AKA: &str = "abdefghiklmnoprstuwyɔɛABDEFGHIKLMNOPRSTUWYƆƐ";
+const AZE: &str = "abcdefghijklmnopqrstuvxyzçöüğışə̇ABCDEFGHIJKLMNOPQRSTUVXYZÇÖÜĞİŞƏ";
+const CAT: &str = "abcdefghijklmnopqrstuvwxyz·àçèéíïòóúüABCDEFGHIJKLMNOPQRSTUVWXYZÀÇÈÉÍÏÒÓÚÜ";
+const CES: &str =
+    "abcdefghijklmnopqrstuvwxyzáéóúýčďěňřšťůžABCDEFGHIJKLMNOPQRSTUVWXYZÁÉÓÚÝČĎĚŇŘŠŤŮŽ";
+const DAN: &str = "abcdefghijklmnopqrstuvwxyzåæøABCDEFGHIJKLMNOPQRSTUVWXYZÅÆØ";
+const DEU: &str = "abcdefghijklmnopqrstuvwxyzßäöüABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÜ";
+const ENG: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const EPO: &str = "abcdefghijklmnoprstuvzĉĝĥĵŝŭABCDEFGHIJKLMNOPRSTUVZĈĜĤĴŜŬ";
+const EST: &str = "abcdefghijklmnopqrstuvwxyzäõöüABCDEFGHIJKLMNOPQRSTUVWXYZÄÕÖÜ";
+const FIN: &str = "abcdefghijklmnopqrstuvwxyzäöšžABCDEFGHIJKLMNOPQRSTUVWXYZÄÖŠŽ";
+const FRA: &str =
+    "abcdefghijklmnopqrstuvwxyzàâçèéêëîïôùûüÿœABCDEFGHIJKLMNOPQRSTUVWXYZÀÂÇÈÉÊËÎÏÔÙÛÜŸŒ";
+const HRV: &str = "abcdefghijklmnopqrstuvwxyzćčđšžABCDEFGHIJKLMNOPQRSTUVWXYZĆČĐŠŽ";
+const HUN: &str = "abcdefghijklmnopqrstuvwxyzáéíóöúüőűABCDEFGHIJKLMNOPQRSTUVWXYZÁÉÍÓÖÚÜŐŰ";
+const IND: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const ITA: &str = "abcdefghijklmnopqrstuvwxyzàèéìòùABCDEFGHIJKLMNOPQRSTUVWXYZÀÈÉÌÒÙ";
+const JAV: &str = "abcdefghijklmnopqrstuvwxyzèéABCDEFGHIJKLMNOPQRSTUVWXYZÈÉ";
+const LAT: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const LAV: &str = "abcdefghijklmnopqrstuvwxyzāčēģīķļņōŗšūžABCDEFGHIJKLMNOPQRSTUVWXYZĀČĒĢĪĶĻŅŌŖŠŪŽ";
+const LIT: &str = "abcdefghijklmnopqrstuvwxyząčėęįšūųžABCDEFGHIJKLMNOPQRSTUVWXYZĄČĖĘĮŠŪŲŽ";
+const NLD: &str = "abcdefghijklmnopqrstuvwxyzàèéëïĳABCDEFGHIJKLMNOPQRSTUVWXYZÀÈÉËÏĲ";
+const NNO: &str = "abcdefghijklmnopqrstuvwxyzåæøABCDEFGHIJKLMNOPQRSTUVWXYZÅÆØ";
+const NOB: &str = "abcdefghijklmnopqrstuvwxyzåæøABCDEFGHIJKLMNOPQRSTUVWXYZÅÆØ";
+const POL: &str = "abcdefghijklmnopqrstuvwxyzóąćęłńśźżABCDEFGHIJKLMNOPQRSTUVWXYZÓĄĆĘŁŃŚŹŻ";
+const POR: &str = "abcdefghijklmnopqrstuvwxyzàáâãçéêíóôõúABCDEFGHIJKLMNOPQRSTUVWXYZÀÁÂÃÇÉÊÍÓÔÕÚ";
+const RON: &str = "abcdefghijklmnopqrstuvwxyzâîăşţABCDEFGHIJKLMNOPQRSTUVWXYZÂÎĂŞŢ";
+const SLK: &str =
+    "abcdefghijklmnopqrstuvwxyzáäéíóôúýčďĺľňŕšťžABCDEFGHIJKLMNOPQRSTUVWXYZÁÄÉÍÓÔÚÝČĎĹĽŇŔŠŤŽ";
+const SLV: &str = "abcdefghijklmnopqrstuvwxyzčšžABCDEFGHIJKLMNOPQRSTUVWXYZČŠŽ";
+const SNA: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const SRP: &str = "abcdefghijklmnopqrstuvwxyzćčđšžABCDEFGHIJKLMNOPQRSTUVWXYZĆČĐŠŽ";
+const SPA: &str = "abcdefghijklmnopqrstuvwxyz¡¿áéíñóúüABCDEFGHIJKLMNOPQRSTUVWXYZÁÉÍÑÓÚÜ";
+const SWE: &str = "abcdefghijklmnopqrstuvwxyzäåöABCDEFGHIJKLMNOPQRSTUVWXYZÄÅÖ";
+const TUK: &str = "abdefghijklmnoprstuwyzäçöüýňşžABDEFGHIJKLMNOPRSTUWYZÄÇÖÜÝŇŞŽ";
+const TUR: &str = "abcdefghijklmnopqrstuvwxyzçöüğış̇ABCDEFGHIJKLMNOPQRSTUVWXYZÇÖÜĞİŞ";
+const UZB: &str = "abcdefghijklmnopqrstuvxyzʻABCDEFGHIJKLMNOPQRSTUVXYZ";
+// Vietnamese text is sometimes written decomposed (NFD): a base letter followed by combining
+// marks. Hook above, horn and dot below are used only by Vietnamese, so they are letters
+// of its alphabet too (unlike e.g. the acute accent, which is shared with other languages).
+const VIE: &str = concat!(
+    "abcdefghijklmnopqrstuvwxyzàáâãèéêìíòóôõùúýăđĩũơưạảấầẩẫậắằẳẵặẹẻẽếềểễệỉịọỏốồổỗộớờởỡợụủứừửữựỳỵỷỹ",
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZÀÁÂÃÈÉÊÌÍÒÓÔÕÙÚÝĂĐĨŨƠƯẠẢẤẦẨẪẬẮẰẲẴẶẸẺẼẾỀỂỄỆỈỊỌỎỐỒỔỖỘỚỜỞỠỢỤỦỨỪỬỮỰỲỴỶỸ",
+    "\u{0309}\u{031B}\u{0323}"
+);
+const ZUL: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
-fn get_lang_chars(lang: Lang) -> Vec<char> {
-    let alphabet = match lang {
+pub(crate) fn get_lang_chars(lang: Lang) -> &'static str {
+    match lang {
         Lang::Afr => AFR,
         Lang::Aka => AKA,
         Lang::Aze => AZE,
@@ -63,6 +74,7 @@ fn get_lang_chars(lang: Lang) -> Vec<char> {
         Lang::Lav => LAV,
         Lang::Lit => LIT,
         Lang::Nld => NLD,
+        Lang::Nno => NNO,
         Lang::Nob => NOB,
         Lang::Pol => POL,
         Lang::Por => POR,
@@ -70,6 +82,7 @@ fn get_lang_chars(lang: Lang) -> Vec<char> {
         Lang::Slk => SLK,
         Lang::Slv => SLV,
         Lang::Sna => SNA,
+        Lang::Srp => SRP,
         Lang::Spa => SPA,
         Lang::Swe => SWE,
         Lang::Tuk => TUK,
@@ -79,55 +92,70 @@ fn get_lang_chars(lang: Lang) -> Vec<char> {
         Lang::Zul => ZUL,
 
         _ => panic!("No alphabet for {}", lang),
-    };
-    alphabet.chars().collect()
+    }
 }
 
-pub fn alphabet_calculate_scores(text: &LowercaseText, filter_list: &FilterList) -> RawOutcome {
-    let mut raw_scores: Vec<(Lang, i32)> = Script::Latin
-        .langs()
-        .iter()
-        .filter(|&&l| filter_list.is_allowed(l))
-        .map(|&l| (l, 0i32))
-        .collect();
-
-    let max_raw_score = text.chars().filter(|&ch| !is_stop_char(ch)).count();
+pub fn alphabet_calculate_scores(
+    char_counts: &[(char, i32)],
+    allowed_langs: LangSet,
+) -> RawOutcome {
+    calculate_scores(
+        Script::Latin,
+        char_counts,
+        allowed_langs,
+        |lang| [get_lang_chars(lang)],
+        |_| true,
+    )
+}
 
-    for (lang, score) in &mut raw_scores {
-        let alphabet = get_lang_chars(*lang);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabets::char_counts_of;
 
-        for ch in text.chars() {
-            if is_stop_char(ch) {
-                continue;
-            };
-            if alphabet.contains(&ch) {
-                *score += 1;
-            } else {
-                *score -= 1;
-            }
-        }
-    }
+    #[test]
+    fn test_raw_scores_do_not_overflow() {
+        // Extremely long text of chars out of all Latin alphabets
+        let char_counts = [('ж', i32::MAX), ('щ', i32::MAX), ('a', 1)];
+        let RawOutcome {
+            count,
+            raw_scores,
+            scores,
+        } = alphabet_calculate_scores(&char_counts, LangSet::all());
 
-    raw_scores.sort_by(|a, b| b.1.cmp(&a.1));
+        assert_eq!(count, 2 * i32::MAX as usize + 1);
+        assert!(raw_scores.iter().all(|&(_, score)| score == 0));
+        assert!(scores.iter().all(|&(_, score)| score == 0.0));
 
-    let raw_scores: Vec<(Lang, usize)> = raw_scores
-        .into_iter()
-        .map(|(l, s)| {
-            let score = if s < 0 { 0usize } else { s as usize };
-            (l, score)
-        })
-        .collect();
-
-    let mut normalized_scores = vec![];
+        let char_counts = char_counts_of(&"ж".repeat(100_000));
+        let outcome = alphabet_calculate_scores(&char_counts, LangSet::all());
+        assert_eq!(outcome.count, 100_000);
+        assert_eq!(outcome.raw_scores[0].1, 0);
+        assert_eq!(outcome.scores[0].1, 0.0);
+    }
 
-    for &(lang, raw_score) in &raw_scores {
-        let normalized_score = raw_score as f64 / max_raw_score as f64;
-        normalized_scores.push((lang, normalized_score));
+    #[test]
+    fn test_vietnamese_combining_marks() {
+        // "Mẹ tôi nấu ăn rất ngon" decomposed (NFD)
+        let text = "Me\u{0323} to\u{0302}i na\u{0302}\u{0301}u a\u{0306}n ra\u{0302}\u{0301}t ngon";
+        let outcome = alphabet_calculate_scores(&char_counts_of(text), LangSet::all());
+        assert_eq!(outcome.raw_scores[0].0, Lang::Vie);
+        assert!(outcome.raw_scores[0].1 > outcome.raw_scores[1].1);
     }
 
-    RawOutcome {
-        count: max_raw_score,
-        raw_scores,
-        scores: normalized_scores,
+    #[test]
+    fn test_when_only_stop_chars_are_given() {
+        let char_counts = char_counts_of("   ... !!!");
+        let RawOutcome {
+            count,
+            raw_scores,
+            scores,
+        } = alphabet_calculate_scores(&char_counts, LangSet::all());
+
+        assert_eq!(count, 0);
+        assert_eq!(raw_scores.len(), Script::Latin.langs().len());
+        assert!(raw_scores.iter().all(|&(_, score)| score == 0));
+        assert_eq!(scores.len(), Script::Latin.langs().len());
+        assert!(scores.iter().all(|&(_, score)| score == 0.0));
     }
 }
@@ -1,14 +1,212 @@
+use alloc::vec::Vec;
+
+mod arabic;
 mod cyrillic;
 pub(crate) mod detection;
+mod hebrew;
 mod latin;
 
-pub use detection::{detect, raw_detect};
+pub use detection::raw_detect;
+pub(crate) use detection::raw_detect_by_char_counts;
 
-use crate::Lang;
+use crate::core::{LangSet, LowercaseText};
+use crate::utils::is_ignored_char;
+use crate::{Lang, Script};
 
+// Raw score of a language is the number of chars of the text that belong to its alphabet
+// minus the number of chars that don't, so it's within -count..=count. It's accumulated as `i64`
+// with saturating arithmetic (every char is counted up to `i32::MAX` times, see `count_chars`),
+// so it never overflows, and negative scores become 0 in `raw_scores`.
 #[derive(Debug)]
 pub struct RawOutcome {
     pub count: usize,
     pub raw_scores: Vec<(Lang, usize)>,
     pub scores: Vec<(Lang, f64)>,
 }
+
+impl RawOutcome {
+    // Drops scores of the other languages. Scores of a language don't depend on the others,
+    // so the outcome is the same as if only the given languages were scored.
+    pub(crate) fn retain_langs(&mut self, langs: LangSet) {
+        self.raw_scores.retain(|&(lang, _)| langs.contains(lang));
+        self.scores.retain(|&(lang, _)| langs.contains(lang));
+    }
+}
+
+// Scores the allowed languages of the script by their alphabets, see `RawOutcome`.
+// Only chars for which `is_relevant` returns `true` are counted, and the alphabet
+// of a language is the union of the sets of chars returned by `get_lang_chars`.
+// Languages with equal scores are ordered by `Lang`, see `combine_scores`.
+fn calculate_scores<A: AsRef<[&'static str]>>(
+    script: Script,
+    char_counts: &[(char, i32)],
+    allowed_langs: LangSet,
+    get_lang_chars: fn(Lang) -> A,
+    is_relevant: fn(char) -> bool,
+) -> RawOutcome {
+    let mut raw_scores: Vec<(Lang, i64)> = script
+        .langs()
+        .iter()
+        .filter(|&&l| allowed_langs.contains(l))
+        .map(|&l| (l, 0i64))
+        .collect();
+
+    let relevant_char_counts = char_counts.iter().filter(|&&(ch, _)| is_relevant(ch));
+    let max_raw_score = relevant_char_counts
+        .clone()
+        .map(|&(_, count)| count as usize)
+        .sum();
+
+    for (lang, score) in &mut raw_scores {
+        let alphabet = get_lang_chars(*lang);
+
+        for &(ch, count) in relevant_char_counts.clone() {
+            if alphabet.as_ref().iter().any(|chars| chars.contains(ch)) {
+                *score = score.saturating_add(i64::from(count));
+            } else {
+                *score = score.saturating_sub(i64::from(count));
+            }
+        }
+    }
+
+    raw_scores.sort_by_key(|&(lang, score)| (core::cmp::Reverse(score), lang));
+
+    let raw_scores: Vec<(Lang, usize)> = raw_scores
+        .into_iter()
+        .map(|(l, s)| {
+            let score = if s < 0 { 0usize } else { s as usize };
+            (l, score)
+        })
+        .collect();
+
+    let mut normalized_scores = Vec::with_capacity(raw_scores.len());
+
+    for &(lang, raw_score) in &raw_scores {
+        // avoid division by zero
+        let normalized_score = if raw_score == 0 {
+            0.0
+        } else {
+            raw_score as f64 / max_raw_score as f64
+        };
+        normalized_scores.push((lang, normalized_score));
+    }
+
+    RawOutcome {
+        count: max_raw_score,
+        raw_scores,
+        scores: normalized_scores,
+    }
+}
+
+// Adds occurrences of every char of the text (except stop chars and, optionally, digits)
+// to `char_counts`. Alphabets of languages are scored by the counts, so they are checked
+// only once per distinct char rather than once per char of the text.
+pub(crate) fn count_chars(
+    text: &LowercaseText,
+    ignore_digits: bool,
+    char_counts: &mut Vec<(char, i32)>,
+) {
+    for ch in text
+        .chars()
+        .filter(|&ch| !is_ignored_char(ch, ignore_digits))
+    {
+        match char_counts.iter_mut().find(|(c, _)| *c == ch) {
+            Some((_, count)) => *count = count.saturating_add(1),
+            None => char_counts.push((ch, 1)),
+        }
+    }
+}
+
+impl Lang {
+    /// Check whether the character is a letter of the alphabet of the language
+    /// (case-insensitively). Only languages of Latin, Cyrillic, Arabic and Hebrew scripts have
+    /// alphabets, for other languages `None` is returned. A language written with
+    /// multiple scripts (e.g. Serbian) contains letters of all its alphabets.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::Lang;
+    /// assert_eq!(Lang::Ukr.contains_char('ї'), Some(true));
+    /// assert_eq!(Lang::Rus.contains_char('ї'), Some(false));
+    /// assert_eq!(Lang::Pes.contains_char('گ'), Some(true));
+    /// assert_eq!(Lang::Hin.contains_char('क'), None);
+    /// ```
+    pub fn contains_char(&self, ch: char) -> Option<bool> {
+        let lang = *self;
+        let is_written_with = |script: Script| script.langs().contains(&lang);
+        let contains = |alphabet: &[&str]| {
+            ch.to_lowercase()
+                .all(|c| alphabet.iter().any(|chars| chars.contains(c)))
+        };
+
+        let mut result = None;
+        if is_written_with(Script::Latin) {
+            result = Some(contains(&[latin::get_lang_chars(lang)]));
+        }
+        if is_written_with(Script::Cyrillic) {
+            let found = contains(&[cyrillic::get_lang_chars(lang)]);
+            result = Some(result.unwrap_or(false) || found);
+        }
+        if is_written_with(Script::Arabic) {
+            result = Some(contains(&arabic::get_lang_chars(lang)));
+        }
+        if is_written_with(Script::Hebrew) {
+            result = Some(contains(&hebrew::get_lang_chars(lang)));
+        }
+        result
+    }
+}
+
+// Alphabets of the language of all the scripts it's written with, see `Lang::contains_char`.
+#[cfg(all(test, feature = "all-scripts"))]
+pub(crate) fn alphabets_of(lang: Lang) -> Vec<&'static str> {
+    let is_written_with = |script: Script| script.langs().contains(&lang);
+    let mut alphabets = Vec::new();
+    if is_written_with(Script::Latin) {
+        alphabets.push(latin::get_lang_chars(lang));
+    }
+    if is_written_with(Script::Cyrillic) {
+        alphabets.push(cyrillic::get_lang_chars(lang));
+    }
+    if is_written_with(Script::Arabic) {
+        alphabets.extend(&arabic::get_lang_chars(lang));
+    }
+    if is_written_with(Script::Hebrew) {
+        alphabets.extend(&hebrew::get_lang_chars(lang));
+    }
+    alphabets
+}
+
+#[cfg(test)]
+fn char_counts_of(text: &str) -> Vec<(char, i32)> {
+    let mut char_counts = Vec::new();
+    count_chars(&LowercaseText::new(text), false, &mut char_counts);
+    char_counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_char() {
+        assert_eq!(Lang::Ukr.contains_char('ї'), Some(true));
+        assert_eq!(Lang::Ukr.contains_char('Ї'), Some(true));
+        assert_eq!(Lang::Rus.contains_char('ї'), Some(false));
+        assert_eq!(Lang::Eng.contains_char('é'), Some(false));
+        assert_eq!(Lang::Fra.contains_char('é'), Some(true));
+
+        // Serbian is written with both Latin and Cyrillic
+        assert_eq!(Lang::Srp.contains_char('đ'), Some(true));
+        assert_eq!(Lang::Srp.contains_char('ђ'), Some(true));
+        assert_eq!(Lang::Srp.contains_char('ї'), Some(false));
+
+        assert_eq!(Lang::Yid.contains_char('\u{05F0}'), Some(true));
+        assert_eq!(Lang::Heb.contains_char('\u{05F0}'), Some(false));
+
+        assert_eq!(Lang::Ara.contains_char('ب'), Some(true));
+        assert_eq!(Lang::Ara.contains_char('گ'), Some(false));
+        assert_eq!(Lang::Hin.contains_char('क'), None);
+        assert_eq!(Lang::Kat.contains_char('ა'), None);
+    }
+}
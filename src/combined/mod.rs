@@ -1,8 +1,12 @@
-use crate::alphabets;
-use crate::core::{calculate_confidence, Info, InternalQuery};
-use crate::trigrams;
+use alloc::vec::Vec;
+
 use crate::Lang;
+#[cfg(feature = "dev")]
+use crate::{alphabets, core::InternalQuery, trigrams};
 
+// Detection itself uses `combine_scores` directly, the full outcome is only needed
+// by the tooling exposed with the `dev` feature.
+#[cfg(feature = "dev")]
 #[derive(Debug)]
 pub struct RawOutcome {
     pub scores: Vec<(Lang, f64)>,
@@ -10,50 +14,51 @@ pub struct RawOutcome {
     pub trigram_raw_outcome: trigrams::RawOutcome,
 }
 
-pub fn detect(iquery: &mut InternalQuery) -> Option<Info> {
-    let raw_outcome = raw_detect(iquery);
-
-    let count = raw_outcome.trigram_raw_outcome.trigrams_count;
-    let mut normalized_scores_iter = raw_outcome.scores.into_iter();
-
-    let opt_lang_score1 = normalized_scores_iter.next();
-    let opt_lang_score2 = normalized_scores_iter.next();
-
-    // TODO: Logic is duplicated in alphabets and trigrams. Consider refactoring
-    opt_lang_score1.map(|(lang1, score1)| {
-        let script = iquery.multi_lang_script.to_script();
-        let confidence = if let Some((_, score2)) = opt_lang_score2 {
-            calculate_confidence(score1, score2, count)
-        } else {
-            1.0
-        };
-        Info::new(script, lang1, confidence)
-    })
-}
-
-// TODO: optimize!
+#[cfg(feature = "dev")]
 pub fn raw_detect(iquery: &mut InternalQuery) -> RawOutcome {
     let alphabet_raw_outcome = alphabets::raw_detect(iquery);
     let trigram_raw_outcome = trigrams::raw_detect(iquery);
 
-    let alphabet_scores = &alphabet_raw_outcome.scores;
-    let trigram_scores = &trigram_raw_outcome.scores;
-
-    let mut all_langs: Vec<Lang> = alphabet_scores.iter().map(|x| x.0).collect();
-    trigram_scores.iter().for_each(|(lang, _)| {
-        if !all_langs.contains(lang) {
-            all_langs.push(*lang);
-        }
-    });
+    let mut scores = Vec::new();
+    combine_scores(
+        &alphabet_raw_outcome.scores,
+        &trigram_raw_outcome.scores,
+        calc_alphabet_weight(alphabet_raw_outcome.count),
+        &mut scores,
+    );
 
-    let count = alphabet_raw_outcome.count;
+    RawOutcome {
+        scores,
+        alphabet_raw_outcome,
+        trigram_raw_outcome,
+    }
+}
 
-    let alphabet_weight = calc_alphabet_weight(count);
+// Blends alphabet and trigram scores into `scores` (which is cleared first),
+// sorted in descending order:
+//
+// score = alphabet_score * alphabet_weight + trigram_score * (1 - alphabet_weight)
+//
+// Languages with exactly equal scores are ordered by `Lang` (the order of declaration),
+// so the result doesn't depend on the order the scores are calculated in.
+pub(crate) fn combine_scores(
+    alphabet_scores: &[(Lang, f64)],
+    trigram_scores: &[(Lang, f64)],
+    alphabet_weight: f64,
+    scores: &mut Vec<(Lang, f64)>,
+) {
     let trigram_weight = 1.0 - alphabet_weight;
 
-    let mut scores = vec![];
+    scores.clear();
+    let all_langs = alphabet_scores
+        .iter()
+        .chain(trigram_scores.iter())
+        .map(|&(lang, _)| lang);
 
     for lang in all_langs {
+        if scores.iter().any(|&(l, _)| l == lang) {
+            continue;
+        }
         let a: f64 = alphabet_scores
             .iter()
             .find(|(l, _)| l == &lang)
@@ -78,13 +83,11 @@ pub fn raw_detect(iquery: &mut InternalQuery) -> RawOutcome {
         scores.push((lang, score));
     }
 
-    scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Less));
-
-    RawOutcome {
-        scores,
-        alphabet_raw_outcome,
-        trigram_raw_outcome,
-    }
+    scores.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(core::cmp::Ordering::Less)
+            .then_with(|| a.0.cmp(&b.0))
+    });
 }
 
 // Function that calculates weight of alphabet score depending on number of characters in the given
@@ -111,7 +114,7 @@ pub fn raw_detect(iquery: &mut InternalQuery) -> RawOutcome {
 //          +----------|------------> count
 //         0          100
 //
-fn calc_alphabet_weight(count: usize) -> f64 {
+pub(crate) fn calc_alphabet_weight(count: usize) -> f64 {
     let weight = -(count as f64 / 300.0) + 2.0 / 3.0;
     weight.clamp(1.0 / 3.0, 2.0 / 3.0)
 }
@@ -127,4 +130,41 @@ mod tests {
         assert_eq!(calc_alphabet_weight(100), 1.0 / 3.0);
         assert_eq!(calc_alphabet_weight(200), 1.0 / 3.0);
     }
+
+    #[test]
+    fn test_combine_scores() {
+        let alphabet_scores = [(Lang::Ukr, 1.0), (Lang::Rus, 0.5)];
+        let trigram_scores = [(Lang::Rus, 0.8), (Lang::Ukr, 0.6)];
+        let mut scores = Vec::new();
+
+        combine_scores(&alphabet_scores, &trigram_scores, 0.0, &mut scores);
+        assert_eq!(scores, [(Lang::Rus, 0.8), (Lang::Ukr, 0.6)]);
+
+        combine_scores(&alphabet_scores, &trigram_scores, 1.0, &mut scores);
+        assert_eq!(scores, [(Lang::Ukr, 1.0), (Lang::Rus, 0.5)]);
+
+        combine_scores(&alphabet_scores, &trigram_scores, 0.5, &mut scores);
+        assert_eq!(scores, [(Lang::Ukr, 0.8), (Lang::Rus, 0.65)]);
+    }
+
+    #[test]
+    fn test_combine_scores_with_tie() {
+        // Rus is declared before Ukr
+        let mut scores = Vec::new();
+        combine_scores(
+            &[(Lang::Ukr, 0.5), (Lang::Rus, 0.5)],
+            &[(Lang::Ukr, 0.5), (Lang::Rus, 0.5)],
+            0.5,
+            &mut scores,
+        );
+        assert_eq!(scores, [(Lang::Rus, 0.5), (Lang::Ukr, 0.5)]);
+
+        combine_scores(
+            &[(Lang::Rus, 0.5), (Lang::Ukr, 0.5)],
+            &[(Lang::Rus, 0.5), (Lang::Ukr, 0.5)],
+            0.5,
+            &mut scores,
+        );
+        assert_eq!(scores, [(Lang::Rus, 0.5), (Lang::Ukr, 0.5)]);
+    }
 }
@@ -1,7 +1,90 @@
-// Calculate confidence that the language with the highest score is the correct one.
-// highest_score - within 0.0..1.0
-// second_score - within 0.0..1.0
-// count - number of chars or trigrams
+use core::cmp::Ordering;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Confidence of detection, always within the range 0.0..=1.0.
+///
+/// # Example
+/// ```
+/// use whatlang::Confidence;
+/// let confidence = Confidence::new(1.0000001);
+/// assert_eq!(confidence.value(), 1.0);
+/// assert_eq!(confidence.to_string(), "1.00");
+/// assert_eq!(format!("{:.3}", Confidence::new(0.0712)), "0.071");
+/// assert!(confidence > 0.9);
+/// ```
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(from = "f64", into = "f64")
+)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Confidence(f64);
+
+impl Confidence {
+    /// Create confidence, clamping the value into the range 0.0..=1.0.
+    /// NaN is turned into 0.0.
+    pub fn new(value: f64) -> Self {
+        if value.is_nan() {
+            Self(0.0)
+        } else {
+            Self(value.clamp(0.0, 1.0))
+        }
+    }
+
+    pub fn value(self) -> f64 {
+        self.0
+    }
+}
+
+impl From<f64> for Confidence {
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<Confidence> for f64 {
+    fn from(confidence: Confidence) -> Self {
+        confidence.0
+    }
+}
+
+impl PartialEq<f64> for Confidence {
+    fn eq(&self, other: &f64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialOrd<f64> for Confidence {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+// Display with two decimals unless the precision is given (e.g. `{:.4}`)
+impl fmt::Display for Confidence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "{:.*}", precision, self.0)
+    }
+}
+
+// Constants of the hyperbola that separates confident detections, see `calculate_confidence`.
+// They are chosen based on experiments.
+const CONFIDENT_RATE_FACTOR: f64 = 3.0;
+const MIN_CONFIDENT_RATE: f64 = 0.015;
+
+/// Calculate confidence that the language with the highest score is the correct one.
+/// * `highest_score` - score of the most likely language, within 0.0..=1.0
+/// * `second_score` - score of the next most likely language, within 0.0..=1.0
+/// * `count` - number of chars or trigrams the scores are based on
+///
+/// Confidence depends on the rate `(highest_score - second_score) / second_score`:
+/// it's 1.0 when the rate is higher than `3.0 / count + 0.015` (the more evidence,
+/// the smaller difference is enough), otherwise it's proportional to the rate.
+/// If `second_score` is 0.0 (there is no other candidate), `highest_score` is returned.
 pub fn calculate_confidence(highest_score: f64, second_score: f64, count: usize) -> f64 {
     if highest_score == 0.0 {
         return 0.0;
@@ -17,8 +100,7 @@ pub fn calculate_confidence(highest_score: f64, second_score: f64, count: usize)
 
     // Hyperbola function. Everything that is above the function has confidence = 1.0
     // If rate is below, confidence is calculated proportionally.
-    // Constants are used based on experiments.
-    let confident_rate = (3.0 / count as f64) + 0.015;
+    let confident_rate = confident_rate(count);
     let rate = (highest_score - second_score) / second_score;
 
     if rate > confident_rate {
@@ -27,3 +109,56 @@ pub fn calculate_confidence(highest_score: f64, second_score: f64, count: usize)
         rate / confident_rate
     }
 }
+
+// The rate of the difference between two scores based on `count` chars or trigrams,
+// above which the higher one is confidently better, see `calculate_confidence`.
+pub(crate) fn confident_rate(count: usize) -> f64 {
+    (CONFIDENT_RATE_FACTOR / count as f64) + MIN_CONFIDENT_RATE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confidence_new() {
+        assert_eq!(Confidence::new(0.5).value(), 0.5);
+        assert_eq!(Confidence::new(1.0000001).value(), 1.0);
+        assert_eq!(Confidence::new(-0.0001).value(), 0.0);
+        assert_eq!(Confidence::new(f64::NAN).value(), 0.0);
+    }
+
+    #[test]
+    fn test_confidence_ord() {
+        assert!(Confidence::new(0.3) < Confidence::new(0.5));
+        assert!(Confidence::new(0.3) < 0.5);
+        assert!(Confidence::new(0.3) >= 0.3);
+        assert_eq!(Confidence::new(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_calculate_confidence() {
+        // No score at all
+        assert_eq!(calculate_confidence(0.0, 0.0, 100), 0.0);
+        // Single candidate or the second one has no score
+        assert_eq!(calculate_confidence(0.7, 0.0, 100), 0.7);
+        // Rate 0.25 is above 3/100 + 0.015
+        assert_eq!(calculate_confidence(0.5, 0.4, 100), 1.0);
+        // Rate 0.025 is below 0.045, so the confidence is proportional
+        let confidence = calculate_confidence(0.41, 0.4, 100);
+        assert!((confidence - 0.025 / 0.045).abs() < 1e-9);
+        // The same scores are less convincing with less evidence
+        assert!(calculate_confidence(0.41, 0.4, 10) < confidence);
+        // Equal scores
+        assert_eq!(calculate_confidence(0.4, 0.4, 100), 0.0);
+    }
+
+    #[test]
+    fn test_confidence_display() {
+        assert_eq!(Confidence::new(1.0).to_string(), "1.00");
+        assert_eq!(Confidence::new(0.824).to_string(), "0.82");
+        assert_eq!(Confidence::new(0.07).to_string(), "0.07");
+        assert_eq!(Confidence::new(0.0).to_string(), "0.00");
+        assert_eq!(format!("{:.3}", Confidence::new(0.824)), "0.824");
+    }
+}
@@ -1,8 +1,16 @@
-use crate::core::{Info, Method, Options, Query};
-use crate::scripts::{
-    grouping::{MultiLangScript, ScriptLangGroup},
-    raw_detect_script, RawScriptInfo, Script,
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::detector::DEFAULT_DETECTOR;
+use crate::core::{
+    calculate_confidence, DetectStats, Info, InternalQuery, LangSet, Method, Options, Query,
+    ScriptDetail,
 };
+use crate::error::DetectError;
+use crate::scripts::grouping::{MultiLangScript, ScriptLangGroup};
+use crate::scripts::{raw_detect_script, RawScriptInfo, Script};
+use crate::utils::{is_ignored_char, is_stop_char};
 use crate::Lang;
 use crate::{alphabets, combined, trigrams};
 
@@ -18,6 +26,32 @@ pub fn detect_lang(text: &str) -> Option<Lang> {
     detect(text).map(|output| output.lang())
 }
 
+/// Detect only a language by a given text, or return `fallback` if the language
+/// can not be detected (e.g. the text has no letters).
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_or, Lang};
+/// assert_eq!(detect_or("Та нічого, все нормально. А в тебе як?", Lang::Eng), Lang::Ukr);
+/// assert_eq!(detect_or("12345", Lang::Ukr), Lang::Ukr);
+/// ```
+pub fn detect_or(text: &str, fallback: Lang) -> Lang {
+    detect_lang(text).unwrap_or(fallback)
+}
+
+/// Detect only a language by a given text, or return English if the language
+/// can not be detected, see `detect_or`.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_or_default, Lang};
+/// assert_eq!(detect_or_default("Та нічого, все нормально. А в тебе як?"), Lang::Ukr);
+/// assert_eq!(detect_or_default("12345"), Lang::Eng);
+/// ```
+pub fn detect_or_default(text: &str) -> Lang {
+    detect_or(text, Lang::Eng)
+}
+
 /// Detect a language and a script by a given text.
 ///
 /// # Example
@@ -27,53 +61,703 @@ pub fn detect_lang(text: &str) -> Option<Lang> {
 /// assert_eq!(lang, Lang::Eng);
 /// ```
 pub fn detect(text: &str) -> Option<Info> {
-    let opts = Options::default();
-    detect_with_options(text, &opts)
+    DEFAULT_DETECTOR.detect(text)
 }
 
+/// Detect a language and a script by a given text using the given options.
+/// When the filter list has both allowlist and denylist, the allowlist restricts the
+/// candidate languages first, and then the denylist removes languages from them.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_with_options, FilterList, Lang, Options};
+/// let filter_list = FilterList::allow_deny(vec![Lang::Eng, Lang::Epo], vec![Lang::Eng]);
+/// let options = Options::new().set_filter_list(filter_list);
+/// let info = detect_with_options("Mi ne scias!", &options).unwrap();
+/// assert_eq!(info.lang(), Lang::Epo);
+/// ```
 pub fn detect_with_options(text: &str, options: &Options) -> Option<Info> {
-    let query = Query {
-        text,
-        filter_list: &options.filter_list,
-        method: options.method,
-    };
+    let query = Query::new(text, options);
     detect_by_query(&query)
 }
 
+/// Detect a language and a script by a given text using the given options,
+/// but stop scoring languages as soon as one of them is guaranteed to get
+/// confidence higher than `bar`, before all trigrams of the language profiles are compared.
+/// The confidence of such an early result is the guaranteed one, so it can be lower than
+/// the one `detect_with_options` gives, but it's always higher than `bar`.
+/// If no language gets there, the scores are calculated in full the usual way.
+///
+/// Confidence is never higher than 1.0, so with `bar` of 1.0 the result is
+/// the same as the one of `detect_with_options`.
+/// Scoring doesn't stop early with custom trigram models (see `Options::set_trigram_models`)
+/// and for languages that are too close to be told apart only by scores (e.g. Serbian and Croatian).
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_until, detect_with_options, Lang, Options};
+/// let text = "Та нічого, все нормально. А в тебе як? Як справи на роботі, все добре?";
+/// let options = Options::default();
+/// let info = detect_until(text, &options, 0.5).unwrap();
+/// assert_eq!(info.lang(), Lang::Ukr);
+/// assert!(info.confidence() > 0.5);
+/// assert_eq!(detect_until(text, &options, 1.0), detect_with_options(text, &options));
+/// ```
+pub fn detect_until(text: &str, options: &Options, bar: f64) -> Option<Info> {
+    let mut query = Query::new(text, options);
+    query.confidence_bar = bar;
+    detect_by_query(&query)
+}
+
+/// Detect a language and a script by a given text using the given options.
+/// Unlike `detect_with_options`, it tells why nothing could be detected.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_result, DetectError, Lang, Options};
+/// let options = Options::default();
+/// let info = detect_result("There is no reason not to learn Esperanto.", &options).unwrap();
+/// assert_eq!(info.lang(), Lang::Eng);
+/// assert_eq!(detect_result("12345", &options), Err(DetectError::NoScript));
+/// ```
+pub fn detect_result(text: &str, options: &Options) -> Result<Info, DetectError> {
+    let query = Query::new(text, options);
+    detect_result_by_query(&query, &mut vec![])
+}
+
+/// Detect a language and a script by a given text, but only if the detection is reliable
+/// (see `Info::is_reliable`), otherwise `None` is returned.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_reliable, Lang};
+/// let text = "Мой дядя самых честных правил, когда не в шутку занемог, он уважать себя заставил и лучше выдумать не мог.";
+/// assert_eq!(detect_reliable(text).unwrap().lang(), Lang::Rus);
+/// assert_eq!(detect_reliable("qwzx vbnm pkjh"), None);
+/// ```
+pub fn detect_reliable(text: &str) -> Option<Info> {
+    DEFAULT_DETECTOR.detect_reliable(text)
+}
+
+/// Detect a language and a script by a given text using the given options,
+/// but only if the detection is reliable, see `detect_reliable`.
+pub fn detect_reliable_with_options(text: &str, options: &Options) -> Option<Info> {
+    detect_with_options(text, options).filter(|info| info.is_reliable())
+}
+
+/// Detect a language and a script by characters of a text using the given options.
+/// It's handy when the text comes from a streaming decoder as characters rather than `&str`.
+/// The result is the same as the one of `detect_with_options` for the same text.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_chars, Lang, Options};
+/// let chars = "Та нічого, все нормально. А в тебе як?".chars();
+/// let info = detect_chars(chars, &Options::default()).unwrap();
+/// assert_eq!(info.lang(), Lang::Ukr);
+/// ```
+pub fn detect_chars<I: Iterator<Item = char>>(chars: I, options: &Options) -> Option<Info> {
+    // Detection goes through the text several times, so it has to be collected
+    let text: String = chars.collect();
+    detect_with_options(&text, options)
+}
+
+/// Detect a language and a script by raw bytes of a text using the given options.
+/// The bytes are decoded as UTF-8. If they are not valid UTF-8, they are decoded lossily:
+/// every invalid sequence is replaced with `U+FFFD`, so e.g. accented letters of a Latin-1
+/// text are lost, but the rest of the text is still detected.
+/// For valid UTF-8 the result is the same as the one of `detect_with_options`.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_bytes, Lang, Options};
+/// let bytes = "Та нічого, все нормально. А в тебе як?".as_bytes();
+/// let info = detect_bytes(bytes, &Options::default()).unwrap();
+/// assert_eq!(info.lang(), Lang::Ukr);
+/// ```
+pub fn detect_bytes(bytes: &[u8], options: &Options) -> Option<Info> {
+    // Valid UTF-8 is borrowed without copying
+    let text = String::from_utf8_lossy(bytes);
+    detect_with_options(&text, options)
+}
+
+/// Detect languages of multiple texts at once.
+/// The result for every text is the same as the one of `detect_with_options`,
+/// but the buffer for scores of languages is allocated once for all the texts
+/// (see `detect_into`).
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_batch, Lang, Options};
+/// let texts = ["Hello, how are you?", "Привет, как дела?", "12345"];
+/// let infos = detect_batch(&texts, &Options::default());
+/// assert_eq!(infos.len(), 3);
+/// assert_eq!(infos[1].as_ref().map(|info| info.lang()), Some(Lang::Rus));
+/// assert_eq!(infos[2], None);
+/// ```
+pub fn detect_batch(texts: &[&str], options: &Options) -> Vec<Option<Info>> {
+    let mut scratch = vec![];
+    texts
+        .iter()
+        .map(|text| detect_into(text, options, &mut scratch))
+        .collect()
+}
+
+/// Lazily detect language of every line of a text.
+/// Lines are split the same way as `str::lines` does it, so both `\n` and `\r\n`
+/// line endings are supported and they are not included into the yielded lines.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_lines, Lang, Options};
+/// let options = Options::default();
+/// let text = "Та нічого, все нормально. А в тебе як?\r\n12345\n";
+/// let mut lines = detect_lines(text, &options);
+///
+/// let (line, info) = lines.next().unwrap();
+/// assert_eq!(line, "Та нічого, все нормально. А в тебе як?");
+/// assert_eq!(info.unwrap().lang(), Lang::Ukr);
+///
+/// assert_eq!(lines.next(), Some(("12345", None)));
+/// assert_eq!(lines.next(), None);
+/// ```
+pub fn detect_lines<'a>(
+    text: &'a str,
+    options: &'a Options,
+) -> impl Iterator<Item = (&'a str, Option<Info>)> + 'a {
+    text.lines()
+        .map(move |line| (line, detect_with_options(line, options)))
+}
+
+/// Detect up to `n` candidate languages by a given text.
+/// Candidates are ordered from the most to the least likely one, so the first
+/// element is always the same as the result of `detect`.
+/// Languages with exactly equal scores are ordered by `Lang` (the order of declaration).
+///
+/// Confidence of every other candidate is calculated against the first one: it's 1.0
+/// minus the confidence of the first candidate over it (so the closer the scores, the higher
+/// it is), but not higher than the confidence of the first candidate.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_top_n, Lang};
+/// let infos = detect_top_n("Jen la trinkejo fermitis, ni iras tra mallumo kaj pluvo.", 3);
+/// assert_eq!(infos[0].lang(), Lang::Epo);
+/// assert!(infos.len() <= 3);
+/// ```
+pub fn detect_top_n(text: &str, n: usize) -> Vec<Info> {
+    DEFAULT_DETECTOR.detect_top_n(text, n)
+}
+
+pub fn detect_top_n_with_options(text: &str, options: &Options, n: usize) -> Vec<Info> {
+    let query = Query::new(text, options);
+    detect_top_n_by_query(&query, n)
+}
+
+/// Detect a language and a script by a given text, and return scores of all the
+/// languages that were considered for the detected script.
+/// Scores are within 0.0..1.0 and sorted in descending order,
+/// languages with exactly equal scores are ordered by `Lang` (the order of declaration).
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_with_scores, Lang, Options};
+/// let text = "There is no reason not to learn Esperanto.";
+/// let (info, scores) = detect_with_scores(text, &Options::default()).unwrap();
+/// assert_eq!(info.lang(), Lang::Eng);
+/// assert_eq!(scores[0].0, Lang::Eng);
+/// ```
+pub fn detect_with_scores(text: &str, options: &Options) -> Option<(Info, Vec<(Lang, f64)>)> {
+    let query = Query::new(text, options);
+    let mut scores = vec![];
+    match raw_detect_by_query(&query, &mut scores).ok()? {
+        RawDetection::Determined(info) => {
+            let scores = vec![(info.lang(), 1.0)];
+            Some((info, scores))
+        }
+        RawDetection::Scored { script, count } => {
+            let info = scores_to_infos(script, &scores, count).next()?;
+            Some((info, scores))
+        }
+    }
+    .filter(|(info, _)| info.confidence() >= query.min_confidence)
+}
+
+/// Detect a language and a script by a given text using the given options, and return
+/// numbers describing how the text was processed (e.g. to monitor detection in production).
+/// The detected language is the same as the one of `detect_with_options`.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_with_stats, Lang, Options};
+/// let text = "Та нічого, все нормально. А в тебе як?";
+/// let (info, stats) = detect_with_stats(text, &Options::default()).unwrap();
+/// assert_eq!(info.lang(), Lang::Ukr);
+/// assert_eq!(stats.chars(), 38);
+/// assert_eq!(stats.trigrams(), info.trigram_count());
+/// assert!(stats.alphabet_used());
+/// ```
+pub fn detect_with_stats(text: &str, options: &Options) -> Option<(Info, DetectStats)> {
+    let query = Query::new(text, options);
+    let mut scores = vec![];
+    let (info, candidates) = match raw_detect_by_query(&query, &mut scores).ok()? {
+        RawDetection::Determined(info) => (info, 1),
+        RawDetection::Scored { script, count } => {
+            let info = scores_to_infos(script, &scores, count).next()?;
+            (info, scores.len())
+        }
+    };
+    if info.confidence() < query.min_confidence {
+        return None;
+    }
+    let stats = DetectStats {
+        chars: text.chars().count(),
+        skipped_chars: text
+            .chars()
+            .filter(|&ch| is_ignored_char(ch, query.ignore_digits))
+            .count(),
+        trigrams: info.trigram_count(),
+        candidates,
+        alphabet_used: !info.is_script_determined()
+            && info.trigram_count() > 0
+            && query.method != Method::Trigram,
+    };
+    Some((info, stats))
+}
+
+/// Detect a language and a script by a given text using the given options, like
+/// `detect_with_options` does, but reuse `scratch` for the final scores of languages,
+/// so this buffer doesn't have to be allocated on every call.
+/// `scratch` is cleared at the start of each call.
+///
+/// Detection is not allocation-free: counts of characters and trigrams of the text
+/// and intermediate alphabet and trigram scores are still allocated on every call.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_into, Lang, Options};
+/// let options = Options::default();
+/// let mut scratch = Vec::new();
+/// for text in &["Guten Morgen, wie geht es dir?", "Доброе утро, как дела?"] {
+///     let info = detect_into(text, &options, &mut scratch).unwrap();
+///     assert!(info.lang() == Lang::Deu || info.lang() == Lang::Rus);
+/// }
+/// ```
+pub fn detect_into(text: &str, options: &Options, scratch: &mut Vec<(Lang, f64)>) -> Option<Info> {
+    let query = Query::new(text, options);
+    scratch.clear();
+    detect_result_by_query(&query, scratch).ok()
+}
+
 pub fn detect_by_query(query: &Query) -> Option<Info> {
+    detect_result_by_query(query, &mut vec![]).ok()
+}
+
+// `scores` is a buffer to accumulate scores of languages in.
+fn detect_result_by_query(
+    query: &Query,
+    scores: &mut Vec<(Lang, f64)>,
+) -> Result<Info, DetectError> {
+    let info = match raw_detect_by_query(query, scores)? {
+        RawDetection::Determined(info) => info,
+        RawDetection::Scored { script, count } => scores_to_infos(script, scores, count)
+            .next()
+            .ok_or(DetectError::AllCandidatesFiltered)?,
+    };
+    if info.confidence() < query.min_confidence {
+        return Err(DetectError::LowConfidence);
+    }
+    Ok(info)
+}
+
+fn detect_top_n_by_query(query: &Query, n: usize) -> Vec<Info> {
+    if n == 0 {
+        return vec![];
+    }
+    try_detect_top_n_by_query(query, n).unwrap_or_default()
+}
+
+// Returns at least one candidate, `n` must be positive.
+fn try_detect_top_n_by_query(query: &Query, n: usize) -> Result<Vec<Info>, DetectError> {
+    let mut scores = vec![];
+    let mut infos = match raw_detect_by_query(query, &mut scores)? {
+        RawDetection::Determined(info) => vec![info],
+        RawDetection::Scored { script, count } => {
+            scores_to_infos(script, &scores, count).take(n).collect()
+        }
+    };
+    if infos.is_empty() {
+        return Err(DetectError::AllCandidatesFiltered);
+    }
+    // Infos are sorted by confidence in descending order
+    infos.retain(|info| info.confidence() >= query.min_confidence);
+    if infos.is_empty() {
+        return Err(DetectError::LowConfidence);
+    }
+    Ok(infos)
+}
+
+// Outcome of detection before the most likely languages are picked.
+enum RawDetection {
+    // Language is determined without scoring other candidates.
+    Determined(Info),
+    // Scores of candidates are written into the buffer given to `raw_detect_by_query`.
+    Scored { script: Script, count: usize },
+}
+
+fn raw_detect_by_query(
+    query: &Query,
+    scores: &mut Vec<(Lang, f64)>,
+) -> Result<RawDetection, DetectError> {
+    if query.text.trim().is_empty() {
+        return Err(DetectError::EmptyInput);
+    }
+    if query.min_text_length > 0 {
+        let text_length = query.text.chars().filter(|&ch| !is_stop_char(ch)).count();
+        if text_length < query.min_text_length {
+            return Err(DetectError::TooShort);
+        }
+    }
+
+    // The fast path returns English with full confidence without scoring, so it's skipped
+    // when the options affect scores or require a minimal number of trigrams.
+    if query.fast_ascii
+        && !query.case_sensitive
+        && query.trigram_models.is_none()
+        && query.min_trigram_count == 0
+        && query.preferred_lang.is_none()
+        && MultiLangScript::Latin.is_enabled()
+        && query.allowed_langs.contains(Lang::Eng)
+        && trigrams::is_english_ascii(query.text)
+    {
+        let info = Info::new(Script::Latin, Lang::Eng, 1.0);
+        return Ok(RawDetection::Determined(info));
+    }
+
     let raw_script_info = raw_detect_script(query.text);
-    let script = raw_script_info.main_script()?;
+    let script = raw_script_info.main_script().ok_or(DetectError::NoScript)?;
 
-    match script.to_lang_group() {
-        ScriptLangGroup::One(lang) => Some(Info::new(script, lang, 1.0)),
+    let raw_detection = match script.to_lang_group() {
+        ScriptLangGroup::One(lang) => {
+            if !query.allowed_langs_of_script(script).contains(lang) {
+                return Err(DetectError::AllCandidatesFiltered);
+            }
+            let info = Info::from_script(script, lang);
+            RawDetection::Determined(with_japanese_script_detail(info, &raw_script_info))
+        }
         ScriptLangGroup::Multi(multi_lang_script) => {
-            detect_by_query_based_on_script(query, multi_lang_script)
+            if !multi_lang_script.is_enabled() {
+                return Err(DetectError::NoScript);
+            }
+            let mut iquery = query.to_internal(multi_lang_script);
+            // Alphabet scores of the candidates are scored only once, see `calculate_scores`.
+            let mut alphabet_outcome = None;
+            if let Some(max_candidates) = query.max_candidates {
+                let mut outcome = alphabets::raw_detect(&mut iquery);
+                iquery.allowed_langs = top_candidates(&outcome, max_candidates);
+                outcome.retain_langs(iquery.allowed_langs);
+                alphabet_outcome = Some(outcome);
+            }
+            let count =
+                match detect_early(&mut iquery, query, script, &mut alphabet_outcome, scores) {
+                    Some(EarlyDetection::Determined(info)) => {
+                        return Ok(RawDetection::Determined(info));
+                    }
+                    Some(EarlyDetection::Scored(count)) => count,
+                    None => calculate_scores(&mut iquery, query, alphabet_outcome, scores),
+                };
+            if let Some(preferred_lang) = query.preferred_lang {
+                nudge_preferred_lang(scores, preferred_lang, count);
+            }
+            if count < query.min_trigram_count {
+                return Err(DetectError::TooFewTrigrams);
+            }
+            RawDetection::Scored { script, count }
         }
         ScriptLangGroup::Mandarin => {
-            Some(detect_lang_base_on_mandarin_script(query, &raw_script_info))
+            let info = detect_lang_base_on_mandarin_script(query, &raw_script_info)
+                .ok_or(DetectError::AllCandidatesFiltered)?;
+            RawDetection::Determined(with_japanese_script_detail(info, &raw_script_info))
         }
+    };
+    Ok(raw_detection)
+}
+
+// Japanese is written with multiple scripts, so keep numbers of characters of all of them.
+pub(crate) fn with_japanese_script_detail(info: Info, raw_script_info: &RawScriptInfo) -> Info {
+    if info.lang() != Lang::Jpn {
+        return info;
     }
+    let detail = ScriptDetail::new(
+        raw_script_info.count(Script::Hiragana),
+        raw_script_info.count(Script::Katakana),
+        raw_script_info.count(Script::Mandarin),
+    );
+    info.with_script_detail(detail)
+}
+
+// Candidates from the most to the least likely one.
+pub(crate) fn scores_to_infos(
+    script: Script,
+    scores: &[(Lang, f64)],
+    count: usize,
+) -> impl Iterator<Item = Info> + '_ {
+    let mut infos = scores
+        .iter()
+        .enumerate()
+        // Languages that got no score at all are not candidates, except the
+        // best one which is always returned (the same way `detect` does it).
+        .take_while(|&(i, &(_, score))| i == 0 || score != 0.0)
+        .map(move |(i, &(lang, score))| {
+            // Confidence of every candidate is calculated against the best one,
+            // see `detect_top_n`.
+            let best_score = scores[0].1;
+            let best_confidence = match scores.get(1) {
+                Some(&(_, second_score)) => calculate_confidence(best_score, second_score, count),
+                None => 1.0,
+            };
+            let confidence = if i == 0 {
+                best_confidence
+            } else {
+                let closeness = 1.0 - calculate_confidence(best_score, score, count);
+                closeness.min(best_confidence)
+            };
+            Info::new(script, lang, confidence).with_trigram_count(count)
+        })
+        .peekable();
+
+    // Every candidate knows the next one, see `Info::alternative`
+    core::iter::from_fn(move || {
+        let info = infos.next()?;
+        let alternative = infos.peek().map(|next| (next.lang(), next.confidence()));
+        Some(info.with_alternative(alternative))
+    })
+}
+
+// Number of trigrams of every profile compared before the bounds of scores are checked.
+const EARLY_DETECTION_STEP: usize = 50;
+
+enum EarlyDetection {
+    // Language is guaranteed to get a confidence higher than the bar.
+    Determined(Info),
+    // All the trigrams are compared, so scores are written into the buffer
+    // (the same way `calculate_scores` does it) with the number of trigrams.
+    Scored(usize),
 }
 
-fn detect_by_query_based_on_script(
+// Compares trigrams step by step and returns the most likely language as soon as it's
+// guaranteed to get confidence higher than `confidence_bar` of the query, see `detect_until`.
+// The confidence is calculated for the lowest possible score of the language and the highest
+// possible score of any other one, so the final confidence can only be higher.
+// Returns `None` if it doesn't apply, then scores must be calculated the usual way.
+fn detect_early(
+    iquery: &mut InternalQuery,
     query: &Query,
-    multi_lang_script: MultiLangScript,
-) -> Option<Info> {
-    let mut iquery = query.to_internal(multi_lang_script);
+    script: Script,
+    alphabet_outcome: &mut Option<alphabets::RawOutcome>,
+    scores: &mut Vec<(Lang, f64)>,
+) -> Option<EarlyDetection> {
+    // Alphabet scores are cheap to calculate in full, and the preferred language
+    // may win a near-tie only after scoring (see `nudge_preferred_lang`).
+    if query.confidence_bar >= 1.0
+        || query.method == Method::Alphabet
+        || query.trigram_models.is_some()
+        || query.preferred_lang.is_some()
+    {
+        return None;
+    }
+
+    let settings = iquery.trigram_settings();
+    let trigram_positions =
+        trigrams::utils::get_trigrams_with_positions(iquery.text.lowercase(), settings)
+            .trigram_positions;
+    let count = trigram_positions.len();
+    if count < query.min_trigram_count {
+        return None;
+    }
+    let alphabet_outcome = match query.method {
+        Method::Combined => Some(
+            alphabet_outcome
+                .take()
+                .unwrap_or_else(|| alphabets::raw_detect(iquery)),
+        ),
+        _ => None,
+    };
+    let alphabet_weight = alphabet_outcome.as_ref().map_or(0.0, |outcome| {
+        query
+            .alphabet_weight
+            .unwrap_or_else(|| combined::calc_alphabet_weight(outcome.count))
+    });
+
+    let mut partial_scores = trigrams::PartialScores::new(
+        &trigram_positions,
+        iquery.multi_lang_script,
+        iquery.allowed_langs,
+    );
+    // Weighted alphabet scores in the order of `PartialScores::bounds`
+    let alphabet_parts: Vec<f64> = partial_scores
+        .bounds()
+        .map(|(lang, _, _)| {
+            alphabet_outcome
+                .iter()
+                .flat_map(|outcome| outcome.scores.iter())
+                .find(|&&(l, _)| l == lang)
+                .map_or(0.0, |&(_, score)| score * alphabet_weight)
+        })
+        .collect();
+    let trigram_weight = 1.0 - alphabet_weight;
+    let mut bounds: Vec<(Lang, f64, f64)> = Vec::with_capacity(alphabet_parts.len());
+    while partial_scores.compare_next(EARLY_DETECTION_STEP) {
+        bounds.clear();
+        bounds.extend(partial_scores.bounds().zip(&alphabet_parts).map(
+            |((lang, min, max), alphabet_part)| {
+                (
+                    lang,
+                    alphabet_part + min * trigram_weight,
+                    alphabet_part + max * trigram_weight,
+                )
+            },
+        ));
+        let leader = bounds
+            .iter()
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(core::cmp::Ordering::Less));
+        let &(lang, min_score, _) = match leader {
+            Some(leader) => leader,
+            None => break,
+        };
+        let max_other_score = bounds
+            .iter()
+            .filter(|&&(l, _, _)| l != lang)
+            .map(|&(_, _, max)| max)
+            .fold(0.0, f64::max);
+        // The only candidate always gets confidence 1.0 after scoring, and close languages
+        // may be merged after scoring (see `is_close_lang`).
+        if bounds.len() < 2 || trigrams::is_close_lang(lang) || min_score <= max_other_score {
+            continue;
+        }
+        let confidence = calculate_confidence(min_score, max_other_score, count);
+        if confidence > query.confidence_bar {
+            let info = Info::new(script, lang, confidence).with_trigram_count(count);
+            return Some(EarlyDetection::Determined(info));
+        }
+    }
+    let trigram_outcome = partial_scores.into_outcome();
+    scores.clear();
+    let count = match alphabet_outcome {
+        Some(alphabet_outcome) => {
+            write_combined_scores(query, &alphabet_outcome, &trigram_outcome, scores)
+        }
+        None => {
+            scores.extend(trigram_outcome.scores);
+            trigram_outcome.trigrams_count
+        }
+    };
+    Some(EarlyDetection::Scored(count))
+}
+
+// Writes scores of languages sorted in descending order into `scores` and returns
+// number of chars or trigrams the scores are based on. `alphabet_outcome` is used instead
+// of scoring alphabets again, if they are already scored.
+fn calculate_scores(
+    iquery: &mut InternalQuery,
+    query: &Query,
+    alphabet_outcome: Option<alphabets::RawOutcome>,
+    scores: &mut Vec<(Lang, f64)>,
+) -> usize {
+    scores.clear();
     match query.method {
-        Method::Alphabet => alphabets::detect(&mut iquery),
-        Method::Trigram => trigrams::detect(&mut iquery),
-        Method::Combined => combined::detect(&mut iquery),
+        Method::Alphabet => {
+            let outcome = alphabet_outcome.unwrap_or_else(|| alphabets::raw_detect(iquery));
+            scores.extend(outcome.scores);
+            outcome.count
+        }
+        Method::Trigram => {
+            let outcome = trigrams::raw_detect(iquery);
+            scores.extend(outcome.scores);
+            outcome.trigrams_count
+        }
+        Method::Combined => {
+            let alphabet_outcome =
+                alphabet_outcome.unwrap_or_else(|| alphabets::raw_detect(iquery));
+            let trigram_outcome = trigrams::raw_detect(iquery);
+            write_combined_scores(query, &alphabet_outcome, &trigram_outcome, scores)
+        }
     }
 }
 
+// Languages with the highest alphabet scores, see `Options::set_max_candidates`.
+// Raw scores are sorted, and all the languages with the same score as the last candidate
+// are kept too, so which of them are dropped doesn't depend on the order of `Lang`.
+pub(crate) fn top_candidates(
+    alphabet_outcome: &alphabets::RawOutcome,
+    max_candidates: usize,
+) -> LangSet {
+    let raw_scores = &alphabet_outcome.raw_scores;
+    let min_score = raw_scores
+        .get(max_candidates.saturating_sub(1))
+        .map_or(0, |&(_, score)| score);
+    let langs = raw_scores
+        .iter()
+        .take_while(|&&(_, score)| score >= min_score)
+        .map(|&(lang, _)| lang);
+    LangSet::from_langs(langs)
+}
+
+// Relative raise of the score of the preferred language, see `Options::set_preferred`.
+const PREFERRED_LANG_NUDGE: f64 = 0.05;
+
+// Raises the score of the preferred language by `PREFERRED_LANG_NUDGE` if the best language
+// is not confidently better than it, and moves it up in the sorted `scores` accordingly.
+// The preferred language wins ties.
+pub(crate) fn nudge_preferred_lang(scores: &mut [(Lang, f64)], preferred_lang: Lang, count: usize) {
+    let best_score = match scores.first() {
+        Some(&(lang, score)) if lang != preferred_lang => score,
+        _ => return,
+    };
+    let mut index = match scores.iter().position(|&(lang, _)| lang == preferred_lang) {
+        Some(index) => index,
+        None => return,
+    };
+    let score = scores[index].1;
+    if score == 0.0 || calculate_confidence(best_score, score, count) >= 1.0 {
+        return;
+    }
+    scores[index].1 = (score * (1.0 + PREFERRED_LANG_NUDGE)).min(1.0);
+    while index > 0 && scores[index - 1].1 <= scores[index].1 {
+        scores.swap(index - 1, index);
+        index -= 1;
+    }
+}
+
+// Blends alphabet and trigram scores into `scores` with the weight from the query
+// and returns number of trigrams the scores are based on.
+pub(crate) fn write_combined_scores(
+    query: &Query,
+    alphabet_outcome: &alphabets::RawOutcome,
+    trigram_outcome: &trigrams::RawOutcome,
+    scores: &mut Vec<(Lang, f64)>,
+) -> usize {
+    let alphabet_weight = query
+        .alphabet_weight
+        .unwrap_or_else(|| combined::calc_alphabet_weight(alphabet_outcome.count));
+    combined::combine_scores(
+        &alphabet_outcome.scores,
+        &trigram_outcome.scores,
+        alphabet_weight,
+        scores,
+    );
+    trigram_outcome.trigrams_count
+}
+
 // Sometimes Mandarin can be Japanese.
 // See https://github.com/greyblake/whatlang-rs/pull/45
+// `None` is returned if neither Mandarin nor Japanese is allowed.
 pub(crate) fn detect_lang_base_on_mandarin_script(
     query: &Query,
     raw_script_info: &RawScriptInfo,
-) -> Info {
-    let (lang, confidence) = if query.filter_list.is_allowed(Lang::Cmn) {
+) -> Option<Info> {
+    let allowed_langs = query.allowed_langs_of_script(Script::Mandarin);
+    let (lang, confidence) = if allowed_langs.contains(Lang::Cmn) {
         let mandrin_count = raw_script_info.count(Script::Mandarin);
         let katakana_count = raw_script_info.count(Script::Katakana);
         let hiragana_count = raw_script_info.count(Script::Hiragana);
@@ -90,13 +774,27 @@ pub(crate) fn detect_lang_base_on_mandarin_script(
             (Lang::Jpn, 0.5)
         } else if jpn_pct > 0.02 {
             (Lang::Cmn, 0.5)
+        } else if japanese_count == 0 && allowed_langs.contains(Lang::Jpn) {
+            han_bias_lang(query.han_bias)
         } else {
             (Lang::Cmn, 1.0)
         }
-    } else {
+    } else if allowed_langs.contains(Lang::Jpn) {
         (Lang::Jpn, 1.0)
+    } else {
+        return None;
     };
-    Info::new(Script::Mandarin, lang, confidence)
+    Some(Info::new(Script::Mandarin, lang, confidence))
+}
+
+// Text written only with Han characters can be both Mandarin and Japanese,
+// so the preference set in options decides.
+fn han_bias_lang(han_bias: f64) -> (Lang, f64) {
+    if han_bias > 0.5 {
+        (Lang::Jpn, han_bias * 2.0 - 1.0)
+    } else {
+        (Lang::Cmn, 1.0 - han_bias * 2.0)
+    }
 }
 
 #[cfg(test)]
@@ -104,6 +802,7 @@ mod tests {
     use super::*;
     use crate::core::FilterList;
     use crate::scripts::Script;
+    use crate::trigrams::{extract_trigrams, Trigram};
 
     #[test]
     fn test_detect_spanish() {
@@ -116,6 +815,529 @@ mod tests {
         assert_eq!(info.script(), Script::Latin);
     }
 
+    #[test]
+    fn test_detect_greek() {
+        let text = "Η ελληνική γλώσσα είναι μία από τις αρχαιότερες γλώσσες της Ευρώπης.";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Ell);
+        assert_eq!(info.script(), Script::Greek);
+        assert!(info.is_script_determined());
+
+        // Polytonic orthography uses characters from the Greek Extended block
+        let info = detect("Ἐν ἀρχῇ ἦν ὁ λόγος").unwrap();
+        assert_eq!(info.lang(), Lang::Ell);
+        assert_eq!(info.script(), Script::Greek);
+    }
+
+    #[test]
+    fn test_detect_armenian() {
+        let info = detect("Բարեւ Ձեզ, ինչպե՞ս եք").unwrap();
+        assert_eq!(info.lang(), Lang::Hye);
+        assert_eq!(info.script(), Script::Armenian);
+        assert!(info.is_script_determined());
+    }
+
+    #[test]
+    fn test_detect_sinhala() {
+        let info = detect("ශ්‍රී ලංකාවේ ප්‍රධාන ජාතිය වන සිංහල ජනයාගේ මව් බස").unwrap();
+        assert_eq!(info.lang(), Lang::Sin);
+        assert_eq!(info.script(), Script::Sinhala);
+        assert_eq!(info.confidence(), 1.0);
+    }
+
+    #[test]
+    fn test_detect_burmese() {
+        // Burmese is written without spaces between words
+        let info = detect("မြန်မာဘာသာစကားသည်မြန်မာနိုင်ငံ၏ရုံးသုံးဘာသာစကားဖြစ်သည်။").unwrap();
+        assert_eq!(info.lang(), Lang::Mya);
+        assert_eq!(info.script(), Script::Myanmar);
+        assert_eq!(info.confidence(), 1.0);
+    }
+
+    #[test]
+    fn test_detect_khmer() {
+        // Khmer is written without spaces between words
+        let info = detect("ភាសាខ្មែរគឺជាភាសារបស់ប្រជាជាតិខ្មែរ។").unwrap();
+        assert_eq!(info.lang(), Lang::Khm);
+        assert_eq!(info.script(), Script::Khmer);
+        assert_eq!(info.confidence(), 1.0);
+    }
+
+    #[test]
+    fn test_detect_georgian() {
+        let info = detect("გამარჯობა").unwrap();
+        assert_eq!(info.lang(), Lang::Kat);
+        assert_eq!(info.script(), Script::Georgian);
+        assert_eq!(info.confidence(), 1.0);
+    }
+
+    #[test]
+    fn test_detect_amharic() {
+        // Amharic is the only language of Ethiopic script, so it's not scored
+        let info = detect("አማርኛ የኢትዮጵያ መደበኛ ቋንቋ ነው።").unwrap();
+        assert_eq!(info.lang(), Lang::Amh);
+        assert_eq!(info.script(), Script::Ethiopic);
+        assert_eq!(info.confidence(), 1.0);
+        assert!(info.is_script_determined());
+    }
+
+    #[test]
+    fn test_detect_korean() {
+        let info = detect("저는 매일 아침 학교에 갑니다").unwrap();
+        assert_eq!(info.lang(), Lang::Kor);
+        assert_eq!(info.script(), Script::Hangul);
+
+        // Hanja is used only occasionally, so Hangul is the main script
+        let info = detect("大韓民國 憲法 第1條 대한민국은 민주공화국이다").unwrap();
+        assert_eq!(info.lang(), Lang::Kor);
+        assert_eq!(info.script(), Script::Hangul);
+
+        // Halfwidth Katakana is not Hangul
+        let info = detect("ｶﾀｶﾅ ﾃﾞｽ").unwrap();
+        assert_eq!(info.lang(), Lang::Jpn);
+        assert_eq!(info.script(), Script::Katakana);
+    }
+
+    #[test]
+    fn test_detect_script_determined_confidence() {
+        // There is no other candidate for a script used only by one language,
+        // so the confidence is 1.0 regardless of the method.
+        let texts = [
+            "Η ελληνική γλώσσα είναι μία από τις αρχαιότερες γλώσσες της Ευρώπης.",
+            "ქართული ენა მსოფლიო",
+        ];
+        for &text in &texts {
+            for &method in &[Method::Alphabet, Method::Trigram, Method::Combined] {
+                let mut options = Options::new();
+                options.method = method;
+                let info = detect_with_options(text, &options).unwrap();
+                assert!(info.is_script_determined());
+                assert_eq!(info.confidence(), 1.0);
+                assert!(info.is_reliable());
+            }
+        }
+
+        let infos = detect_top_n_with_options(texts[0], &Options::new(), 3);
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].lang(), Lang::Ell);
+        assert_eq!(infos[0].confidence(), 1.0);
+    }
+
+    #[test]
+    fn test_detect_is_script_determined() {
+        let info = detect("ქართული ენა მსოფლიო").unwrap();
+        assert_eq!(info.lang(), Lang::Kat);
+        assert!(info.is_script_determined());
+
+        let info = detect("Та нічого, все нормально. А в тебе як?").unwrap();
+        assert!(!info.is_script_determined());
+
+        let info = detect("県見夜上温国阪題富販").unwrap();
+        assert!(!info.is_script_determined());
+    }
+
+    #[test]
+    fn test_detect_with_scores() {
+        let text = "Además de todo lo anteriormente dicho, también encontramos...";
+        let (info, scores) = detect_with_scores(text, &Options::default()).unwrap();
+        assert_eq!(Some(&info), detect(text).as_ref());
+        assert_eq!(scores.len(), Script::Latin.langs().len());
+        assert_eq!(scores[0].0, info.lang());
+        for pair in scores.windows(2) {
+            assert!(pair[0].1 >= pair[1].1);
+        }
+
+        let (info, scores) = detect_with_scores("ქართული ენა", &Options::default()).unwrap();
+        assert_eq!(info.lang(), Lang::Kat);
+        assert_eq!(scores, vec![(Lang::Kat, 1.0)]);
+
+        assert_eq!(detect_with_scores("12345", &Options::default()), None);
+    }
+
+    #[test]
+    fn test_detect_with_stats() {
+        let options = Options::default();
+        let text = "Además de todo lo anteriormente dicho, también encontramos...";
+        let (info, stats) = detect_with_stats(text, &options).unwrap();
+        assert_eq!(Some(&info), detect(text).as_ref());
+        assert_eq!(stats.chars(), text.chars().count());
+        // 7 spaces, a comma and 3 dots
+        assert_eq!(stats.skipped_chars(), 11);
+        assert_eq!(stats.trigrams(), info.trigram_count());
+        assert!(stats.trigrams() > 0);
+        assert_eq!(stats.candidates(), Script::Latin.langs().len());
+        assert!(stats.alphabet_used());
+
+        let options = Options::new().set_filter_list(FilterList::deny(vec![Lang::Spa]));
+        let (_, stats) = detect_with_stats(text, &options).unwrap();
+        assert_eq!(stats.candidates(), Script::Latin.langs().len() - 1);
+
+        let (info, stats) = detect_with_stats("ქართული ენა 2024", &Options::default()).unwrap();
+        assert_eq!(info.lang(), Lang::Kat);
+        assert_eq!(stats.chars(), 16);
+        assert_eq!(stats.skipped_chars(), 6);
+        assert_eq!(stats.trigrams(), 0);
+        assert_eq!(stats.candidates(), 1);
+        assert!(!stats.alphabet_used());
+
+        assert_eq!(detect_with_stats("12345", &Options::default()), None);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_max_candidates() {
+        let texts = [
+            "Además de todo lo anteriormente dicho, también encontramos...",
+            "Dit is een Nederlandse zin zonder speciale letters.",
+            "Та нічого, все нормально. А в тебе як?",
+            "मैं आज बाज़ार जा रहा हूँ",
+        ];
+        let options = Options::new().set_max_candidates(Lang::all().len());
+        for text in &texts {
+            assert_eq!(
+                detect_with_scores(text, &options),
+                detect_with_scores(text, &Options::default())
+            );
+        }
+
+        let text = "Además de todo lo anteriormente dicho, también encontramos...";
+        let options = Options::new().set_max_candidates(2);
+        let (info, stats) = detect_with_stats(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Spa);
+        // Other languages can have the same alphabet score as the second one
+        assert!(stats.candidates() >= 2);
+        assert!(stats.candidates() < Script::Latin.langs().len());
+
+        // Languages with the same alphabet score as the last candidate are candidates too,
+        // so plain ASCII text is not detected as whichever languages go first in `Lang`
+        let ascii_text = "Dit is een Nederlandse zin zonder speciale letters.";
+        for &max_candidates in &[3, 5, 10] {
+            let options = Options::new().set_max_candidates(max_candidates);
+            let info = detect_with_options(ascii_text, &options).unwrap();
+            assert_eq!(info.lang(), Lang::Nld);
+        }
+
+        // At least one language is always scored ("ß" is only in the alphabet of German)
+        let options = Options::new().set_max_candidates(0);
+        let (info, scores) =
+            detect_with_scores("Die Straße ist groß und schön.", &options).unwrap();
+        assert_eq!(info.lang(), Lang::Deu);
+        assert_eq!(scores.len(), 1);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_min_text_length() {
+        let options = Options::new().set_min_text_length(5);
+        assert_eq!(detect_with_options("fdf", &options), None);
+        assert_eq!(detect_with_options("- fdf, 123!", &options), None);
+        assert_eq!(detect_with_options("ქართ", &options), None);
+
+        let info = detect_with_options("Hello world", &options).unwrap();
+        assert_eq!(info.script(), Script::Latin);
+
+        // No minimum by default
+        assert!(detect("fdf").is_some());
+    }
+
+    #[test]
+    fn test_detect_reliable() {
+        let text = "Мой дядя самых честных правил, когда не в шутку занемог, он уважать себя заставил и лучше выдумать не мог.";
+        let info = detect_reliable(text).unwrap();
+        assert_eq!(info.lang(), Lang::Rus);
+        assert_eq!(Some(info), detect(text));
+
+        // Random texts are detected as some language, but never reliably
+        let random_texts = [
+            "qwzx vbnm pkjh",
+            "sdfsdf sdfsdf",
+            "xkcd ptrq zvbw lmno fghj",
+            "aaaaaa bbbbbb",
+            "lkjh gfds wert yuio",
+            "Пвыф йцук фыва",
+        ];
+        for text in random_texts {
+            assert!(detect(text).is_some());
+            assert_eq!(detect_reliable(text), None);
+        }
+
+        let options = Options::new().set_filter_list(FilterList::deny(vec![Lang::Rus]));
+        assert_eq!(detect_reliable_with_options(text, &options), None);
+    }
+
+    #[test]
+    fn test_detect_chars() {
+        let options = Options::default();
+        let texts = [
+            "Та нічого, все нормально. А в тебе як?",
+            "There is no reason not to learn Esperanto.",
+            "ქართული ენა",
+            "12345",
+            "",
+        ];
+        for text in texts {
+            assert_eq!(
+                detect_chars(text.chars(), &options),
+                detect_with_options(text, &options)
+            );
+        }
+
+        let options = Options::new().set_filter_list(FilterList::deny(vec![Lang::Ukr]));
+        let text = "Та нічого, все нормально. А в тебе як?";
+        let info = detect_chars(text.chars(), &options).unwrap();
+        assert_ne!(info.lang(), Lang::Ukr);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_min_trigram_count() {
+        let options = Options::new().set_min_trigram_count(5);
+        assert_eq!(detect_with_options("fdf", &options), None);
+        assert_eq!(
+            detect_result("fdf", &options),
+            Err(DetectError::TooFewTrigrams)
+        );
+        // Long enough, but repeated characters give few trigrams
+        assert_eq!(detect_with_options("aaaa aaaa aaaa", &options), None);
+        assert!(detect_top_n_with_options("fdf", &options, 3).is_empty());
+
+        let info = detect_with_options("Hello world", &options).unwrap();
+        assert!(info.trigram_count() >= 5);
+
+        // The language is determined by the script
+        assert!(detect_with_options("ქართ", &options).is_some());
+
+        // No minimum by default
+        assert!(detect("fdf").is_some());
+    }
+
+    #[test]
+    fn test_detect_result() {
+        let options = Options::default();
+        let info = detect_result("Та нічого, все нормально. А в тебе як?", &options).unwrap();
+        assert_eq!(info.lang(), Lang::Ukr);
+
+        assert_eq!(detect_result("", &options), Err(DetectError::EmptyInput));
+        assert_eq!(
+            detect_result(" \n\t", &options),
+            Err(DetectError::EmptyInput)
+        );
+        assert_eq!(detect_result("12345", &options), Err(DetectError::NoScript));
+
+        let options = Options::new().set_min_text_length(10);
+        assert_eq!(detect_result("Hello", &options), Err(DetectError::TooShort));
+        let options = Options::new().set_min_text_length(5);
+        assert_eq!(detect_result("ab", &options), Err(DetectError::TooShort));
+        assert_eq!(detect_result("", &options), Err(DetectError::EmptyInput));
+
+        let options =
+            Options::new().set_filter_list(FilterList::deny(Script::Cyrillic.langs().to_owned()));
+        assert_eq!(
+            detect_result("Кириллица", &options),
+            Err(DetectError::AllCandidatesFiltered)
+        );
+
+        let options = Options::new().set_min_confidence(1.0);
+        assert_eq!(
+            detect_result("Jeg bor i Oslo", &options),
+            Err(DetectError::LowConfidence)
+        );
+    }
+
+    #[test]
+    fn test_detect_with_options_with_fast_ascii() {
+        let options = Options::new().set_fast_ascii(true);
+        let texts = [
+            "I think that it is going to rain today, so you should take an umbrella with you.",
+            "De snelle bruine vos springt over de luie hond en rent dan het bos in.",
+            "Error: could not connect to database server at localhost port 5432",
+            "Hello",
+        ];
+        for text in texts {
+            let expected = detect(text).map(|info| info.lang());
+            let actual = detect_with_options(text, &options).map(|info| info.lang());
+            assert_eq!(actual, expected, "{}", text);
+        }
+
+        // English is not returned when it's not allowed
+        let text =
+            "I think that it is going to rain today, so you should take an umbrella with you.";
+        let options = options.set_filter_list(FilterList::deny(vec![Lang::Eng]));
+        let info = detect_with_options(text, &options).unwrap();
+        assert_ne!(info.lang(), Lang::Eng);
+
+        // Options that affect scoring disable the fast path
+        let all_options = [
+            Options::new().set_min_trigram_count(1000),
+            Options::new().set_preferred(Lang::Deu),
+            Options::new().set_trigram_models(vec![(Lang::Eng, vec![])]),
+        ];
+        for options in all_options.iter() {
+            let fast_options = options.clone().set_fast_ascii(true);
+            assert_eq!(
+                detect_with_options(text, &fast_options),
+                detect_with_options(text, options)
+            );
+        }
+        let options = Options::new()
+            .set_min_trigram_count(1000)
+            .set_fast_ascii(true);
+        assert_eq!(detect_with_options(text, &options), None);
+    }
+
+    #[test]
+    fn test_detect_mixed_case() {
+        let texts = [
+            "Además de todo lo ANTERIORMENTE dicho, también encontramos...",
+            "Та Нічого, Все Нормально. А В Тебе Як?",
+            "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG",
+        ];
+        for text in texts {
+            let lowercase = text.to_lowercase();
+            assert_eq!(detect(text), detect(&lowercase), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_detect_trigram_count() {
+        let info = detect("Jen la trinkejo fermitis").unwrap();
+        // 21 trigrams within the words and 3 trigrams spanning over the spaces ("n l", etc)
+        assert_eq!(info.trigram_count(), 24);
+
+        let longer = detect("Jen la trinkejo fermitis, ni iras tra mallumo kaj pluvo.").unwrap();
+        assert!(longer.trigram_count() > info.trigram_count());
+
+        assert_eq!(detect("ქართული ენა").unwrap().trigram_count(), 0);
+    }
+
+    #[test]
+    fn test_detect_japanese_script_detail() {
+        let info = detect("テレビを見ながら、晩ご飯を食べました").unwrap();
+        assert_eq!(info.lang(), Lang::Jpn);
+        assert_eq!(info.script(), Script::Hiragana);
+        assert_eq!(info.script_detail(), Some(ScriptDetail::new(10, 3, 4)));
+
+        // Mostly Kanji
+        let info = detect("東京都庁は新宿区にある").unwrap();
+        assert_eq!(info.lang(), Lang::Jpn);
+        assert_eq!(info.script(), Script::Mandarin);
+        assert_eq!(info.script_detail(), Some(ScriptDetail::new(4, 0, 7)));
+
+        assert_eq!(
+            detect("县见夜上温国阪题富贩").unwrap().script_detail(),
+            None
+        );
+        assert_eq!(detect("Hello world").unwrap().script_detail(), None);
+    }
+
+    #[test]
+    fn test_detect_into() {
+        let options = Options::default();
+        let mut scratch = vec![(Lang::Epo, 0.5)];
+        let texts = [
+            "Та нічого, все нормально. А в тебе як?",
+            "Además de todo lo anteriormente dicho, también encontramos...",
+            "ქართული ენა",
+            "12345",
+        ];
+        for text in texts {
+            assert_eq!(
+                detect_into(text, &options, &mut scratch),
+                detect_with_options(text, &options)
+            );
+        }
+        // Cleared at the start of every call
+        assert!(scratch.is_empty());
+    }
+
+    #[test]
+    fn test_detect_bytes() {
+        let options = Options::default();
+        let text = "Мы хотим видеть дальше, чем окна дома напротив";
+        let info = detect_bytes(text.as_bytes(), &options).unwrap();
+        assert_eq!(info.lang(), Lang::Rus);
+        assert_eq!(Some(info), detect_with_options(text, &options));
+
+        // "Ceci est une phrase très simple en français" in Latin-1
+        let latin1: Vec<u8> = "Ceci est une phrase très simple en français"
+            .chars()
+            .map(|ch| ch as u8)
+            .collect();
+        let info = detect_bytes(&latin1, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Fra);
+
+        assert_eq!(detect_bytes(&[], &options), None);
+        assert_eq!(detect_bytes(&[0xff, 0xfe], &options), None);
+    }
+
+    #[test]
+    fn test_detect_or() {
+        let text = "Мы хотим видеть дальше, чем окна дома напротив";
+        assert_eq!(detect_or(text, Lang::Eng), Lang::Rus);
+        assert_eq!(detect_or_default(text), Lang::Rus);
+
+        // Digits and punctuation have no script
+        let text = "#4%7 (1-800) 555-0199 !? 42";
+        assert_eq!(detect_lang(text), None);
+        assert_eq!(detect_or(text, Lang::Deu), Lang::Deu);
+        assert_eq!(detect_or_default(text), Lang::Eng);
+        assert_eq!(detect_or_default(""), Lang::Eng);
+    }
+
+    #[test]
+    fn test_detect_batch() {
+        let texts = [
+            "Та нічого, все нормально. А в тебе як?",
+            "",
+            "Además de todo lo anteriormente dicho, también encontramos...",
+        ];
+        let filter_list = FilterList::deny(vec![Lang::Rus]);
+        let options = Options::new().set_filter_list(filter_list);
+
+        let expected: Vec<Option<Info>> = texts
+            .iter()
+            .map(|text| detect_with_options(text, &options))
+            .collect();
+        assert_eq!(detect_batch(&texts, &options), expected);
+        assert_eq!(detect_batch(&[], &options), vec![]);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_min_confidence() {
+        let text = "Jeg bor i Oslo";
+        assert!(detect(text).unwrap().confidence() < 0.9);
+
+        let options = Options::new().set_min_confidence(0.9);
+        assert_eq!(detect_with_options(text, &options), None);
+        assert_eq!(detect_with_scores(text, &options), None);
+        assert_eq!(detect_top_n_with_options(text, &options, 3), vec![]);
+
+        let options = Options::new().set_min_confidence(0.3);
+        let info = detect_with_options(text, &options).unwrap();
+        assert!(info.confidence() >= 0.3);
+
+        // Threshold is clamped to 1.0
+        let options = Options::new().set_min_confidence(2.0);
+        assert!(detect_with_options("ქართული ენა", &options).is_some());
+    }
+
+    #[test]
+    fn test_detect_lines() {
+        let options = Options::default();
+        let text = "Та нічого, все нормально. А в тебе як?\n\r\nAdemás de todo lo anteriormente dicho, también encontramos...\r\n";
+        let lines: Vec<(&str, Option<Lang>)> = detect_lines(text, &options)
+            .map(|(line, info)| (line, info.map(|i| i.lang())))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                ("Та нічого, все нормально. А в тебе як?", Some(Lang::Ukr)),
+                ("", None),
+                (
+                    "Además de todo lo anteriormente dicho, también encontramos...",
+                    Some(Lang::Spa)
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_detect_lang_ukrainian() {
         let text = "Та нічого, все нормально. А в тебе як?";
@@ -142,6 +1364,340 @@ mod tests {
         assert_eq!(info.lang(), Lang::Eng);
     }
 
+    #[test]
+    fn test_detect_with_options_with_alphabet_weight() {
+        // Only Polish has all of these characters
+        let text = "Zażółć gęślą jaźń";
+        let options = Options::new().set_alphabet_weight(1.0);
+        let (info, scores) = detect_with_scores(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Pol);
+        assert_eq!(scores[0], (Lang::Pol, 1.0));
+
+        let options = Options::new().set_alphabet_weight(0.0);
+        let (_, trigram_scores) = detect_with_scores(text, &options).unwrap();
+        assert_ne!(trigram_scores, scores);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_boundary_padding() {
+        let text = "Та нічого, все нормально. А в тебе як?";
+        let info = detect(text).unwrap();
+        assert_eq!(info.trigram_count(), 33);
+
+        let options = Options::new().set_boundary_padding(false);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Ukr);
+        assert_eq!(info.trigram_count(), 14);
+    }
+
+    #[test]
+    #[cfg(not(feature = "script-hebrew"))]
+    fn test_detect_with_disabled_script() {
+        let text = "שלום, מה שלומך היום?";
+        assert_eq!(crate::detect_script(text), Some(Script::Hebrew));
+        assert_eq!(
+            detect_result(text, &Options::new()),
+            Err(DetectError::NoScript)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "script-arabic")]
+    fn test_detect_with_options_with_ignore_digits() {
+        let options = Options::new().set_ignore_digits(true);
+
+        // Arabic-Indic digits are ignored the same way as ASCII digits
+        let ascii_digits_info = detect("رقم 0123456789 اتصل").unwrap();
+        let info = detect_with_options("رقم ٠١٢٣٤٥٦٧٨٩ اتصل", &options).unwrap();
+        assert_eq!(info, ascii_digits_info);
+        let info = detect("رقم ٠١٢٣٤٥٦٧٨٩ اتصل").unwrap();
+        assert_ne!(info.trigram_count(), ascii_digits_info.trigram_count());
+
+        // ASCII digits are always ignored
+        let text = "100 руб. спасибо";
+        assert_eq!(detect_with_options(text, &options), detect(text));
+        assert_eq!(detect(text), detect("руб. спасибо"));
+
+        // Only digits of the script, so there are no trigrams at all
+        let info = detect_with_options("٠١٢٣", &options).unwrap();
+        assert_eq!(info.script(), Script::Arabic);
+        assert_eq!(info.trigram_count(), 0);
+        assert_eq!(info.confidence(), 0.0);
+    }
+
+    #[test]
+    fn test_detect_alternative() {
+        let text = "Та нічого, все нормально. А в тебе як?";
+        let info = detect(text).unwrap();
+        let infos = detect_top_n(text, 2);
+        assert_eq!(infos[0], info);
+        assert_eq!(
+            info.alternative(),
+            Some((infos[1].lang(), infos[1].confidence().value()))
+        );
+
+        // The alternative is compared with the detected language, not with the third one
+        let (_, scores) = detect_with_scores(text, &Options::default()).unwrap();
+        let closeness = 1.0 - calculate_confidence(scores[0].1, scores[1].1, info.trigram_count());
+        let expected = closeness.min(info.confidence().value());
+        assert_eq!(info.alternative(), Some((scores[1].0, expected)));
+
+        let options = Options::new().set_filter_list(FilterList::allow(vec![Lang::Ukr]));
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Ukr);
+        assert_eq!(info.alternative(), None);
+
+        assert_eq!(detect("ქართული ენა").unwrap().alternative(), None);
+    }
+
+    #[test]
+    fn test_detect_with_bom_and_zero_width_chars() {
+        let text = "Мы хотим видеть дальше, чем окна дома напротив";
+        let info = detect(text).unwrap();
+
+        let with_bom = detect(&format!("\u{FEFF}{}", text)).unwrap();
+        assert_eq!(with_bom.lang(), Lang::Rus);
+        assert_eq!(with_bom, info);
+
+        let with_zero_width_spaces = detect("\u{FEFF}Мы\u{200B} хотим видеть\u{200D}").unwrap();
+        assert_eq!(with_zero_width_spaces.lang(), Lang::Rus);
+        assert_eq!(
+            detect_result("\u{FEFF}\u{200B}", &Options::new()),
+            Err(DetectError::NoScript)
+        );
+    }
+
+    #[test]
+    fn test_detect_ukrainian_vs_russian_on_short_texts() {
+        // Trigrams alone are not enough for such short texts,
+        // but the letters і, ї and є are used in Ukrainian and not in Russian
+        let trigrams_only = Options::new().set_alphabet_weight(0.0);
+        for &text in &["Їжак і єнот", "Привіт"] {
+            let info = detect_with_options(text, &trigrams_only).unwrap();
+            assert_ne!(info.lang(), Lang::Ukr);
+
+            let info = detect(text).unwrap();
+            assert_eq!(info.lang(), Lang::Ukr);
+        }
+
+        let info = detect("Съешь же ещё").unwrap();
+        assert_eq!(info.lang(), Lang::Rus);
+    }
+
+    #[test]
+    fn test_detect_bengali() {
+        let info = detect("আমি বাংলায় গান গাই। বাংলা আমার মাতৃভাষা।").unwrap();
+        assert_eq!(info.script(), Script::Bengali);
+        assert_eq!(info.lang(), Lang::Ben);
+    }
+
+    #[test]
+    fn test_detect_tamil() {
+        let info = detect("தமிழ் ஒரு பழமையான மொழி").unwrap();
+        assert_eq!(info.script(), Script::Tamil);
+        assert_eq!(info.lang(), Lang::Tam);
+        assert!(info.is_script_determined());
+    }
+
+    #[test]
+    fn test_detect_south_indian_languages() {
+        let examples = [
+            ("తెలుగు ఒక ద్రావిడ భాష", Script::Telugu, Lang::Tel),
+            ("ಕನ್ನಡ ಒಂದು ದ್ರಾವಿಡ ಭಾಷೆ", Script::Kannada, Lang::Kan),
+            ("മലയാളം ഒരു ദ്രാവിഡ ഭാഷയാണ്", Script::Malayalam, Lang::Mal),
+        ];
+        for (text, script, lang) in examples {
+            let info = detect(text).unwrap();
+            assert_eq!(info.script(), script);
+            assert_eq!(info.lang(), lang);
+            assert!(info.is_script_determined());
+        }
+    }
+
+    #[test]
+    fn test_detect_thai() {
+        // Thai is written without spaces between words
+        let info = detect("ฉันชอบกินข้าวผัดกับต้มยำกุ้งทุกวัน").unwrap();
+        assert_eq!(info.script(), Script::Thai);
+        assert_eq!(info.lang(), Lang::Tha);
+        assert!(info.is_script_determined());
+    }
+
+    #[test]
+    fn test_detect_vietnamese() {
+        let text = "Mẹ tôi nấu ăn rất ngon";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Vie);
+        assert_eq!(info.confidence(), 1.0);
+
+        // The same text decomposed (NFD): tone marks are combining characters
+        let text = "Me\u{0323} to\u{0302}i na\u{0302}\u{0301}u a\u{0306}n ra\u{0302}\u{0301}t ngon";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Vie);
+        assert!(info.is_reliable());
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn test_detect_with_options_with_normalize_unicode() {
+        let nfc = "Où est la bibliothèque? Je voudrais lire un roman français.";
+        let nfd =
+            "Ou\u{300} est la bibliothe\u{300}que? Je voudrais lire un roman franc\u{327}ais.";
+        let options = Options::default();
+        assert_ne!(
+            detect_with_scores(nfd, &options),
+            detect_with_scores(nfc, &options)
+        );
+
+        let options = Options::new().set_normalize_unicode(true);
+        let info = detect_with_options(nfd, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Fra);
+        assert_eq!(
+            detect_with_scores(nfd, &options),
+            detect_with_scores(nfc, &options)
+        );
+        assert_eq!(Some(info), detect(nfc));
+    }
+
+    #[test]
+    fn test_detect_lao() {
+        // Lao letters look like Thai ones, but they are in a separate block
+        let info = detect("ຂ້ອຍມັກກິນເຂົ້າໜຽວກັບຕຳໝາກຫຸ່ງ").unwrap();
+        assert_eq!(info.script(), Script::Lao);
+        assert_eq!(info.lang(), Lang::Lao);
+        assert!(info.is_script_determined());
+
+        let info = detect("ฉันชอบกินข้าวเหนียวกับส้มตำ").unwrap();
+        assert_eq!(info.lang(), Lang::Tha);
+    }
+
+    #[test]
+    #[cfg(feature = "script-hebrew")]
+    fn test_detect_yiddish_vs_hebrew() {
+        let info = detect("מיר װױנען אין אַ קלײנעם שטעטל").unwrap();
+        assert_eq!(info.lang(), Lang::Yid);
+
+        // Too short for trigrams to tell, but the double letters are used only in Yiddish
+        let info = detect("װי גײט עס").unwrap();
+        assert_eq!(info.lang(), Lang::Yid);
+
+        let info = detect("אני אוהב לקרוא ספרים").unwrap();
+        assert_eq!(info.lang(), Lang::Heb);
+    }
+
+    #[test]
+    #[cfg(feature = "script-arabic")]
+    fn test_detect_arabic_vs_persian_vs_urdu() {
+        let info = detect("من هر روز صبح چای می‌نوشم و کتاب می‌خوانم").unwrap();
+        assert_eq!(info.lang(), Lang::Pes);
+
+        let info = detect("میں ہر روز صبح چائے پیتا ہوں اور کتاب پڑھتا ہوں").unwrap();
+        assert_eq!(info.lang(), Lang::Urd);
+
+        let info = detect("أنا أشرب الشاي كل صباح وأقرأ كتابا").unwrap();
+        assert_eq!(info.lang(), Lang::Ara);
+
+        // Too short for trigrams to tell, but gaf is not used in Arabic and heh in Urdu
+        let info = detect("گربه سیاه").unwrap();
+        assert_eq!(info.lang(), Lang::Pes);
+    }
+
+    #[test]
+    fn test_detect_azerbaijani_in_both_scripts() {
+        let text = "Bütün insanlar ləyaqət və hüquqlarına görə azad və bərabər doğulurlar.";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Aze);
+        assert_eq!(info.script(), Script::Latin);
+
+        let text = "Бүтүн инсанлар ләјагәт вә һүгугларына ҝөрә азад вә бәрабәр доғулурлар.";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Aze);
+        assert_eq!(info.script(), Script::Cyrillic);
+    }
+
+    #[test]
+    fn test_detect_until() {
+        let texts = [
+            "Та нічого, все нормально. А в тебе як?",
+            "Ich wohne seit zehn Jahren in Berlin und arbeite dort als Lehrer.",
+            "There is no reason not to learn Esperanto.",
+            "Ja ne znam šta da radim, deca su uvek gladna, a mleko je skupo.",
+            "ქართული ენა",
+            "12345",
+        ];
+        let mut trigram_options = Options::new().set_min_trigram_count(10);
+        trigram_options.method = Method::Trigram;
+        for options in [Options::default(), trigram_options].iter() {
+            for text in texts.iter() {
+                let expected = detect_with_options(text, options);
+                assert_eq!(detect_until(text, options, 1.0), expected);
+
+                let info = detect_until(text, options, 0.5);
+                assert_eq!(
+                    info.as_ref().map(|i| i.lang()),
+                    expected.as_ref().map(|i| i.lang())
+                );
+                if info != expected {
+                    // Stopped early
+                    assert!(info.unwrap().confidence() > 0.5);
+                }
+            }
+        }
+
+        let info = detect_until(
+            "Та нічого, все нормально. А в тебе як?",
+            &Options::default(),
+            0.5,
+        );
+        assert_eq!(info.unwrap().alternative(), None);
+    }
+
+    #[test]
+    fn test_detect_serbian_in_both_scripts() {
+        let text = "Ja ne znam šta da radim, deca su uvek gladna, a mleko je skupo.";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Srp);
+        assert_eq!(info.script(), Script::Latin);
+
+        let text = "Ја не знам шта да радим, деца су увек гладна, а млеко је скупо.";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Srp);
+        assert_eq!(info.script(), Script::Cyrillic);
+
+        // Ijekavian is standard in both, so without ekavian trigrams it's Croatian
+        let text = "Ne znam gdje su djeca, a mlijeko je na stolu već cijelo jutro.";
+        assert_eq!(detect(text).unwrap().lang(), Lang::Hrv);
+
+        let text = "Beograd je glavni grad Srbije, gde živi skoro dva miliona ljudi.";
+        assert_eq!(detect(text).unwrap().lang(), Lang::Srp);
+
+        let text = "Bosanski jezik je standardni jezik kojim se služe Bošnjaci.";
+        assert_ne!(detect(text).unwrap().lang(), Lang::Srp);
+
+        // Latin Serbian doesn't make Croatian less confident
+        let text = "Zagreb je glavni grad Republike Hrvatske i najveći grad u državi.";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Hrv);
+        assert!(info.confidence() > 0.5);
+    }
+
+    #[test]
+    fn test_detect_nynorsk_vs_bokmal() {
+        let text = "Eg veit ikkje kva eg skal gjere, men eg kjem frå Bergen og likar meg her.";
+        assert_eq!(detect(text).unwrap().lang(), Lang::Nno);
+
+        // Nynorsk doesn't make clear Bokmål less confident
+        let text = "Jeg vet ikke hva jeg skal gjøre, men jeg kommer fra Bergen og liker meg her.";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Nob);
+        assert!(info.confidence() > 0.25);
+
+        // "kv" and "rle" are common in Bokmål too
+        let text = "Kvaliteten på denne boken er svært god, \
+                    og kvinnen som skrev den har vunnet mange priser";
+        assert_eq!(detect(text).unwrap().lang(), Lang::Nob);
+    }
+
     // TODO:  see https://github.com/greyblake/whatlang-rs/issues/78
     #[test]
     fn test_detect_with_options_with_filter_list_except_none() {
@@ -185,6 +1741,83 @@ mod tests {
         assert_eq!(info.lang(), Lang::Epo);
     }
 
+    #[test]
+    fn test_detect_with_options_with_allowed_scripts() {
+        let text = "Мы хотим видеть дальше, чем окна дома напротив";
+        let options = Options::new().set_allowed_scripts(vec![Script::Cyrillic]);
+        assert_eq!(
+            detect_with_options(text, &options).unwrap().lang(),
+            Lang::Rus
+        );
+        assert_eq!(detect_with_options("Hello, how are you?", &options), None);
+
+        // Scripts of a single language and Mandarin are filtered out too
+        for text in &["ქართული ენა", "东京都庁"] {
+            assert_eq!(detect_with_options(text, &options), None);
+            assert_eq!(
+                detect_result(text, &options),
+                Err(DetectError::AllCandidatesFiltered)
+            );
+        }
+
+        // Combined with the filter list
+        let options = Options::new()
+            .set_filter_list(FilterList::deny(vec![Lang::Rus]))
+            .set_allowed_scripts(vec![Script::Cyrillic]);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.script(), Script::Cyrillic);
+        assert_ne!(info.lang(), Lang::Rus);
+
+        let options = Options::new()
+            .set_allowed_scripts(vec![Script::Latin])
+            .set_filter_list(FilterList::allow(vec![Lang::Rus, Lang::Eng]));
+        assert_eq!(detect_with_options(text, &options), None);
+    }
+
+    #[test]
+    fn test_detect_top_n() {
+        let text = "Además de todo lo anteriormente dicho, también encontramos...";
+        let infos = detect_top_n(text, 3);
+        assert_eq!(infos.len(), 3);
+        assert_eq!(infos.first(), detect(text).as_ref());
+
+        for pair in infos.windows(2) {
+            assert!(pair[0].confidence() >= pair[1].confidence());
+            assert_ne!(pair[0].lang(), pair[1].lang());
+        }
+
+        // Every candidate is compared with the best one, so the least likely
+        // candidate doesn't get its raw score
+        let text = "Ich bin müde";
+        let infos = detect_top_n(text, 3);
+        assert_eq!(infos.len(), 3);
+        let (_, scores) = detect_with_scores(text, &Options::default()).unwrap();
+        let best_confidence = infos[0].confidence().value();
+        for (info, &(_, score)) in infos.iter().zip(scores.iter()).skip(1) {
+            let closeness =
+                1.0 - calculate_confidence(scores[0].1, score, infos[0].trigram_count());
+            assert_eq!(info.confidence(), closeness.min(best_confidence));
+        }
+    }
+
+    #[test]
+    fn test_detect_top_n_with_fewer_candidates() {
+        // Only two languages are allowed
+        let filter_list = FilterList::allow(vec![Lang::Epo, Lang::Ukr]);
+        let options = Options::new().set_filter_list(filter_list);
+        let infos = detect_top_n_with_options("Mi ne scias!", &options, 5);
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].lang(), Lang::Epo);
+        assert_eq!(infos[0].confidence(), 1.0);
+
+        // Script with only one language
+        let infos = detect_top_n("ქართული ენა მსოფლიო", 5);
+        assert_eq!(infos, vec![Info::from_script(Script::Georgian, Lang::Kat)]);
+
+        assert_eq!(detect_top_n("Hello", 0), vec![]);
+        assert_eq!(detect_top_n("1234567890", 3), vec![]);
+    }
+
     #[test]
     fn test_detect_with_options_with_allowlist_mandarin_japanese() {
         let text = "水";
@@ -198,6 +1831,147 @@ mod tests {
         assert_eq!(info.lang(), Lang::Cmn);
     }
 
+    #[test]
+    fn test_detect_with_options_with_preferred() {
+        // Catalan is only a bit more likely than Spanish
+        let text = "Hola, que tal";
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Cat);
+        assert!(info.confidence() < 1.0);
+
+        let options = Options::new().set_preferred(Lang::Spa);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Spa);
+        assert_eq!(info.alternative().unwrap().0, Lang::Cat);
+
+        // Confident results are not changed
+        let text = "Dobrý den, jak se máte";
+        let options = Options::new().set_preferred(Lang::Slk);
+        assert_eq!(
+            detect_with_options(text, &options).unwrap().lang(),
+            Lang::Ces
+        );
+        let options = Options::new().set_preferred(Lang::Ces);
+        assert_eq!(
+            detect_with_options(text, &options).unwrap().lang(),
+            Lang::Ces
+        );
+    }
+
+    #[test]
+    fn test_nudge_preferred_lang() {
+        let mut scores = [(Lang::Cat, 0.8), (Lang::Fra, 0.79), (Lang::Spa, 0.78)];
+        nudge_preferred_lang(&mut scores, Lang::Spa, 10);
+        assert_eq!(scores[0], (Lang::Spa, 0.78 * 1.05));
+        assert_eq!(scores[1..], [(Lang::Cat, 0.8), (Lang::Fra, 0.79)]);
+
+        // Score is raised, but it's not enough to win
+        let mut scores = [(Lang::Cat, 0.8), (Lang::Spa, 0.7)];
+        nudge_preferred_lang(&mut scores, Lang::Spa, 10);
+        assert_eq!(scores, [(Lang::Cat, 0.8), (Lang::Spa, 0.7 * 1.05)]);
+
+        // Not a near-tie
+        let mut scores = [(Lang::Cat, 0.8), (Lang::Spa, 0.7)];
+        nudge_preferred_lang(&mut scores, Lang::Spa, 1000);
+        assert_eq!(scores, [(Lang::Cat, 0.8), (Lang::Spa, 0.7)]);
+
+        // Wins a tie
+        let mut scores = [(Lang::Cat, 1.0), (Lang::Spa, 0.99)];
+        nudge_preferred_lang(&mut scores, Lang::Spa, 10);
+        assert_eq!(scores, [(Lang::Spa, 1.0), (Lang::Cat, 1.0)]);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_case_sensitive() {
+        let options = Options::new().set_case_sensitive(true);
+
+        // Lowercase text is detected the same way
+        let text = "їжак їсть яблуко";
+        assert_eq!(
+            detect_with_scores(text, &options),
+            detect_with_scores(text, &Options::new())
+        );
+
+        // Uppercase "Ї" still belongs to the alphabet of Ukrainian, but uppercase trigrams
+        // are not found in the models, so only trigrams of "яблуко" are scored
+        let text = "ЇЖАК ЇСТЬ яблуко";
+        let (info, scores) = detect_with_scores(text, &Options::new()).unwrap();
+        assert_eq!(info.lang(), Lang::Ukr);
+        assert_eq!(info.confidence(), 1.0);
+
+        let (case_sensitive_info, case_sensitive_scores) =
+            detect_with_scores(text, &options).unwrap();
+        assert_eq!(case_sensitive_info.lang(), Lang::Ukr);
+        assert_eq!(case_sensitive_info.confidence(), 1.0);
+        assert!(case_sensitive_scores[0].1 < scores[0].1);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_normalize_by_model_size() {
+        // Small custom model of Esperanto, built from another text
+        let model_text = "Esperanto estas la plej disvastigita internacia planlingvo. \
+                          La nomo de la lingvo venas de la kaŝnomo de ĝia iniciatinto.";
+        let model: Vec<(Trigram, u32)> = extract_trigrams(model_text)
+            .into_iter()
+            .take(50)
+            .enumerate()
+            .map(|(rank, (trigram, _))| (trigram, rank as u32))
+            .collect();
+        let options = Options::new().set_trigram_models(vec![(Lang::Epo, model)]);
+
+        let text = "Mi ne scias kion vi volas diri, sed mi ŝatas la lingvon.";
+        let info = detect_with_options(text, &options).unwrap();
+        assert_ne!(info.lang(), Lang::Epo);
+
+        let options = options.set_normalize_by_model_size(true);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Epo);
+
+        // Other languages are still detected
+        let text = "There is no reason not to learn a new language this year.";
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Eng);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_han_bias() {
+        let text = "水";
+
+        let info = detect(text).unwrap();
+        assert_eq!(info.lang(), Lang::Cmn);
+        assert_eq!(info.confidence(), 1.0);
+
+        let options = Options::new().set_han_bias(1.0);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Jpn);
+        assert_eq!(info.confidence(), 1.0);
+
+        let options = Options::new().set_han_bias(0.75);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Jpn);
+        assert_eq!(info.confidence(), 0.5);
+
+        let options = Options::new().set_han_bias(0.25);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Cmn);
+        assert_eq!(info.confidence(), 0.5);
+
+        // Japanese is never returned when it's not allowed
+        let options = Options::new()
+            .set_han_bias(1.0)
+            .set_filter_list(FilterList::deny(vec![Lang::Jpn]));
+        assert_eq!(
+            detect_with_options(text, &options).unwrap().lang(),
+            Lang::Cmn
+        );
+
+        // Text with Kana is not affected
+        let options = Options::new().set_han_bias(0.0);
+        let info = detect_with_options("東京都庁は新宿区にある", &options).unwrap();
+        assert_eq!(info.lang(), Lang::Jpn);
+        assert_eq!(info.script(), Script::Mandarin);
+    }
+
     #[test]
     fn test_detect_with_options_with_blacklist_mandarin_japanese() {
         let text = "水";
@@ -1,8 +1,14 @@
+use ::core::ops::Range;
+use alloc::vec::Vec;
+
 use crate::core;
+use crate::core::DetectStats;
 use crate::core::FilterList;
 use crate::core::Info;
 use crate::core::Options;
+use crate::error::DetectError;
 use crate::scripts::{detect_script, Script};
+use crate::trigrams::Trigram;
 use crate::Lang;
 
 /// Configurable structure that holds detection options and provides functions
@@ -33,9 +39,15 @@ pub struct Detector {
     options: Options,
 }
 
+// Detector with the default options, which the functions without options (e.g. `detect`)
+// delegate to. It's built at compile time, so nothing is initialized on the first call.
+pub(crate) static DEFAULT_DETECTOR: Detector = Detector::new();
+
 impl Detector {
-    pub fn new() -> Self {
-        Self::default()
+    pub const fn new() -> Self {
+        Detector {
+            options: Options::new(),
+        }
     }
 
     pub fn with_allowlist(list: Vec<Lang>) -> Self {
@@ -48,18 +60,115 @@ impl Detector {
         Self::with_options(opts)
     }
 
-    fn with_options(options: Options) -> Self {
+    /// Create detector with custom trigram models, see `Options::set_trigram_models`.
+    pub fn with_trigram_models(
+        models: impl IntoIterator<Item = (Lang, Vec<(Trigram, u32)>)>,
+    ) -> Self {
+        let opts = Options::new().set_trigram_models(models);
+        Self::with_options(opts)
+    }
+
+    /// Create detector with the given options. The set of allowed languages is computed
+    /// once, when the options are built, and is reused by every call of the detector.
+    pub fn with_options(options: Options) -> Self {
         Detector { options }
     }
 
+    /// Get the version of the built-in models the detector uses, e.g. to store it
+    /// with detection results. Results may change only when the version changes.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::Detector;
+    /// println!("Detected with models {}", Detector::new().model_version());
+    /// ```
+    pub fn model_version(&self) -> &'static str {
+        self.options.model_version()
+    }
+
     pub fn detect(&self, text: &str) -> Option<Info> {
         core::detect_with_options(text, &self.options)
     }
 
+    /// Detect a language and a script, or tell why nothing could be detected.
+    /// See `detect_result`.
+    pub fn detect_result(&self, text: &str) -> Result<Info, DetectError> {
+        core::detect_result(text, &self.options)
+    }
+
+    /// Detect a language and a script, but only if the detection is reliable,
+    /// see `detect_reliable`.
+    pub fn detect_reliable(&self, text: &str) -> Option<Info> {
+        core::detect_reliable_with_options(text, &self.options)
+    }
+
+    /// Detect a language and a script, but stop scoring as soon as a language is guaranteed
+    /// to get confidence higher than `bar`, see `detect_until`.
+    pub fn detect_until(&self, text: &str, bar: f64) -> Option<Info> {
+        core::detect_until(text, &self.options, bar)
+    }
+
+    /// Detect a language and a script by characters of a text, see `detect_chars`.
+    pub fn detect_chars<I: Iterator<Item = char>>(&self, chars: I) -> Option<Info> {
+        core::detect_chars(chars, &self.options)
+    }
+
+    /// Detect a language and a script by raw bytes of a text, see `detect_bytes`.
+    pub fn detect_bytes(&self, bytes: &[u8]) -> Option<Info> {
+        core::detect_bytes(bytes, &self.options)
+    }
+
+    /// Detect a language and a script reusing `scratch` buffer, see `detect_into`.
+    pub fn detect_into(&self, text: &str, scratch: &mut Vec<(Lang, f64)>) -> Option<Info> {
+        core::detect_into(text, &self.options, scratch)
+    }
+
     pub fn detect_lang(&self, text: &str) -> Option<Lang> {
         core::detect_with_options(text, &self.options).map(|info| info.lang())
     }
 
+    /// Detect only a language, or return `fallback` if it can not be detected, see `detect_or`.
+    pub fn detect_or(&self, text: &str, fallback: Lang) -> Lang {
+        self.detect_lang(text).unwrap_or(fallback)
+    }
+
+    pub fn detect_batch(&self, texts: &[&str]) -> Vec<Option<Info>> {
+        core::detect_batch(texts, &self.options)
+    }
+
+    pub fn detect_lines<'a>(
+        &'a self,
+        text: &'a str,
+    ) -> impl Iterator<Item = (&'a str, Option<Info>)> + 'a {
+        core::detect_lines(text, &self.options)
+    }
+
+    pub fn detect_mixed(&self, text: &str) -> Vec<(Info, Range<usize>)> {
+        core::detect_mixed(text, &self.options)
+    }
+
+    /// Detect all languages of a text with their proportions, see `detect_all`.
+    pub fn detect_all(&self, text: &str) -> Vec<Info> {
+        core::detect_all(text, &self.options)
+    }
+
+    /// Check whether a text is written in more than one language, see `is_multilingual`.
+    pub fn is_multilingual(&self, text: &str) -> bool {
+        core::is_multilingual(text, &self.options)
+    }
+
+    pub fn detect_with_scores(&self, text: &str) -> Option<(Info, Vec<(Lang, f64)>)> {
+        core::detect_with_scores(text, &self.options)
+    }
+
+    pub fn detect_with_stats(&self, text: &str) -> Option<(Info, DetectStats)> {
+        core::detect_with_stats(text, &self.options)
+    }
+
+    pub fn detect_top_n(&self, text: &str, n: usize) -> Vec<Info> {
+        core::detect_top_n_with_options(text, &self.options, n)
+    }
+
     pub fn detect_script(&self, text: &str) -> Option<Script> {
         detect_script(text)
     }
@@ -68,6 +177,9 @@ impl Detector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::LowercaseText;
+    use crate::trigrams::utils::get_trigrams_with_positions;
+    use std::collections::HashMap;
 
     #[test]
     fn test_detect_script() {
@@ -96,4 +208,60 @@ mod tests {
         assert_eq!(info.lang(), Lang::Epo);
         assert_eq!(info.script(), Script::Latin);
     }
+
+    #[test]
+    fn test_default_detector() {
+        assert_eq!(DEFAULT_DETECTOR.options, Options::default());
+        let text = "Та нічого, все нормально. А в тебе як?";
+        assert_eq!(core::detect(text), Detector::default().detect(text));
+    }
+
+    #[test]
+    fn test_with_options_same_as_detect_with_options() {
+        let filter_list = FilterList::allow_deny(
+            vec![Lang::Eng, Lang::Deu, Lang::Rus, Lang::Ukr, Lang::Epo],
+            vec![Lang::Deu],
+        );
+        let options = Options::new().set_filter_list(filter_list);
+        let detector = Detector::with_options(options.clone());
+        let texts = [
+            "The quick brown fox jumps over the lazy dog",
+            "Die Ordnung muss für immer in diesem Codebase bleiben",
+            "Мы хотим видеть дерево, а не лес",
+            "Jen la trinkejo fermitis, ni iras tra mallumo kaj pluvo.",
+            "",
+        ];
+        for text in texts {
+            assert_eq!(
+                detector.detect(text),
+                core::detect_with_options(text, &options)
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_trigram_models() {
+        let text = "Jen la trinkejo fermitis, ni iras tra mallumo kaj pluvo.";
+        assert_eq!(Detector::new().detect_lang(text), Some(Lang::Epo));
+
+        // Model of English that is built from the Esperanto text
+        let lowercase_text = LowercaseText::new(text);
+        let trigrams = get_trigrams_with_positions(&lowercase_text, Default::default())
+            .trigram_positions
+            .into_iter()
+            .collect();
+        let mut models = HashMap::new();
+        models.insert(Lang::Eng, trigrams);
+        let detector = Detector::with_trigram_models(models);
+        assert_eq!(detector.detect_lang(text), Some(Lang::Eng));
+    }
+
+    #[test]
+    fn test_detect_top_n() {
+        let detector = Detector::with_allowlist(vec![Lang::Eng, Lang::Deu, Lang::Rus]);
+        let infos = detector.detect_top_n("The quick brown fox jumps over the lazy dog", 3);
+        assert_eq!(infos.len(), 2);
+        assert_eq!(infos[0].lang(), Lang::Eng);
+        assert_eq!(infos[1].lang(), Lang::Deu);
+    }
 }
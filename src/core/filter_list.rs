@@ -1,10 +1,19 @@
+use alloc::vec::Vec;
+
+use crate::error::Error;
 use crate::Lang;
 
-#[derive(Debug, Clone)]
+/// Restricts the set of languages that can be detected.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum FilterList {
+    #[default]
     All,
     Allow(Vec<Lang>),
     Deny(Vec<Lang>),
+    AllowDeny {
+        allowlist: Vec<Lang>,
+        denylist: Vec<Lang>,
+    },
 }
 
 impl FilterList {
@@ -21,18 +30,51 @@ impl FilterList {
         Self::Deny(blacklist)
     }
 
+    /// Combine allowlist and denylist: the allowlist restricts the set of languages first,
+    /// then languages from the denylist are removed from it.
+    /// So a language that is present in both lists is not allowed.
+    pub fn allow_deny(allowlist: Vec<Lang>, denylist: Vec<Lang>) -> Self {
+        Self::AllowDeny {
+            allowlist,
+            denylist,
+        }
+    }
+
     pub fn is_allowed(&self, lang: Lang) -> bool {
         match self {
             Self::All => true,
             Self::Allow(ref allowlist) => allowlist.contains(&lang),
             Self::Deny(ref blacklist) => !blacklist.contains(&lang),
+            Self::AllowDeny {
+                ref allowlist,
+                ref denylist,
+            } => allowlist.contains(&lang) && !denylist.contains(&lang),
         }
     }
-}
 
-impl Default for FilterList {
-    fn default() -> Self {
-        FilterList::All
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        match self {
+            Self::All | Self::Deny(_) => Ok(()),
+            Self::Allow(ref allowlist) => {
+                if allowlist.is_empty() {
+                    Err(Error::EmptyAllowlist)
+                } else {
+                    Ok(())
+                }
+            }
+            Self::AllowDeny {
+                ref allowlist,
+                ref denylist,
+            } => {
+                if let Some(&lang) = allowlist.iter().find(|lang| denylist.contains(lang)) {
+                    Err(Error::AllowedAndDenied(lang))
+                } else if allowlist.is_empty() {
+                    Err(Error::EmptyAllowlist)
+                } else {
+                    Ok(())
+                }
+            }
+        }
     }
 }
 
@@ -68,4 +110,37 @@ mod tests {
         assert!(!list.is_allowed(Lang::Rus));
         assert!(!list.is_allowed(Lang::Ukr));
     }
+
+    #[test]
+    fn test_allow_deny() {
+        let list = FilterList::allow_deny(vec![Lang::Rus, Lang::Ukr], vec![Lang::Ukr]);
+
+        assert!(list.is_allowed(Lang::Rus));
+
+        assert!(!list.is_allowed(Lang::Ukr));
+        assert!(!list.is_allowed(Lang::Eng));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(FilterList::default().validate().is_ok());
+        assert!(FilterList::deny(vec![]).validate().is_ok());
+        assert!(FilterList::allow(vec![Lang::Rus]).validate().is_ok());
+        assert!(FilterList::allow_deny(vec![Lang::Rus], vec![Lang::Eng])
+            .validate()
+            .is_ok());
+
+        assert!(matches!(
+            FilterList::allow(vec![]).validate(),
+            Err(Error::EmptyAllowlist)
+        ));
+        assert!(matches!(
+            FilterList::allow_deny(vec![Lang::Rus, Lang::Ukr], vec![Lang::Ukr]).validate(),
+            Err(Error::AllowedAndDenied(Lang::Ukr))
+        ));
+        assert!(matches!(
+            FilterList::allow_deny(vec![], vec![Lang::Ukr]).validate(),
+            Err(Error::EmptyAllowlist)
+        ));
+    }
 }
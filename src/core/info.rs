@@ -1,13 +1,63 @@
-use crate::{Lang, Script};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Confidence;
+use crate::{Direction, Lang, Script};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 const RELIABLE_CONFIDENCE_THRESHOLD: f64 = 0.9;
 
+/// Breakdown of the scripts Japanese text is written with,
+/// see `Info::script_detail`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptDetail {
+    hiragana: usize,
+    katakana: usize,
+    kanji: usize,
+}
+
+impl ScriptDetail {
+    pub(crate) fn new(hiragana: usize, katakana: usize, kanji: usize) -> Self {
+        Self {
+            hiragana,
+            katakana,
+            kanji,
+        }
+    }
+
+    /// Number of Hiragana characters.
+    pub fn hiragana(&self) -> usize {
+        self.hiragana
+    }
+
+    /// Number of Katakana characters.
+    pub fn katakana(&self) -> usize {
+        self.katakana
+    }
+
+    /// Number of Kanji characters (`Script::Mandarin`).
+    pub fn kanji(&self) -> usize {
+        self.kanji
+    }
+}
+
 /// Represents a full outcome of language detection.
-#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug)]
 pub struct Info {
     script: Script,
     lang: Lang,
-    confidence: f64,
+    confidence: Confidence,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    script_determined: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    trigram_count: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    script_detail: Option<ScriptDetail>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    alternative: Option<(Lang, Confidence)>,
 }
 
 impl Info {
@@ -15,7 +65,91 @@ impl Info {
         Self {
             script,
             lang,
-            confidence,
+            confidence: Confidence::new(confidence),
+            script_determined: false,
+            trigram_count: 0,
+            script_detail: None,
+            alternative: None,
+        }
+    }
+
+    /// Combine infos of parts of the same text (e.g. chunks of a document) into one.
+    /// Every info votes for its language with its confidence, and the language with
+    /// the highest sum of confidences wins (on a tie, the one that comes first).
+    /// Confidence of the result is the sum of confidences of the winner minus the sum
+    /// of confidences of all other languages, divided by the number of infos
+    /// (and not lower than 0.0). So it's the average confidence when all parts agree,
+    /// and every disagreeing part lowers it.
+    /// The script is the script of the first info of the winner, and the trigram count
+    /// is the sum of trigram counts of all its infos.
+    /// Returns `None` if there are no infos.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{Info, Lang, Script};
+    /// let infos = [
+    ///     Info::new(Script::Latin, Lang::Eng, 0.9),
+    ///     Info::new(Script::Latin, Lang::Eng, 0.7),
+    ///     Info::new(Script::Latin, Lang::Fra, 0.4),
+    /// ];
+    /// let info = Info::combine(&infos).unwrap();
+    /// assert_eq!(info.lang(), Lang::Eng);
+    /// assert_eq!(info.confidence(), (0.9 + 0.7 - 0.4) / 3.0);
+    /// ```
+    pub fn combine(infos: &[Info]) -> Option<Info> {
+        let mut lang_confidences: Vec<(Lang, f64)> = vec![];
+        for info in infos {
+            let confidence = info.confidence.value();
+            match lang_confidences
+                .iter_mut()
+                .find(|(lang, _)| *lang == info.lang)
+            {
+                Some((_, sum)) => *sum += confidence,
+                None => lang_confidences.push((info.lang, confidence)),
+            }
+        }
+
+        let (lang, lang_confidence) = lang_confidences.iter().fold(
+            None,
+            |best: Option<(Lang, f64)>, &(lang, sum)| match best {
+                Some(best) if best.1 >= sum => Some(best),
+                _ => Some((lang, sum)),
+            },
+        )?;
+        let total_confidence: f64 = lang_confidences.iter().map(|&(_, sum)| sum).sum();
+        let confidence = (2.0 * lang_confidence - total_confidence) / infos.len() as f64;
+
+        let lang_infos = infos.iter().filter(|info| info.lang == lang);
+        let script = lang_infos.clone().next()?.script;
+        let trigram_count = lang_infos.map(|info| info.trigram_count).sum();
+        Some(Info::new(script, lang, confidence).with_trigram_count(trigram_count))
+    }
+
+    pub(crate) fn with_trigram_count(mut self, trigram_count: usize) -> Self {
+        self.trigram_count = trigram_count;
+        self
+    }
+
+    pub(crate) fn with_alternative(mut self, alternative: Option<(Lang, Confidence)>) -> Self {
+        self.alternative = alternative;
+        self
+    }
+
+    pub(crate) fn with_script_detail(mut self, script_detail: ScriptDetail) -> Self {
+        self.script_detail = Some(script_detail);
+        self
+    }
+
+    // Info for a script that is used only by one language.
+    pub(crate) fn from_script(script: Script, lang: Lang) -> Self {
+        Self {
+            script,
+            lang,
+            confidence: Confidence::new(1.0),
+            script_determined: true,
+            trigram_count: 0,
+            script_detail: None,
+            alternative: None,
         }
     }
 
@@ -27,11 +161,180 @@ impl Info {
         self.script
     }
 
-    pub fn confidence(&self) -> f64 {
+    /// Returns the direction the detected script is written in, see `Script::direction`.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{detect, Direction};
+    /// let info = detect("ქართული ენა").unwrap();
+    /// assert_eq!(info.direction(), Direction::LeftToRight);
+    /// ```
+    pub fn direction(&self) -> Direction {
+        self.script.direction()
+    }
+
+    pub fn confidence(&self) -> Confidence {
         self.confidence
     }
 
+    /// Returns `true` if confidence is higher than 0.9.
+    /// Use `is_reliable_with` to choose a different threshold.
     pub fn is_reliable(&self) -> bool {
-        self.confidence > RELIABLE_CONFIDENCE_THRESHOLD
+        self.is_reliable_with(RELIABLE_CONFIDENCE_THRESHOLD)
+    }
+
+    /// Returns `true` if confidence is higher than the given threshold.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{Info, Lang, Script};
+    /// let info = Info::new(Script::Latin, Lang::Epo, 0.8);
+    /// assert!(!info.is_reliable());
+    /// assert!(info.is_reliable_with(0.7));
+    /// ```
+    pub fn is_reliable_with(&self, threshold: f64) -> bool {
+        self.confidence > threshold
+    }
+
+    /// Returns `true` if the language was inferred purely from the script,
+    /// because the script is used by only one language (e.g. Georgian).
+    pub fn is_script_determined(&self) -> bool {
+        self.script_determined
+    }
+
+    /// Number of trigrams in the text the confidence is calculated from.
+    /// The lower it is, the less evidence the detection is based on.
+    /// It's 0 when the language is determined without comparing trigrams
+    /// (e.g. when the script is used by only one language).
+    /// With `Method::Alphabet` (available with the `dev` feature) it's the number of letters
+    /// compared with alphabets.
+    pub fn trigram_count(&self) -> usize {
+        self.trigram_count
+    }
+
+    /// Returns numbers of Hiragana, Katakana and Kanji characters for Japanese,
+    /// since it's written with all of them. `script()` returns only the dominant one.
+    /// It's `None` for other languages.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{detect, Lang};
+    /// let info = detect("テレビを見ながら、晩ご飯を食べました").unwrap();
+    /// assert_eq!(info.lang(), Lang::Jpn);
+    /// let detail = info.script_detail().unwrap();
+    /// assert_eq!(detail.katakana(), 3);
+    /// ```
+    pub fn script_detail(&self) -> Option<ScriptDetail> {
+        self.script_detail
+    }
+
+    /// Returns the second most likely language and its confidence, which is calculated
+    /// against the detected language (see `detect_top_n`).
+    /// It's `None` when there is no other candidate, e.g. when the language is determined
+    /// by the script alone or all other languages of the script are filtered out.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{detect, Lang};
+    /// let info = detect("Та нічого, все нормально. А в тебе як?").unwrap();
+    /// assert_eq!(info.lang(), Lang::Ukr);
+    /// let (lang, confidence) = info.alternative().unwrap();
+    /// assert_eq!(lang, Lang::Bel);
+    /// assert!(confidence <= info.confidence().value());
+    ///
+    /// assert_eq!(detect("ქართული ენა").unwrap().alternative(), None);
+    /// ```
+    pub fn alternative(&self) -> Option<(Lang, f64)> {
+        self.alternative
+            .map(|(lang, confidence)| (lang, confidence.value()))
+    }
+}
+
+// Infos are equal when they have the same script, language and confidence.
+// Details of the detection (e.g. the number of trigrams or the alternative) are not
+// compared, they are not serialized either.
+impl PartialEq for Info {
+    fn eq(&self, other: &Self) -> bool {
+        self.script == other.script
+            && self.lang == other.lang
+            && self.confidence == other.confidence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_reliable() {
+        let info = Info::new(Script::Latin, Lang::Epo, 0.9);
+        assert!(!info.is_reliable());
+        assert!(!info.is_reliable_with(0.9));
+        assert!(info.is_reliable_with(0.89));
+
+        let info = Info::new(Script::Latin, Lang::Epo, 0.95);
+        assert!(info.is_reliable());
+        assert!(info.is_reliable_with(0.0));
+        assert!(!info.is_reliable_with(1.0));
+    }
+
+    #[test]
+    fn test_combine() {
+        assert_eq!(Info::combine(&[]), None);
+
+        let info = Info::new(Script::Cyrillic, Lang::Ukr, 0.8).with_trigram_count(10);
+        let combined = Info::combine(&[info]).unwrap();
+        assert_eq!(
+            combined,
+            Info::new(Script::Cyrillic, Lang::Ukr, 0.8).with_trigram_count(10)
+        );
+        assert_eq!(combined.trigram_count(), 10);
+
+        let infos = [
+            Info::new(Script::Cyrillic, Lang::Rus, 0.5).with_trigram_count(5),
+            Info::new(Script::Cyrillic, Lang::Ukr, 0.8).with_trigram_count(10),
+            Info::new(Script::Cyrillic, Lang::Ukr, 0.6).with_trigram_count(20),
+        ];
+        let combined = Info::combine(&infos).unwrap();
+        assert_eq!(combined.lang(), Lang::Ukr);
+        assert_eq!(combined.script(), Script::Cyrillic);
+        assert_eq!(combined.confidence(), (0.8 + 0.6 - 0.5) / 3.0);
+        assert_eq!(combined.trigram_count(), 30);
+    }
+
+    #[test]
+    fn test_combine_with_disagreement() {
+        // Tie is resolved in favour of the language that comes first
+        let infos = [
+            Info::new(Script::Latin, Lang::Spa, 0.5),
+            Info::new(Script::Latin, Lang::Por, 0.5),
+        ];
+        let combined = Info::combine(&infos).unwrap();
+        assert_eq!(combined.lang(), Lang::Spa);
+        assert_eq!(combined.confidence(), 0.0);
+
+        // Confidence is not lower than 0.0
+        let infos = [
+            Info::new(Script::Latin, Lang::Spa, 0.5),
+            Info::new(Script::Latin, Lang::Por, 0.4),
+            Info::new(Script::Latin, Lang::Cat, 0.4),
+        ];
+        let combined = Info::combine(&infos).unwrap();
+        assert_eq!(combined.lang(), Lang::Spa);
+        assert_eq!(combined.confidence(), 0.0);
+    }
+
+    #[test]
+    fn test_eq() {
+        let info = Info::new(Script::Latin, Lang::Epo, 0.5);
+        let detailed = Info::new(Script::Latin, Lang::Epo, 0.5)
+            .with_trigram_count(10)
+            .with_alternative(Some((Lang::Spa, Confidence::new(0.2))));
+        assert_eq!(info, detailed);
+        assert_ne!(info, Info::new(Script::Latin, Lang::Epo, 0.6));
+        assert_ne!(info, Info::new(Script::Latin, Lang::Spa, 0.5));
+
+        let info = crate::detect("ქართული ენა").unwrap();
+        assert_eq!(info, Info::new(Script::Georgian, Lang::Kat, 1.0));
     }
 }
@@ -0,0 +1,107 @@
+use super::FilterList;
+use crate::{Lang, Script};
+
+/// Set of languages, stored as a bit mask indexed by `Lang as usize`.
+/// It's built once from a filter list, so checking whether a language is allowed
+/// doesn't require scanning the allowlist and denylist on every detection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LangSet(u128);
+
+impl LangSet {
+    // Languages are numbered from 0 without gaps, so all of them are the lowest bits.
+    pub const fn all() -> Self {
+        Self(u128::MAX >> (u128::BITS as usize - Lang::all().len()))
+    }
+
+    pub fn from_filter_list(filter_list: &FilterList) -> Self {
+        let bits = Lang::all()
+            .iter()
+            .filter(|&&lang| filter_list.is_allowed(lang))
+            .fold(0, |bits, &lang| bits | Self::bit(lang));
+        Self(bits)
+    }
+
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    pub fn from_scripts(scripts: &[Script]) -> Self {
+        let bits = scripts
+            .iter()
+            .flat_map(|script| script.langs())
+            .fold(0, |bits, &lang| bits | Self::bit(lang));
+        Self(bits)
+    }
+
+    pub fn from_langs(langs: impl IntoIterator<Item = Lang>) -> Self {
+        let bits = langs
+            .into_iter()
+            .fold(0, |bits, lang| bits | Self::bit(lang));
+        Self(bits)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    pub fn contains(self, lang: Lang) -> bool {
+        self.0 & Self::bit(lang) != 0
+    }
+
+    #[inline]
+    fn bit(lang: Lang) -> u128 {
+        1 << (lang as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_lang_fits_into_mask() {
+        for &lang in Lang::all() {
+            assert!((lang as usize) < u128::BITS as usize);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        let set = LangSet::all();
+        for &lang in Lang::all() {
+            assert!(set.contains(lang));
+        }
+        assert_eq!(set, LangSet::from_filter_list(&FilterList::All));
+    }
+
+    #[test]
+    fn test_from_scripts_and_intersection() {
+        let set = LangSet::from_scripts(&[Script::Cyrillic, Script::Georgian]);
+        for &lang in Lang::all() {
+            let expected = Script::Cyrillic.langs().contains(&lang) || lang == Lang::Kat;
+            assert_eq!(set.contains(lang), expected);
+        }
+
+        let allowed = LangSet::from_filter_list(&FilterList::allow(vec![Lang::Rus, Lang::Eng]));
+        let set = set.intersection(allowed);
+        assert!(set.contains(Lang::Rus));
+        assert!(!set.contains(Lang::Eng));
+        assert!(!set.contains(Lang::Kat));
+    }
+
+    #[test]
+    fn test_from_filter_list() {
+        let filter_lists = vec![
+            FilterList::allow(vec![Lang::Rus, Lang::Ukr]),
+            FilterList::deny(vec![Lang::Rus, Lang::Ukr]),
+            FilterList::allow_deny(vec![Lang::Rus, Lang::Ukr], vec![Lang::Ukr]),
+        ];
+        for filter_list in filter_lists {
+            let set = LangSet::from_filter_list(&filter_list);
+            for &lang in Lang::all() {
+                assert_eq!(set.contains(lang), filter_list.is_allowed(lang));
+            }
+        }
+    }
+}
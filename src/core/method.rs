@@ -1,11 +1,14 @@
+use alloc::string::ToString;
+
 use crate::error::Error;
-use std::fmt;
-use std::str::FromStr;
+use core::fmt;
+use core::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Method {
     Trigram,
     Alphabet,
+    #[default]
     Combined,
 }
 
@@ -33,12 +36,6 @@ impl fmt::Display for Method {
     }
 }
 
-impl Default for Method {
-    fn default() -> Self {
-        Method::Combined
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
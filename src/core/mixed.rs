@@ -0,0 +1,334 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::core::{detect_with_options, Info, Options};
+use crate::scripts::{script_segments, Script};
+use crate::utils::is_stop_char;
+use crate::Lang;
+
+/// Detect languages of a text that is written with multiple scripts.
+/// The text is split into maximal runs of the same script, and every run is detected
+/// independently. Spaces, punctuation and digits do not split the runs.
+/// Returns detected runs along with their byte ranges in the text.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_mixed, Lang, Options};
+/// let text = "Мы хотим видеть дальше, чем окна дома напротив: the quick brown fox jumps";
+/// let segments = detect_mixed(text, &Options::default());
+/// assert_eq!(segments.len(), 2);
+/// assert_eq!(segments[0].0.lang(), Lang::Rus);
+/// assert_eq!(&text[segments[1].1.clone()], "the quick brown fox jumps");
+/// ```
+pub fn detect_mixed(text: &str, options: &Options) -> Vec<(Info, Range<usize>)> {
+    script_ranges(text)
+        .into_iter()
+        .filter_map(|range| {
+            detect_with_options(&text[range.clone()], options).map(|info| (info, range))
+        })
+        .collect()
+}
+
+/// Detect all languages of a text, including languages written with the same script.
+/// The text is split into runs of the same script (see `detect_mixed`), and every run
+/// is split further into sentences, which are detected independently. Sentences shorter
+/// than 10 letters are joined with the following ones (or the preceding one at the end
+/// of a run), and a sentence which can't be detected reliably (see `Info::is_reliable`)
+/// is counted as the language of its whole run. So a language written with the same script
+/// as another one is found only if some sentence is reliably detected as the language.
+///
+/// Returns one `Info` per distinct language, sorted by the proportion of the text
+/// written in the language (the most used language goes first).
+/// The confidence of every `Info` is the proportion of the text in the language:
+/// the number of its characters (not counting spaces, punctuation and digits)
+/// relative to the characters of all the detected sentences.
+/// The script of an `Info` is the script of the first sentence detected as the language.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_all, Lang, Options};
+/// let text = "Мы хотим видеть дальше, чем окна дома напротив: the quick brown fox";
+/// let infos = detect_all(text, &Options::default());
+/// assert_eq!(infos.len(), 2);
+/// assert_eq!(infos[0].lang(), Lang::Rus);
+/// assert!(infos[0].confidence() > 0.6);
+///
+/// // Languages written with the same script are told apart by sentences
+/// let text = "I really love this little town and the people who live here. \
+///             Mais je ne parle pas encore très bien le français, malheureusement.";
+/// let infos = detect_all(text, &Options::default());
+/// assert_eq!(infos.len(), 2);
+/// assert_eq!(infos[0].lang(), Lang::Fra);
+/// assert_eq!(infos[1].lang(), Lang::Eng);
+/// ```
+pub fn detect_all(text: &str, options: &Options) -> Vec<Info> {
+    let mut lang_lengths: Vec<(Lang, Script, usize)> = vec![];
+
+    for (run_info, run) in detect_mixed(text, options) {
+        for range in sentence_ranges(text, run) {
+            // A sentence which can't be detected reliably is more likely to be written
+            // in the language of the whole run than in the one it's detected as.
+            let (lang, script) = detect_with_options(&text[range.clone()], options)
+                .filter(|info| info.is_reliable())
+                .map_or((run_info.lang(), run_info.script()), |info| {
+                    (info.lang(), info.script())
+                });
+            let length = text[range].chars().filter(|&ch| !is_stop_char(ch)).count();
+            match lang_lengths.iter_mut().find(|(l, _, _)| *l == lang) {
+                Some((_, _, total_length)) => *total_length += length,
+                None => lang_lengths.push((lang, script, length)),
+            }
+        }
+    }
+
+    // Stable sort keeps languages with the same length in the order they appear in the text
+    lang_lengths.sort_by_key(|&(_, _, length)| core::cmp::Reverse(length));
+
+    let text_length: usize = lang_lengths.iter().map(|&(_, _, length)| length).sum();
+    lang_lengths
+        .into_iter()
+        .map(|(lang, script, length)| Info::new(script, lang, length as f64 / text_length as f64))
+        .collect()
+}
+
+// Minimal proportion of a text written in a language to consider the language
+// as one of the languages of the text, see `is_multilingual`.
+const MULTILINGUAL_MIN_PROPORTION: f64 = 0.1;
+
+/// Check whether a text is written in more than one language, see `detect_all`.
+/// A language is counted only if more than 10% of the text is written in it,
+/// so a single foreign word in a long text doesn't make the text multilingual.
+///
+/// # Example
+/// ```
+/// use whatlang::{is_multilingual, Options};
+/// let options = Options::default();
+/// assert!(is_multilingual("Мы хотим видеть дальше: the quick brown fox", &options));
+/// assert!(!is_multilingual("Мы хотим видеть дальше, чем окна дома напротив", &options));
+/// assert!(is_multilingual(
+///     "Il fait très beau aujourd'hui, allons à la plage! \
+///      I would rather stay at home and read a good book, to be honest.",
+///     &options
+/// ));
+/// ```
+pub fn is_multilingual(text: &str, options: &Options) -> bool {
+    detect_all(text, options)
+        .iter()
+        .filter(|info| info.confidence() > MULTILINGUAL_MIN_PROPORTION)
+        .count()
+        > 1
+}
+
+// Minimal number of letters of a sentence to detect it on its own, see `detect_all`.
+const MIN_SENTENCE_LENGTH: usize = 10;
+
+// Maximal runs of the same script, see `detect_mixed`.
+fn script_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges: Vec<(Script, Range<usize>)> = vec![];
+
+    for (script, range) in script_segments(text) {
+        match ranges.last_mut() {
+            // Japanese is written with Kanji (Mandarin), Hiragana and Katakana at the same time.
+            Some((last_script, ref mut last_range))
+                if is_japanese_script(*last_script) && is_japanese_script(script) =>
+            {
+                last_range.end = range.end;
+            }
+            _ => ranges.push((script, range)),
+        }
+    }
+
+    ranges.into_iter().map(|(_, range)| range).collect()
+}
+
+// Split a run of the same script into sentences of at least `MIN_SENTENCE_LENGTH` letters
+// (except a run which is shorter), without spaces and punctuation around them.
+fn sentence_ranges(text: &str, run: Range<usize>) -> Vec<Range<usize>> {
+    let mut sentences: Vec<Range<usize>> = vec![];
+    // Byte range of letters of the current sentence and their number
+    let mut current: Option<Range<usize>> = None;
+    let mut length = 0;
+
+    for (offset, ch) in text[run.clone()].char_indices() {
+        let index = run.start + offset;
+        if is_sentence_end(ch) {
+            if length >= MIN_SENTENCE_LENGTH {
+                sentences.extend(current.take());
+                length = 0;
+            }
+        } else if !is_stop_char(ch) {
+            let end = index + ch.len_utf8();
+            match current {
+                Some(ref mut range) => range.end = end,
+                None => current = Some(index..end),
+            }
+            length += 1;
+        }
+    }
+
+    if let Some(range) = current {
+        match sentences.last_mut() {
+            Some(last) if length < MIN_SENTENCE_LENGTH => last.end = range.end,
+            _ => sentences.push(range),
+        }
+    }
+    sentences
+}
+
+fn is_sentence_end(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?' | '\n' | '。' | '！' | '？')
+}
+
+fn is_japanese_script(script: Script) -> bool {
+    matches!(
+        script,
+        Script::Mandarin | Script::Hiragana | Script::Katakana
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lang;
+
+    #[test]
+    fn test_detect_mixed() {
+        let text = "Мы хотим видеть дальше, чем окна дома напротив. \
+                    And this is an English quote for everyone, isn't it?";
+        let segments = detect_mixed(text, &Options::default());
+        assert_eq!(segments.len(), 2);
+
+        let (ref info, ref range) = segments[0];
+        assert_eq!(info.script(), Script::Cyrillic);
+        assert_eq!(info.lang(), Lang::Rus);
+        assert_eq!(
+            &text[range.clone()],
+            "Мы хотим видеть дальше, чем окна дома напротив"
+        );
+
+        let (ref info, ref range) = segments[1];
+        assert_eq!(info.script(), Script::Latin);
+        assert_eq!(info.lang(), Lang::Eng);
+        assert_eq!(
+            &text[range.clone()],
+            "And this is an English quote for everyone, isn't it"
+        );
+    }
+
+    #[test]
+    fn test_detect_mixed_with_one_script() {
+        let text = "  Та нічого, все нормально. А в тебе як?  ";
+        let segments = detect_mixed(text, &Options::default());
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].0, crate::detect(text).unwrap());
+        assert_eq!(
+            &text[segments[0].1.clone()],
+            "Та нічого, все нормально. А в тебе як"
+        );
+
+        assert_eq!(detect_mixed("", &Options::default()), vec![]);
+        assert_eq!(detect_mixed("123 - 456!", &Options::default()), vec![]);
+    }
+
+    #[test]
+    fn test_detect_mixed_with_japanese() {
+        let text = "この間、川越城や松井田城などの諸城を拡張・改修";
+        let segments = detect_mixed(text, &Options::default());
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].0.lang(), Lang::Jpn);
+    }
+
+    #[test]
+    fn test_detect_all() {
+        let text = "Мы хотим видеть дальше, чем окна дома напротив. \
+                    And this is an English quote for everyone, isn't it? \
+                    Но это уже совсем другая история.";
+        let infos = detect_all(text, &Options::default());
+        assert_eq!(infos.len(), 2);
+
+        assert_eq!(infos[0].lang(), Lang::Rus);
+        assert_eq!(infos[0].script(), Script::Cyrillic);
+        // 38 + 27 Russian chars against 40 English chars
+        assert_eq!(infos[0].confidence(), 65.0 / 105.0);
+
+        assert_eq!(infos[1].lang(), Lang::Eng);
+        assert_eq!(infos[1].script(), Script::Latin);
+        assert_eq!(infos[1].confidence(), 40.0 / 105.0);
+    }
+
+    #[test]
+    fn test_detect_all_with_one_script() {
+        let text = "Das ist ein wirklich schönes Haus mit einem großen Garten. \
+                    But the neighbours are very loud at night and I can't sleep.";
+        let infos = detect_all(text, &Options::default());
+        assert_eq!(infos.len(), 2);
+        assert_eq!(infos[0].lang(), Lang::Deu);
+        assert_eq!(infos[0].script(), Script::Latin);
+        assert_eq!(infos[1].lang(), Lang::Eng);
+        assert_eq!(infos[1].script(), Script::Latin);
+
+        // Sentences which are not detected reliably don't add languages
+        let text = "The weather has been terrible this week. It rained every single day. \
+                    We stayed at home and watched old movies.";
+        let infos = detect_all(text, &Options::default());
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].lang(), Lang::Eng);
+    }
+
+    #[test]
+    fn test_sentence_ranges() {
+        let text = "Short one. This sentence is long enough! Another long sentence?  Tail";
+        let sentences: Vec<&str> = sentence_ranges(text, 0..text.len())
+            .into_iter()
+            .map(|range| &text[range])
+            .collect();
+        assert_eq!(
+            sentences,
+            vec![
+                "Short one. This sentence is long enough",
+                "Another long sentence?  Tail"
+            ]
+        );
+
+        let text = "Too short. Tiny";
+        assert_eq!(sentence_ranges(text, 0..text.len()), vec![0..text.len()]);
+        assert_eq!(sentence_ranges("?!", 0..2), vec![]);
+    }
+
+    #[test]
+    fn test_detect_all_with_one_language() {
+        let text = "Та нічого, все нормально. А в тебе як?";
+        let infos = detect_all(text, &Options::default());
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].lang(), Lang::Ukr);
+        assert_eq!(infos[0].confidence(), 1.0);
+
+        assert_eq!(detect_all("", &Options::default()), vec![]);
+        assert_eq!(detect_all("123 - 456!", &Options::default()), vec![]);
+    }
+
+    #[test]
+    fn test_is_multilingual() {
+        let options = Options::default();
+
+        let text = "Мы хотим видеть дальше, чем окна дома напротив. \
+                    And this is an English quote for everyone, isn't it?";
+        assert!(is_multilingual(text, &options));
+
+        // Less than 10% of the text is English
+        let text = "Сегодня мы наконец-то посмотрели новый фильм, о котором все говорят \
+                    уже целый месяц, и он нам очень понравился: wow!";
+        assert!(!is_multilingual(text, &options));
+
+        assert!(!is_multilingual(
+            "Та нічого, все нормально. А в тебе як?",
+            &options
+        ));
+        assert!(!is_multilingual("", &options));
+
+        let text = "Il fait très beau aujourd'hui, allons à la plage! \
+                    I would rather stay at home and read a good book, to be honest.";
+        assert!(is_multilingual(text, &options));
+    }
+}
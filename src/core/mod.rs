@@ -3,17 +3,35 @@ pub(crate) mod detect;
 mod detector;
 mod filter_list;
 mod info;
+mod lang_set;
 mod method;
+mod mixed;
 mod options;
+mod options_builder;
+mod outcome;
 mod query;
+mod stats;
+mod streaming;
 mod text;
 
-pub use confidence::calculate_confidence;
-pub use detect::{detect, detect_lang, detect_with_options};
+pub(crate) use confidence::confident_rate;
+pub use confidence::{calculate_confidence, Confidence};
+pub use detect::{
+    detect, detect_batch, detect_bytes, detect_chars, detect_into, detect_lang, detect_lines,
+    detect_or, detect_or_default, detect_reliable, detect_reliable_with_options, detect_result,
+    detect_top_n, detect_top_n_with_options, detect_until, detect_with_options, detect_with_scores,
+    detect_with_stats,
+};
 pub use detector::Detector;
 pub use filter_list::FilterList;
-pub use info::Info;
+pub use info::{Info, ScriptDetail};
+pub use lang_set::LangSet;
 pub use method::Method;
+pub use mixed::{detect_all, detect_mixed, is_multilingual};
 pub use options::Options;
+pub use options_builder::OptionsBuilder;
+pub use outcome::{calculate_scores, score_for, Outcome};
 pub use query::{InternalQuery, Query};
-pub use text::{LowercaseText, Text};
+pub use stats::DetectStats;
+pub use streaming::StreamingDetector;
+pub use text::{LowercaseText, Text, TextSettings};
@@ -1,16 +1,61 @@
-use super::{FilterList, Method};
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 
-#[derive(Debug, Clone)]
+use super::{FilterList, LangSet, Method};
+use crate::error::Error;
+use crate::trigrams::{Trigram, TrigramModels, MODEL_VERSION, MODEL_VERSIONS};
+use crate::{Lang, Script};
+
+/// Options to customize detection, see `detect_with_options`.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Options {
     pub(crate) filter_list: FilterList,
+    pub(crate) allowed_scripts: Option<Vec<Script>>,
+    // Computed from `filter_list` and `allowed_scripts`, so it's not rebuilt on every detection
+    pub(crate) allowed_langs: LangSet,
     pub(crate) method: Method,
+    pub(crate) min_text_length: usize,
+    pub(crate) min_trigram_count: usize,
+    pub(crate) min_confidence: f64,
+    pub(crate) trigram_models: Option<Arc<TrigramModels>>,
+    pub(crate) normalize_by_model_size: bool,
+    pub(crate) fast_ascii: bool,
+    pub(crate) han_bias: f64,
+    pub(crate) alphabet_weight: Option<f64>,
+    pub(crate) boundary_padding: bool,
+    pub(crate) ignore_digits: bool,
+    pub(crate) case_sensitive: bool,
+    #[cfg(feature = "unicode-normalization")]
+    pub(crate) normalize_unicode: bool,
+    pub(crate) preferred_lang: Option<Lang>,
+    pub(crate) max_candidates: Option<usize>,
+    pub(crate) model_version: &'static str,
 }
 
 impl Options {
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
             filter_list: FilterList::All,
+            allowed_scripts: None,
+            allowed_langs: LangSet::all(),
             method: Method::Combined,
+            min_text_length: 0,
+            min_trigram_count: 0,
+            min_confidence: 0.0,
+            trigram_models: None,
+            normalize_by_model_size: false,
+            fast_ascii: false,
+            han_bias: 0.0,
+            alphabet_weight: None,
+            boundary_padding: true,
+            ignore_digits: false,
+            case_sensitive: false,
+            #[cfg(feature = "unicode-normalization")]
+            normalize_unicode: false,
+            preferred_lang: None,
+            max_candidates: None,
+            model_version: MODEL_VERSION,
         }
     }
 
@@ -26,9 +71,265 @@ impl Options {
 
     pub fn set_filter_list(mut self, filter_list: FilterList) -> Self {
         self.filter_list = filter_list;
+        self.update_allowed_langs();
+        self
+    }
+
+    /// Restrict detection to languages written with the given scripts (see `Script::langs`).
+    /// It's combined with the filter list, so a language must be allowed by both of them.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{detect_with_options, Lang, Options, Script};
+    /// let options = Options::new().set_allowed_scripts(vec![Script::Cyrillic]);
+    /// let info = detect_with_options("Привет, как дела?", &options).unwrap();
+    /// assert_eq!(info.lang(), Lang::Rus);
+    /// assert_eq!(detect_with_options("Hello, how are you?", &options), None);
+    /// ```
+    pub fn set_allowed_scripts(mut self, scripts: Vec<Script>) -> Self {
+        self.allowed_scripts = Some(scripts);
+        self.update_allowed_langs();
+        self
+    }
+
+    fn update_allowed_langs(&mut self) {
+        let allowed_langs = LangSet::from_filter_list(&self.filter_list);
+        self.allowed_langs = match self.allowed_scripts {
+            Some(ref scripts) => allowed_langs.intersection(LangSet::from_scripts(scripts)),
+            None => allowed_langs,
+        };
+    }
+
+    /// Check that options are consistent. It returns an error when:
+    /// * a language is both in allowlist and denylist (such a language is never detected,
+    ///   because the allowlist is applied first and then the denylist is applied on top of it)
+    /// * allowlist is empty, so no language can be detected
+    pub fn validate(&self) -> Result<(), Error> {
+        self.filter_list.validate()
+    }
+
+    /// Set minimal number of characters (ignoring spaces, punctuation and digits)
+    /// a text must have to be detected. Shorter texts are not detected at all.
+    /// By default there is no minimum.
+    pub fn set_min_text_length(mut self, min_text_length: usize) -> Self {
+        self.min_text_length = min_text_length;
+        self
+    }
+
+    /// Set minimal number of unique trigrams a text must have, so the language can be
+    /// chosen among languages of the same script (see `Info::trigram_count`).
+    /// Unlike `set_min_text_length`, it also takes into account that spaces, punctuation
+    /// and repeated characters give few trigrams. Languages determined by the script alone
+    /// (e.g. Georgian) are not affected. By default there is no minimum.
+    ///
+    /// With `Method::Alphabet` (available with the `dev` feature) trigrams are not compared,
+    /// so the number of letters compared with alphabets is checked instead.
+    pub fn set_min_trigram_count(mut self, min_trigram_count: usize) -> Self {
+        self.min_trigram_count = min_trigram_count;
+        self
+    }
+
+    /// Set minimal confidence of the detected language. When confidence is lower,
+    /// nothing is detected. The value is clamped to the range 0.0..=1.0.
+    /// Default is 0.0, so the most likely language is always returned.
+    pub fn set_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_confidence = min_confidence.clamp(0.0, 1.0);
         self
     }
 
+    /// Set custom trigram models, that are used instead of the built-in models
+    /// of the given languages. Every language has a list of trigrams with their ranks
+    /// (0 is the most frequent trigram). Only the first 300 trigrams are used.
+    /// Models can be given as any collection of pairs, e.g. `HashMap<Lang, Vec<(Trigram, u32)>>`.
+    pub fn set_trigram_models(
+        mut self,
+        models: impl IntoIterator<Item = (Lang, Vec<(Trigram, u32)>)>,
+    ) -> Self {
+        self.trigram_models = Some(Arc::new(TrigramModels::new(models)));
+        self
+    }
+
+    /// Let languages with small trigram models (fewer than 300 trigrams, which is possible
+    /// with custom models, see `set_trigram_models`) compete fairly with the full ones.
+    /// By default every trigram missing in a model counts as a trigram that is not found
+    /// in the text, so small models get systematically lower scores. With normalization
+    /// the similarity of a small model to the text (the opposite of the distance) is multiplied
+    /// by `300 / model size` (up to the maximal similarity), as if the missing trigrams
+    /// were found as often as the present ones. It's noisy for tiny models of a few dozens
+    /// of trigrams, since a couple of matches are multiplied many times.
+    /// The built-in models are full, so they are not affected. Disabled by default.
+    pub fn set_normalize_by_model_size(mut self, normalize_by_model_size: bool) -> Self {
+        self.normalize_by_model_size = normalize_by_model_size;
+        self
+    }
+
+    /// Enable a fast path for English: a long enough pure ASCII text is first checked
+    /// against a small set of the most common English trigrams, and if it clearly matches,
+    /// English is returned without scoring other languages. Otherwise the text is detected
+    /// as usual. The fast path is not used with custom trigram models, a minimal trigram count
+    /// or a preferred language, since they can change the result. Disabled by default.
+    pub fn set_fast_ascii(mut self, fast_ascii: bool) -> Self {
+        self.fast_ascii = fast_ascii;
+        self
+    }
+
+    /// Set preference between Mandarin and Japanese for text written only with Han
+    /// characters (without any Hiragana or Katakana), which can be either of them.
+    /// The value is clamped to the range 0.0..=1.0: 0.0 prefers Mandarin, 1.0 prefers
+    /// Japanese and 0.5 is no preference at all. The further the value is from 0.5,
+    /// the higher the confidence is. Default is 0.0 (Mandarin).
+    /// Text that has Hiragana or Katakana is not affected.
+    pub fn set_han_bias(mut self, han_bias: f64) -> Self {
+        self.han_bias = han_bias.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set weight of alphabet scores when they are blended with trigram scores
+    /// for scripts shared by several languages (e.g. Latin or Cyrillic).
+    /// Alphabet scores tell languages apart by their characteristic characters
+    /// (e.g. `ő` or `ł`), which matters most for short texts with few trigrams.
+    /// The value is clamped to the range 0.0..=1.0: 0.0 uses only trigrams and 1.0 uses only
+    /// alphabets. By default the weight depends on the text length: it goes down
+    /// from 2/3 for the shortest texts to 1/3 for texts of 100 characters and longer.
+    pub fn set_alphabet_weight(mut self, alphabet_weight: f64) -> Self {
+        self.alphabet_weight = Some(alphabet_weight.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Enable or disable padding of word boundaries with spaces when a text is split
+    /// into trigrams. With padding every word also gives trigrams of its beginning and
+    /// its end (e.g. `" he"` and `"lo "` for `"hello"`), which helps to detect short texts.
+    /// Without padding only trigrams within words are used.
+    /// Enabled by default. The built-in models are built with padding, so disabling it
+    /// makes sense only with custom models built without padding (see `set_trigram_models`).
+    pub fn set_boundary_padding(mut self, boundary_padding: bool) -> Self {
+        self.boundary_padding = boundary_padding;
+        self
+    }
+
+    /// Ignore digits of all scripts (e.g. Arabic-Indic `٣` or Devanagari `३`) the same way
+    /// as spaces and punctuation, when characters and trigrams of a text are compared
+    /// with languages. ASCII digits are always ignored. Script detection is not affected.
+    /// Disabled by default.
+    pub fn set_ignore_digits(mut self, ignore_digits: bool) -> Self {
+        self.ignore_digits = ignore_digits;
+        self
+    }
+
+    /// Treat uppercase and lowercase characters as distinct, when characters and trigrams
+    /// of a text are compared with languages. By default the text is lowercased first.
+    /// The built-in alphabets have both cases of their letters, so they score the same way,
+    /// but the built-in trigram models are lowercase, so with case-sensitive detection trigrams
+    /// with uppercase characters are not found in them (but they can be found in custom models,
+    /// see `set_trigram_models`). The fast path for English (see `set_fast_ascii`) is not used.
+    /// Script detection is not affected. Disabled by default.
+    pub fn set_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Compose the text to Unicode NFC before its characters and trigrams are compared with
+    /// languages. Text from different sources can be precomposed (NFC, e.g. `é` is one char)
+    /// or decomposed (NFD, `e` followed by a combining acute accent), and the built-in alphabets
+    /// and trigram models are precomposed, so decomposed text gets lower scores.
+    /// Normalization takes time and may allocate a copy of the text, so it's disabled by default.
+    /// With `StreamingDetector` every chunk is normalized separately, so a combining mark
+    /// at the start of a chunk is not composed with the letter at the end of the previous one.
+    ///
+    /// Available with the `unicode-normalization` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{detect, detect_with_options, Lang, Options};
+    /// // "Ceci est une phrase très simple en français" decomposed
+    /// let text = "Ceci est une phrase tre\u{300}s simple en franc\u{327}ais";
+    /// let options = Options::new().set_normalize_unicode(true);
+    /// let info = detect_with_options(text, &options).unwrap();
+    /// assert_eq!(info.lang(), Lang::Fra);
+    /// assert_eq!(Some(info), detect("Ceci est une phrase très simple en français"));
+    /// ```
+    #[cfg(feature = "unicode-normalization")]
+    pub fn set_normalize_unicode(mut self, normalize_unicode: bool) -> Self {
+        self.normalize_unicode = normalize_unicode;
+        self
+    }
+
+    /// Set a language that is expected (e.g. the language of the user interface), so it wins
+    /// near-ties: when the most likely language is not confidently better than the preferred one
+    /// (its confidence against the preferred language is below 1.0), the score of the preferred
+    /// language is raised by 5% (relatively). So the preferred language is detected if its score
+    /// is lower by less than 5%, otherwise the result is the same. Unlike the filter list, it never
+    /// excludes other languages.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{detect, detect_with_options, Lang, Options};
+    /// let text = "Eu sou de Lisboa";
+    /// assert_eq!(detect(text).unwrap().lang(), Lang::Fra);
+    ///
+    /// let options = Options::new().set_preferred(Lang::Por);
+    /// let info = detect_with_options(text, &options).unwrap();
+    /// assert_eq!(info.lang(), Lang::Por);
+    /// ```
+    pub fn set_preferred(mut self, lang: Lang) -> Self {
+        self.preferred_lang = Some(lang);
+        self
+    }
+
+    /// Set the maximal number of languages compared by trigrams for scripts shared by several
+    /// languages (e.g. Latin). Characters of the text are compared with alphabets of all
+    /// the allowed languages first, which is cheap, and only the given number of languages
+    /// with the highest alphabet scores are scored with trigrams, which is the most expensive
+    /// part of detection. It makes detection faster, but less accurate: alphabets tell apart
+    /// only texts with characteristic letters (e.g. `ß` or `ñ`), so the right language can be
+    /// dropped before its trigrams are checked. Languages with the same alphabet score as the
+    /// last candidate are kept as candidates too, so for a text without characteristic letters
+    /// (e.g. plain ASCII), which many languages get equal alphabet scores for, more languages
+    /// than the given number are scored. The result is always the same as without the limit
+    /// only when the number is not smaller than the number of languages of the script
+    /// (see `Script::langs`). The value is at least 1. By default there is no limit.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{detect_with_options, Lang, Options};
+    /// let text = "Ich möchte heute ins Theater gehen, aber ich bin zu müde.";
+    /// let options = Options::new().set_max_candidates(5);
+    /// let info = detect_with_options(text, &options).unwrap();
+    /// assert_eq!(info.lang(), Lang::Deu);
+    /// ```
+    pub fn set_max_candidates(mut self, max_candidates: usize) -> Self {
+        self.max_candidates = Some(max_candidates.max(1));
+        self
+    }
+
+    /// Pin detection to the given version of the built-in models (see `Detector::model_version`),
+    /// so results stored with a previous release of the crate can be reproduced.
+    /// An error is returned if the models of the version are not compiled in.
+    /// Only the current version is available for now, so pinning it makes an upgrade that
+    /// changes the models fail loudly instead of silently changing the results.
+    /// Custom trigram models (see `set_trigram_models`) are not versioned.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{Detector, Options};
+    /// let version = Detector::new().model_version();
+    /// let options = Options::new().set_model_version(version).unwrap();
+    /// assert_eq!(options.model_version(), version);
+    /// assert!(Options::new().set_model_version("0.1.0").is_err());
+    /// ```
+    pub fn set_model_version(mut self, version: &str) -> Result<Self, Error> {
+        self.model_version = MODEL_VERSIONS
+            .iter()
+            .find(|&&v| v == version)
+            .ok_or_else(|| Error::UnknownModelVersion(version.to_string()))?;
+        Ok(self)
+    }
+
+    /// Get the version of the built-in models used for detection, see `set_model_version`.
+    pub fn model_version(&self) -> &'static str {
+        self.model_version
+    }
+
     #[cfg(feature = "dev")]
     pub fn set_method(mut self, method: Method) -> Self {
         self.method = method;
@@ -41,3 +342,42 @@ impl Default for Options {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clone() {
+        let options = Options::new()
+            .set_filter_list(FilterList::allow(vec![Lang::Eng, Lang::Rus]))
+            .set_min_text_length(3);
+        let cloned = options.clone();
+        assert_eq!(cloned, options);
+
+        let changed = cloned.set_min_text_length(5);
+        assert_ne!(changed, options);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Options::new().validate().is_ok());
+
+        let filter_list = FilterList::allow_deny(vec![Lang::Eng, Lang::Rus], vec![Lang::Rus]);
+        let options = Options::new().set_filter_list(filter_list);
+        assert!(matches!(
+            options.validate(),
+            Err(Error::AllowedAndDenied(Lang::Rus))
+        ));
+    }
+
+    #[test]
+    fn test_set_model_version() {
+        let options = Options::new().set_model_version(MODEL_VERSION).unwrap();
+        assert_eq!(options, Options::new());
+        assert_eq!(options.model_version(), MODEL_VERSION);
+
+        let result = Options::new().set_model_version("0.0.1");
+        assert!(matches!(result, Err(Error::UnknownModelVersion(ref v)) if v == "0.0.1"));
+    }
+}
@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+
+use super::{FilterList, Options};
+use crate::{Lang, Script};
+
+/// Fluent way to build `Options` with allowed and denied languages.
+///
+/// # Example
+/// ```
+/// use whatlang::{detect_with_options, Lang, OptionsBuilder, Script};
+/// let options = OptionsBuilder::new()
+///     .allow_script(Script::Cyrillic)
+///     .allow(Lang::Eng)
+///     .deny(Lang::Bul)
+///     .build();
+/// assert!(options.validate().is_ok());
+/// let info = detect_with_options("Привет, как дела?", &options).unwrap();
+/// assert_eq!(info.lang(), Lang::Rus);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OptionsBuilder {
+    allowlist: Vec<Lang>,
+    denylist: Vec<Lang>,
+}
+
+impl OptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow the language. Once any language is allowed, only allowed languages are detected.
+    pub fn allow(mut self, lang: Lang) -> Self {
+        if !self.allowlist.contains(&lang) {
+            self.allowlist.push(lang);
+        }
+        self
+    }
+
+    /// Allow all languages written with the script, see `Script::langs`.
+    pub fn allow_script(self, script: Script) -> Self {
+        script
+            .langs()
+            .iter()
+            .fold(self, |builder, &lang| builder.allow(lang))
+    }
+
+    /// Deny the language. It's not detected even if it's allowed.
+    pub fn deny(mut self, lang: Lang) -> Self {
+        if !self.denylist.contains(&lang) {
+            self.denylist.push(lang);
+        }
+        self
+    }
+
+    /// Build options with the filter list of the allowed and denied languages.
+    /// When some languages are allowed, the denied ones are removed from them,
+    /// so a language is never both allowed and denied (see `Options::validate`).
+    pub fn build(self) -> Options {
+        let filter_list = match (self.allowlist.is_empty(), self.denylist.is_empty()) {
+            (true, true) => FilterList::All,
+            (true, false) => FilterList::deny(self.denylist),
+            (false, _) => {
+                let denylist = self.denylist;
+                let allowlist = self
+                    .allowlist
+                    .into_iter()
+                    .filter(|lang| !denylist.contains(lang))
+                    .collect();
+                FilterList::allow(allowlist)
+            }
+        };
+        Options::new().set_filter_list(filter_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_build() {
+        assert_eq!(OptionsBuilder::new().build(), Options::new());
+
+        let options = OptionsBuilder::new()
+            .allow(Lang::Eng)
+            .allow(Lang::Rus)
+            .allow(Lang::Eng)
+            .build();
+        let expected =
+            Options::new().set_filter_list(FilterList::allow(vec![Lang::Eng, Lang::Rus]));
+        assert_eq!(options, expected);
+
+        let options = OptionsBuilder::new().deny(Lang::Eng).build();
+        assert_eq!(options.filter_list, FilterList::deny(vec![Lang::Eng]));
+        assert!(options.validate().is_ok());
+
+        let options = OptionsBuilder::new()
+            .allow(Lang::Eng)
+            .allow(Lang::Rus)
+            .deny(Lang::Rus)
+            .deny(Lang::Ukr)
+            .build();
+        assert_eq!(options.filter_list, FilterList::allow(vec![Lang::Eng]));
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn test_allow_script() {
+        let options = OptionsBuilder::new()
+            .allow_script(Script::Cyrillic)
+            .deny(Lang::Ukr)
+            .build();
+        assert!(options.validate().is_ok());
+        assert!(options.filter_list.is_allowed(Lang::Rus));
+        assert!(options.filter_list.is_allowed(Lang::Bel));
+        assert!(!options.filter_list.is_allowed(Lang::Ukr));
+        assert!(!options.filter_list.is_allowed(Lang::Eng));
+    }
+}
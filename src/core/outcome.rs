@@ -0,0 +1,209 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::detect::{detect_lang_base_on_mandarin_script, write_combined_scores};
+use super::{Method, Options, Query};
+use crate::scripts::grouping::ScriptLangGroup;
+use crate::scripts::{raw_detect_script, Script};
+use crate::{alphabets, trigrams, Lang};
+
+/// Scores of all the languages considered for a text, before the most likely one is picked
+/// and its confidence is calculated, see `calculate_scores`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    /// Script of the text. Only languages written with it are scored.
+    pub script: Script,
+    /// Number of letters of the script in the text (after the options are applied,
+    /// e.g. without digits if they are ignored), so raw scores are within `0..=max_raw_score`.
+    /// It's 0 for scripts used by a single language and for Mandarin, which are not scored.
+    pub max_raw_score: usize,
+    /// Alphabet scores of languages sorted in descending order: number of letters of the text
+    /// which belong to the alphabet of the language minus number of letters which don't
+    /// (0 if there are more of the latter). Languages of a script without alphabets
+    /// (e.g. Devanagari) get equal raw scores.
+    pub raw_scores: Vec<(Lang, usize)>,
+    /// Scores of languages within 0.0..=1.0 sorted in descending order, the same as
+    /// `detect_with_scores` returns. They are raw scores divided by `max_raw_score`
+    /// blended with scores of trigrams of the text, so the order can differ from the raw one.
+    /// Scripts used by a single language and Mandarin give only the detected language with 1.0,
+    /// or nothing if it's not allowed by the options.
+    pub normalized_scores: Vec<(Lang, f64)>,
+}
+
+/// Calculate scores of languages for a given text using the given options, without picking
+/// the most likely language. It's meant for custom decision logic, so the thresholds of the
+/// options (e.g. `Options::set_min_confidence`), the preferred language and the fast path for
+/// English are not applied. `None` is returned if the text has no script to detect by.
+///
+/// # Example
+/// ```
+/// use whatlang::{calculate_scores, Lang, Options, Script};
+/// let outcome = calculate_scores("Та нічого, все нормально. А в тебе як?", &Options::default()).unwrap();
+/// assert_eq!(outcome.script, Script::Cyrillic);
+/// assert_eq!(outcome.normalized_scores[0].0, Lang::Ukr);
+/// assert_eq!(outcome.raw_scores[0], (Lang::Ukr, outcome.max_raw_score));
+/// assert_eq!(calculate_scores("12345", &Options::default()), None);
+/// ```
+pub fn calculate_scores(text: &str, options: &Options) -> Option<Outcome> {
+    let query = Query::new(text, options);
+    let raw_script_info = raw_detect_script(text);
+    let script = raw_script_info.main_script()?;
+
+    let outcome = match script.to_lang_group() {
+        ScriptLangGroup::One(lang) => {
+            if query.allowed_langs_of_script(script).contains(lang) {
+                Outcome::determined(script, lang)
+            } else {
+                Outcome::filtered(script)
+            }
+        }
+        ScriptLangGroup::Multi(multi_lang_script) => {
+            if !multi_lang_script.is_enabled() {
+                return None;
+            }
+            let mut iquery = query.to_internal(multi_lang_script);
+            let alphabet_outcome = alphabets::raw_detect(&mut iquery);
+            let mut normalized_scores = vec![];
+            match query.method {
+                Method::Alphabet => normalized_scores.extend(&alphabet_outcome.scores),
+                Method::Trigram => {
+                    normalized_scores.extend(trigrams::raw_detect(&mut iquery).scores);
+                }
+                Method::Combined => {
+                    let trigram_outcome = trigrams::raw_detect(&mut iquery);
+                    write_combined_scores(
+                        &query,
+                        &alphabet_outcome,
+                        &trigram_outcome,
+                        &mut normalized_scores,
+                    );
+                }
+            }
+            Outcome {
+                script,
+                max_raw_score: alphabet_outcome.count,
+                raw_scores: alphabet_outcome.raw_scores,
+                normalized_scores,
+            }
+        }
+        ScriptLangGroup::Mandarin => {
+            match detect_lang_base_on_mandarin_script(&query, &raw_script_info) {
+                Some(info) => Outcome::determined(script, info.lang()),
+                None => Outcome::filtered(script),
+            }
+        }
+    };
+    Some(outcome)
+}
+
+/// Get the score of the given language for a given text using the given options,
+/// see `Outcome::normalized_scores`. Unlike confidence of a detected language, it doesn't
+/// depend on scores of other languages. `None` is returned if the language is not a candidate
+/// for the script of the text (e.g. it's written with another script or it's filtered out).
+///
+/// # Example
+/// ```
+/// use whatlang::{score_for, Lang, Options};
+/// let text = "There is no reason not to learn Esperanto.";
+/// let options = Options::default();
+/// assert!(score_for(text, Lang::Eng, &options).unwrap() > score_for(text, Lang::Epo, &options).unwrap());
+/// assert_eq!(score_for(text, Lang::Rus, &options), None);
+/// ```
+pub fn score_for(text: &str, lang: Lang, options: &Options) -> Option<f64> {
+    calculate_scores(text, options)?
+        .normalized_scores
+        .iter()
+        .find(|&&(l, _)| l == lang)
+        .map(|&(_, score)| score)
+}
+
+impl Outcome {
+    fn determined(script: Script, lang: Lang) -> Self {
+        Self {
+            script,
+            max_raw_score: 0,
+            raw_scores: vec![],
+            normalized_scores: vec![(lang, 1.0)],
+        }
+    }
+
+    // The language of the script is not allowed by the options.
+    fn filtered(script: Script) -> Self {
+        Self {
+            script,
+            max_raw_score: 0,
+            raw_scores: vec![],
+            normalized_scores: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{detect_with_scores, FilterList};
+
+    #[test]
+    fn test_calculate_scores() {
+        let text = "Jen la trinkejo fermitis, ni iras tra mallumo kaj pluvo.";
+        let options = Options::default();
+        let outcome = calculate_scores(text, &options).unwrap();
+        let (_, scores) = detect_with_scores(text, &options).unwrap();
+        assert_eq!(outcome.script, Script::Latin);
+        assert_eq!(outcome.normalized_scores, scores);
+        assert!(outcome
+            .raw_scores
+            .iter()
+            .all(|&(_, score)| score <= outcome.max_raw_score));
+
+        let mut options = Options::new();
+        options.method = Method::Alphabet;
+        let outcome = calculate_scores(text, &options).unwrap();
+        for (&(lang, raw_score), &(l, score)) in
+            outcome.raw_scores.iter().zip(&outcome.normalized_scores)
+        {
+            assert_eq!(lang, l);
+            assert_eq!(score, raw_score as f64 / outcome.max_raw_score as f64);
+        }
+    }
+
+    #[test]
+    fn test_calculate_scores_ignores_thresholds() {
+        let text = "Та нічого";
+        let options = Options::new()
+            .set_min_confidence(1.0)
+            .set_min_trigram_count(100);
+        assert_eq!(detect_with_scores(text, &options), None);
+        let outcome = calculate_scores(text, &options).unwrap();
+        assert_eq!(outcome.normalized_scores[0].0, Lang::Ukr);
+
+        let options = Options::new().set_filter_list(FilterList::deny(vec![Lang::Ukr]));
+        let outcome = calculate_scores(text, &options).unwrap();
+        assert!(outcome
+            .normalized_scores
+            .iter()
+            .all(|&(l, _)| l != Lang::Ukr));
+    }
+
+    #[test]
+    fn test_score_for() {
+        let text = "I am going to the market to buy some bread and milk.";
+        let options = Options::default();
+        let eng_score = score_for(text, Lang::Eng, &options).unwrap();
+        let (_, scores) = detect_with_scores(text, &options).unwrap();
+        assert_eq!(scores[0], (Lang::Eng, eng_score));
+        assert!(score_for(text, Lang::Deu, &options).unwrap() < eng_score);
+        // Russian is not written with Latin script
+        assert_eq!(score_for(text, Lang::Rus, &options), None);
+
+        let options = Options::new().set_filter_list(FilterList::deny(vec![Lang::Eng]));
+        assert_eq!(score_for(text, Lang::Eng, &options), None);
+        assert_eq!(score_for("", Lang::Eng, &options), None);
+    }
+
+    #[test]
+    fn test_calculate_scores_of_one_lang_script() {
+        let outcome = calculate_scores("ქართული ენა", &Options::default()).unwrap();
+        assert_eq!(outcome, Outcome::determined(Script::Georgian, Lang::Kat));
+    }
+}
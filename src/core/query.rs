@@ -1,26 +1,118 @@
-use super::{FilterList, Method, Text};
+use super::{LangSet, Method, Options, Text, TextSettings};
 use crate::scripts::grouping::MultiLangScript;
+use crate::trigrams::utils::TrigramSettings;
+use crate::trigrams::TrigramModels;
+use crate::{Lang, Script};
 
 pub struct Query<'a, 'b> {
     pub(crate) text: &'a str,
-    pub(crate) filter_list: &'b FilterList,
+    pub(crate) allowed_langs: LangSet,
+    pub(crate) allowed_scripts: Option<&'b [Script]>,
     pub(crate) method: Method,
+    pub(crate) min_text_length: usize,
+    pub(crate) min_trigram_count: usize,
+    pub(crate) min_confidence: f64,
+    pub(crate) trigram_models: Option<&'b TrigramModels>,
+    pub(crate) normalize_by_model_size: bool,
+    pub(crate) fast_ascii: bool,
+    pub(crate) han_bias: f64,
+    pub(crate) alphabet_weight: Option<f64>,
+    pub(crate) boundary_padding: bool,
+    pub(crate) ignore_digits: bool,
+    pub(crate) case_sensitive: bool,
+    #[cfg(feature = "unicode-normalization")]
+    pub(crate) normalize_unicode: bool,
+    pub(crate) preferred_lang: Option<Lang>,
+    pub(crate) max_candidates: Option<usize>,
+    // Scoring stops as soon as a language is guaranteed to get a higher confidence,
+    // see `detect_until`. It never stops with 1.0.
+    pub(crate) confidence_bar: f64,
 }
 
 // TODO: find a better name?
 // A query after script detection
 pub struct InternalQuery<'a, 'b> {
     pub(crate) text: Text<'a>,
-    pub(crate) filter_list: &'b FilterList,
+    pub(crate) allowed_langs: LangSet,
     pub(crate) multi_lang_script: MultiLangScript,
+    pub(crate) trigram_models: Option<&'b TrigramModels>,
+    pub(crate) normalize_by_model_size: bool,
+    pub(crate) boundary_padding: bool,
+    pub(crate) ignore_digits: bool,
 }
 
 impl<'a, 'b> Query<'a, 'b> {
+    pub(crate) fn new(text: &'a str, options: &'b Options) -> Self {
+        Self {
+            text,
+            allowed_langs: options.allowed_langs,
+            allowed_scripts: options.allowed_scripts.as_deref(),
+            method: options.method,
+            min_text_length: options.min_text_length,
+            min_trigram_count: options.min_trigram_count,
+            min_confidence: options.min_confidence,
+            trigram_models: options.trigram_models.as_deref(),
+            normalize_by_model_size: options.normalize_by_model_size,
+            fast_ascii: options.fast_ascii,
+            han_bias: options.han_bias,
+            alphabet_weight: options.alphabet_weight,
+            boundary_padding: options.boundary_padding,
+            ignore_digits: options.ignore_digits,
+            case_sensitive: options.case_sensitive,
+            #[cfg(feature = "unicode-normalization")]
+            normalize_unicode: options.normalize_unicode,
+            preferred_lang: options.preferred_lang,
+            max_candidates: options.max_candidates,
+            confidence_bar: 1.0,
+        }
+    }
+
     pub(crate) fn to_internal(&self, multi_lang_script: MultiLangScript) -> InternalQuery<'a, 'b> {
         InternalQuery {
-            text: Text::new(self.text),
-            filter_list: self.filter_list,
+            text: Text::new(self.text, self.text_settings()),
+            allowed_langs: self.allowed_langs_of(multi_lang_script),
             multi_lang_script,
+            trigram_models: self.trigram_models,
+            normalize_by_model_size: self.normalize_by_model_size,
+            boundary_padding: self.boundary_padding,
+            ignore_digits: self.ignore_digits,
+        }
+    }
+
+    // Some languages are written with multiple scripts (e.g. Azerbaijani with Latin and Cyrillic),
+    // so a text written with a script that is not allowed must not be detected as such a language.
+    pub(crate) fn allowed_langs_of(&self, multi_lang_script: MultiLangScript) -> LangSet {
+        self.allowed_langs_of_script(multi_lang_script.to_script())
+    }
+
+    pub(crate) fn allowed_langs_of_script(&self, script: Script) -> LangSet {
+        match self.allowed_scripts {
+            Some(scripts) if !scripts.contains(&script) => LangSet::empty(),
+            _ => self.allowed_langs,
+        }
+    }
+
+    pub(crate) fn trigram_settings(&self) -> TrigramSettings {
+        TrigramSettings {
+            boundary_padding: self.boundary_padding,
+            ignore_digits: self.ignore_digits,
+        }
+    }
+
+    pub(crate) fn text_settings(&self) -> TextSettings {
+        TextSettings {
+            case_sensitive: self.case_sensitive,
+            #[cfg(feature = "unicode-normalization")]
+            normalize_unicode: self.normalize_unicode,
+        }
+    }
+}
+
+impl<'a, 'b> InternalQuery<'a, 'b> {
+    pub(crate) fn trigram_settings(&self) -> TrigramSettings {
+        TrigramSettings {
+            boundary_padding: self.boundary_padding,
+            ignore_digits: self.ignore_digits,
         }
     }
 }
@@ -0,0 +1,40 @@
+/// Numbers describing how a text was processed by detection, see `detect_with_stats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DetectStats {
+    pub(crate) chars: usize,
+    pub(crate) skipped_chars: usize,
+    pub(crate) trigrams: usize,
+    pub(crate) candidates: usize,
+    pub(crate) alphabet_used: bool,
+}
+
+impl DetectStats {
+    /// Number of characters of the text.
+    pub fn chars(&self) -> usize {
+        self.chars
+    }
+
+    /// Number of characters skipped by scoring: ASCII whitespace, punctuation and digits,
+    /// zero-width characters (and digits of all scripts if they are ignored,
+    /// see `Options::set_ignore_digits`).
+    pub fn skipped_chars(&self) -> usize {
+        self.skipped_chars
+    }
+
+    /// Number of unique trigrams of the text compared with languages, see `Info::trigram_count`.
+    /// It's 0 when the language is determined without comparing trigrams.
+    pub fn trigrams(&self) -> usize {
+        self.trigrams
+    }
+
+    /// Number of languages that were scored. It's 1 when the language is determined
+    /// without scoring, e.g. by the script alone.
+    pub fn candidates(&self) -> usize {
+        self.candidates
+    }
+
+    /// Whether characters of the text were compared with alphabets of languages.
+    pub fn alphabet_used(&self) -> bool {
+        self.alphabet_used
+    }
+}
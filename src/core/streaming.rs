@@ -0,0 +1,245 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::detect::{
+    detect_lang_base_on_mandarin_script, nudge_preferred_lang, scores_to_infos, top_candidates,
+    with_japanese_script_detail, write_combined_scores,
+};
+use super::{Info, LowercaseText, Method, Options, Query};
+use crate::scripts::grouping::{MultiLangScript, ScriptLangGroup};
+use crate::scripts::{char_script, RawScriptInfo, Script};
+use crate::trigrams::utils::TrigramCounter;
+use crate::utils::is_stop_char;
+use crate::{alphabets, trigrams, Lang};
+
+/// Detects a language of a text that arrives in chunks (e.g. live transcription).
+/// Every chunk is processed once when it's pushed, and the current best guess
+/// is calculated from the accumulated counts of scripts, characters and trigrams,
+/// so the text pushed before is never processed again.
+///
+/// Memory usage doesn't grow with the length of the text: it's bounded by the size
+/// of the trigram table, i.e. the number of distinct trigrams (and characters) of the text.
+///
+/// The result is the same as the one of `detect_with_options` for the whole text,
+/// except that the fast path for English (see `Options::set_fast_ascii`) is not used.
+///
+/// # Example
+/// ```
+/// use whatlang::{Lang, StreamingDetector};
+///
+/// let mut detector = StreamingDetector::new();
+/// assert_eq!(detector.current(), None);
+///
+/// detector.push("Та нічого, все ");
+/// detector.push("нормально. А в тебе як?");
+/// assert_eq!(detector.current().unwrap().lang(), Lang::Ukr);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamingDetector {
+    options: Options,
+    // Number of chars except stop chars, to check the minimal text length
+    text_length: usize,
+    script_counters: Vec<(Script, usize)>,
+    char_counts: Vec<(char, i32)>,
+    trigram_counter: TrigramCounter,
+}
+
+impl StreamingDetector {
+    pub fn new() -> Self {
+        Self::with_options(Options::default())
+    }
+
+    pub fn with_options(options: Options) -> Self {
+        Self {
+            text_length: 0,
+            script_counters: Script::all().iter().map(|&script| (script, 0)).collect(),
+            char_counts: vec![],
+            trigram_counter: TrigramCounter::new(0, Query::new("", &options).trigram_settings()),
+            options,
+        }
+    }
+
+    /// Add the next chunk of the text.
+    pub fn push(&mut self, text: &str) {
+        for ch in text.chars().filter(|&ch| !is_stop_char(ch)) {
+            self.text_length += 1;
+            if let Some(script) = char_script(ch) {
+                // Script::all() has every script, so the counter is always found
+                if let Some((_, count)) =
+                    self.script_counters.iter_mut().find(|(s, _)| *s == script)
+                {
+                    *count += 1;
+                }
+            }
+        }
+
+        let settings = Query::new("", &self.options).text_settings();
+        let lowercase_text = LowercaseText::with_settings(text, settings);
+        alphabets::count_chars(
+            &lowercase_text,
+            self.options.ignore_digits,
+            &mut self.char_counts,
+        );
+        self.trigram_counter.push_text(&lowercase_text);
+    }
+
+    /// Get the best guess for the text pushed so far.
+    pub fn current(&self) -> Option<Info> {
+        let query = Query::new("", &self.options);
+        if self.text_length == 0 || self.text_length < query.min_text_length {
+            return None;
+        }
+
+        let raw_script_info = RawScriptInfo::new(self.script_counters.clone());
+        let script = raw_script_info.main_script()?;
+
+        let info = match script.to_lang_group() {
+            ScriptLangGroup::One(lang) => {
+                if !query.allowed_langs_of_script(script).contains(lang) {
+                    return None;
+                }
+                let info = Info::from_script(script, lang);
+                with_japanese_script_detail(info, &raw_script_info)
+            }
+            ScriptLangGroup::Multi(multi_lang_script) => {
+                if !multi_lang_script.is_enabled() {
+                    return None;
+                }
+                let mut scores = vec![];
+                let count = self.calculate_scores(&query, multi_lang_script, &mut scores);
+                if count < query.min_trigram_count {
+                    return None;
+                }
+                if let Some(preferred_lang) = query.preferred_lang {
+                    nudge_preferred_lang(&mut scores, preferred_lang, count);
+                }
+                let info = scores_to_infos(script, &scores, count).next()?;
+                info
+            }
+            ScriptLangGroup::Mandarin => {
+                let info = detect_lang_base_on_mandarin_script(&query, &raw_script_info)?;
+                with_japanese_script_detail(info, &raw_script_info)
+            }
+        };
+        Some(info).filter(|info| info.confidence() >= query.min_confidence)
+    }
+
+    // The same as `calculate_scores` of `detect`, but based on the accumulated counts.
+    fn calculate_scores(
+        &self,
+        query: &Query,
+        multi_lang_script: MultiLangScript,
+        scores: &mut Vec<(Lang, f64)>,
+    ) -> usize {
+        let alphabet_outcome = |allowed_langs| {
+            alphabets::raw_detect_by_char_counts(
+                &self.char_counts,
+                multi_lang_script,
+                allowed_langs,
+            )
+        };
+        let mut allowed_langs = query.allowed_langs_of(multi_lang_script);
+        let mut candidates_alphabet_outcome = None;
+        if let Some(max_candidates) = query.max_candidates {
+            let mut outcome = alphabet_outcome(allowed_langs);
+            allowed_langs = top_candidates(&outcome, max_candidates);
+            outcome.retain_langs(allowed_langs);
+            candidates_alphabet_outcome = Some(outcome);
+        }
+        let alphabet_outcome =
+            || candidates_alphabet_outcome.unwrap_or_else(|| alphabet_outcome(allowed_langs));
+        let trigram_outcome = || {
+            trigrams::raw_detect_by_positions(
+                &self.trigram_counter.trigram_positions(),
+                multi_lang_script,
+                allowed_langs,
+                query.trigram_models,
+                query.normalize_by_model_size,
+            )
+        };
+
+        scores.clear();
+        match query.method {
+            Method::Alphabet => {
+                let outcome = alphabet_outcome();
+                scores.extend(outcome.scores);
+                outcome.count
+            }
+            Method::Trigram => {
+                let outcome = trigram_outcome();
+                scores.extend(outcome.scores);
+                outcome.trigrams_count
+            }
+            Method::Combined => {
+                let alphabet_outcome = alphabet_outcome();
+                write_combined_scores(query, &alphabet_outcome, &trigram_outcome(), scores)
+            }
+        }
+    }
+}
+
+impl Default for StreamingDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{detect_with_options, FilterList};
+
+    fn assert_same_as_detect(chunks: &[&str], options: &Options) {
+        let mut detector = StreamingDetector::with_options(options.clone());
+        let mut text = alloc::string::String::new();
+        for chunk in chunks {
+            detector.push(chunk);
+            text.push_str(chunk);
+            assert_eq!(
+                detector.current(),
+                detect_with_options(&text, options),
+                "text: {:?}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_same_as_detect() {
+        let options = Options::default();
+        assert_same_as_detect(
+            &["Та нічого, ", "все норма", "льно. А в тебе як?"],
+            &options,
+        );
+        assert_same_as_detect(
+            &["There is no ", "reason not to ", "learn Esperanto."],
+            &options,
+        );
+        assert_same_as_detect(&["12", "ქართული ", "ენა"], &options);
+        assert_same_as_detect(&["テレビを見", "ながら、晩ご飯を食べました"], &options);
+        assert_same_as_detect(&["東京都庁は", "新宿区にある"], &options);
+
+        let options = Options::new()
+            .set_filter_list(FilterList::deny(vec![Lang::Ukr]))
+            .set_min_text_length(10)
+            .set_min_trigram_count(5);
+        assert_same_as_detect(
+            &["Та ", "нічого, ", "все нормально. А в тебе як?"],
+            &options,
+        );
+
+        let options = Options::new().set_max_candidates(3);
+        assert_same_as_detect(&["Eu sou ", "de Lisboa, ", "e tu és de onde?"], &options);
+
+        let options = Options::new().set_ignore_digits(true);
+        assert_same_as_detect(&["رقم ٠١٢٣", "٤٥٦٧٨٩ اتصل"], &options);
+    }
+
+    #[test]
+    fn test_current_without_text() {
+        let mut detector = StreamingDetector::new();
+        assert_eq!(detector.current(), None);
+        detector.push("  123, ...");
+        assert_eq!(detector.current(), None);
+    }
+}
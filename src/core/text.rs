@@ -1,18 +1,84 @@
-use std::ops::Deref;
+use alloc::borrow::Cow;
+use core::ops::Deref;
 
+// How the text is prepared for scoring, see `Options::set_case_sensitive`
+// and `Options::set_normalize_unicode`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TextSettings {
+    pub case_sensitive: bool,
+    #[cfg(feature = "unicode-normalization")]
+    pub normalize_unicode: bool,
+}
+
+/// Lowercased text. When the original text is already in lowercase
+/// (which is often the case for ASCII input), it's borrowed instead of copied,
+/// so no memory is allocated. Otherwise a lowercased copy of the text is kept,
+/// which takes about as much memory as the original text.
 #[derive(Debug)]
-pub struct LowercaseText {
-    inner: String,
+pub struct LowercaseText<'a> {
+    inner: Cow<'a, str>,
 }
 
-impl LowercaseText {
-    pub fn new(original_text: &str) -> Self {
-        let inner = original_text.to_lowercase();
+impl<'a> LowercaseText<'a> {
+    pub fn new(original_text: &'a str) -> Self {
+        let inner = if original_text.chars().all(is_lowercase_char) {
+            Cow::Borrowed(original_text)
+        } else {
+            Cow::Owned(original_text.to_lowercase())
+        };
         Self { inner }
     }
+
+    /// The text as it is, without lowercasing, for case-sensitive detection
+    /// (see `Options::set_case_sensitive`).
+    pub fn new_case_sensitive(original_text: &'a str) -> Self {
+        Self {
+            inner: Cow::Borrowed(original_text),
+        }
+    }
+
+    pub fn with_settings(original_text: &'a str, settings: TextSettings) -> Self {
+        let text = if settings.case_sensitive {
+            Self::new_case_sensitive(original_text)
+        } else {
+            Self::new(original_text)
+        };
+        #[cfg(feature = "unicode-normalization")]
+        let text = if settings.normalize_unicode {
+            text.into_nfc()
+        } else {
+            text
+        };
+        text
+    }
+
+    // Composes the text to NFC, so letters written with combining marks (NFD) are the same
+    // chars as the precomposed ones. Text that is already in NFC is kept as it is.
+    #[cfg(feature = "unicode-normalization")]
+    fn into_nfc(self) -> Self {
+        use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+        if is_nfc(&self.inner) {
+            return self;
+        }
+        Self {
+            inner: Cow::Owned(self.inner.nfc().collect()),
+        }
+    }
+}
+
+// Returns true if lowercasing keeps the char as is.
+#[inline]
+fn is_lowercase_char(ch: char) -> bool {
+    if ch.is_ascii() {
+        !ch.is_ascii_uppercase()
+    } else {
+        let mut lowercase = ch.to_lowercase();
+        lowercase.next() == Some(ch) && lowercase.next().is_none()
+    }
 }
 
-impl Deref for LowercaseText {
+impl<'a> Deref for LowercaseText<'a> {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
@@ -23,20 +89,23 @@ impl Deref for LowercaseText {
 #[derive(Debug)]
 pub struct Text<'a> {
     original: &'a str,
-    lowercase: Option<LowercaseText>,
+    settings: TextSettings,
+    lowercase: Option<LowercaseText<'a>>,
 }
 
 impl<'a> Text<'a> {
-    pub fn new(original_text: &'a str) -> Self {
+    pub fn new(original_text: &'a str, settings: TextSettings) -> Self {
         Self {
             original: original_text,
+            settings,
             lowercase: None,
         }
     }
 
-    pub fn lowercase(&mut self) -> &LowercaseText {
+    // The original text if detection is case-sensitive.
+    pub fn lowercase(&mut self) -> &LowercaseText<'a> {
         if self.lowercase.is_none() {
-            self.lowercase = Some(LowercaseText::new(self.original));
+            self.lowercase = Some(LowercaseText::with_settings(self.original, self.settings));
         }
         self.lowercase.as_ref().unwrap()
     }
@@ -45,10 +114,42 @@ impl<'a> Text<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Options, Query};
 
     #[test]
     fn test_text() {
-        let mut text = Text::new("Hello THERE");
+        let mut text = Text::new("Hello THERE", TextSettings::default());
         assert_eq!(text.lowercase().deref(), "hello there");
+        let options = Options::new().set_case_sensitive(true);
+        let settings = Query::new("", &options).text_settings();
+        let mut text = Text::new("Hello THERE", settings);
+        assert_eq!(text.lowercase().deref(), "Hello THERE");
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn test_lowercase_text_with_nfc() {
+        let options = Options::new().set_normalize_unicode(true);
+        let settings = Query::new("", &options).text_settings();
+        let text = LowercaseText::with_settings("E\u{0301}te\u{0301}", settings);
+        assert_eq!(text.deref(), "été");
+        let text = LowercaseText::with_settings("été", settings);
+        assert!(matches!(text.inner, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_lowercase_text_borrows_lowercase_input() {
+        let text = LowercaseText::new("hello there, 123!");
+        assert!(matches!(text.inner, Cow::Borrowed(_)));
+        let text = LowercaseText::new("привет всем");
+        assert!(matches!(text.inner, Cow::Borrowed(_)));
+
+        let text = LowercaseText::new("Привет всем");
+        assert!(matches!(text.inner, Cow::Owned(_)));
+        assert_eq!(text.deref(), "привет всем");
+
+        // Titlecase letter is not uppercase, but still changes when lowercased
+        let text = LowercaseText::new("ǅemal");
+        assert_eq!(text.deref(), "ǆemal");
     }
 }
@@ -4,14 +4,18 @@
 //!
 pub use crate::alphabets::{raw_detect as alphabets_raw_detect, RawOutcome as RawAlphabetsInfo};
 pub use crate::combined::{raw_detect as combined_raw_detect, RawOutcome as RawCombinedInfo};
-pub use crate::core::{detect, detect_lang, detect_with_options, Detector, Info, Method, Options};
+pub use crate::core::{
+    detect, detect_batch, detect_chars, detect_into, detect_lang, detect_lines, detect_reliable,
+    detect_reliable_with_options, detect_result, detect_top_n, detect_top_n_with_options,
+    detect_with_options, detect_with_scores, Detector, Info, Method, Options,
+};
 pub use crate::lang::Lang;
 pub use crate::scripts::{detect_script, raw_detect_script, RawScriptInfo, Script};
 pub use crate::trigrams::{raw_detect as trigrams_raw_detect, RawOutcome as RawTrigramsInfo};
 
 // private imports
 use crate::core::detect::detect_lang_base_on_mandarin_script;
-use crate::core::{FilterList, Query};
+use crate::core::Query;
 use crate::scripts::grouping::ScriptLangGroup;
 
 #[derive(Debug)]
@@ -30,11 +34,8 @@ pub enum RawLangInfo {
 pub fn raw_detect(text: &str) -> RawInfo {
     let script_info = raw_detect_script(text);
 
-    let query = Query {
-        text,
-        filter_list: &FilterList::default(),
-        method: Method::Combined,
-    };
+    let options = Options::default();
+    let query = Query::new(text, &options);
 
     let lang_info = script_info
         .main_script()
@@ -46,7 +47,9 @@ pub fn raw_detect(text: &str) -> RawInfo {
                 RawLangInfo::MultiScript(combined)
             }
             ScriptLangGroup::Mandarin => {
-                let lang = detect_lang_base_on_mandarin_script(&query, &script_info).lang();
+                let lang = detect_lang_base_on_mandarin_script(&query, &script_info)
+                    .expect("all languages are allowed by default options")
+                    .lang();
                 RawLangInfo::Mandarin(lang)
             }
         });
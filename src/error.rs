@@ -1,11 +1,17 @@
-use std::error::Error as StdError;
-use std::fmt::{self, Display};
+use alloc::string::String;
+use core::fmt::{self, Display};
 
+use crate::Lang;
+
+/// Error type of the crate.
 #[derive(Debug)]
 pub enum Error {
     ParseScript(String),
     ParseLang(String),
     ParseMethod(String),
+    AllowedAndDenied(Lang),
+    EmptyAllowlist,
+    UnknownModelVersion(String),
 }
 
 impl Display for Error {
@@ -20,8 +26,58 @@ impl Display for Error {
             Error::ParseMethod(ref val) => {
                 write!(f, "Cannot parse str into whatlang::Method: {:?}", val)
             }
+            Error::AllowedAndDenied(lang) => {
+                write!(f, "Language {:?} is both in allowlist and denylist", lang)
+            }
+            Error::EmptyAllowlist => write!(f, "Allowlist does not allow any language"),
+            Error::UnknownModelVersion(ref val) => {
+                write!(f, "Model version {:?} is not available", val)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Reason why a language could not be detected, see `detect_result`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectError {
+    /// Text is empty or consists of whitespace only.
+    EmptyInput,
+    /// Text is shorter than the minimal text length set in options.
+    TooShort,
+    /// Text has fewer unique trigrams than the minimal trigram count set in options.
+    TooFewTrigrams,
+    /// Text has no characters of any supported script (e.g. only digits or punctuation),
+    /// or its script is disabled at compile time (see `script-*` features).
+    NoScript,
+    /// All languages of the detected script are excluded by the filter list.
+    AllCandidatesFiltered,
+    /// Confidence of the most likely language is lower than the minimal confidence.
+    LowConfidence,
+}
+
+impl Display for DetectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DetectError::EmptyInput => write!(f, "Text is empty"),
+            DetectError::TooShort => {
+                write!(f, "Text is shorter than the minimal text length")
+            }
+            DetectError::TooFewTrigrams => {
+                write!(f, "Text has fewer trigrams than the minimal trigram count")
+            }
+            DetectError::NoScript => write!(f, "Text has no characters of a known script"),
+            DetectError::AllCandidatesFiltered => {
+                write!(f, "All candidate languages are excluded by the filter list")
+            }
+            DetectError::LowConfidence => {
+                write!(f, "Confidence is lower than the minimal confidence")
+            }
         }
     }
 }
 
-impl StdError for Error {}
+#[cfg(feature = "std")]
+impl std::error::Error for DetectError {}
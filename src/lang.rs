@@ -2,8 +2,10 @@
 //    This file is generated automatically.
 //    Edit misc/lang.rs.erb template instead of editing lang.rs file directly.
 
-use std::fmt;
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
 
 use crate::error::Error;
 
@@ -12,7 +14,7 @@ use enum_map::Enum;
 
 /// Represents a language following [ISO 639-3](https://en.wikipedia.org/wiki/ISO_639-3) standard.
 #[cfg_attr(feature = "enum-map", derive(Enum))]
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Clone, Copy)]
 pub enum Lang {
     /// Esperanto (Esperanto)
     Epo = 0,
@@ -214,9 +216,18 @@ pub enum Lang {
 
     /// Català (Catalan)
     Cat = 66,
+
+    /// Հայերեն (Armenian)
+    Hye = 67,
+
+    /// Nynorsk (Nynorsk)
+    Nno = 68,
+
+    /// ພາສາລາວ (Lao)
+    Lao = 69,
 }
 
-const VALUES: [Lang; 67] = [
+const VALUES: [Lang; 70] = [
     Lang::Epo,
     Lang::Eng,
     Lang::Rus,
@@ -284,6 +295,9 @@ const VALUES: [Lang; 67] = [
     Lang::Lat,
     Lang::Slk,
     Lang::Cat,
+    Lang::Hye,
+    Lang::Nno,
+    Lang::Lao,
 ];
 
 fn lang_from_code<S: Into<String>>(code: S) -> Option<Lang> {
@@ -355,6 +369,9 @@ fn lang_from_code<S: Into<String>>(code: S) -> Option<Lang> {
         "lat" => Some(Lang::Lat),
         "slk" => Some(Lang::Slk),
         "cat" => Some(Lang::Cat),
+        "hye" => Some(Lang::Hye),
+        "nno" => Some(Lang::Nno),
+        "lao" => Some(Lang::Lao),
         _ => None,
     }
 }
@@ -428,6 +445,9 @@ fn lang_to_code(lang: Lang) -> &'static str {
         Lang::Lat => "lat",
         Lang::Slk => "slk",
         Lang::Cat => "cat",
+        Lang::Hye => "hye",
+        Lang::Nno => "nno",
+        Lang::Lao => "lao",
     }
 }
 
@@ -500,6 +520,9 @@ fn lang_to_name(lang: Lang) -> &'static str {
         Lang::Lat => "Lingua Latina",
         Lang::Slk => "Slovenčina",
         Lang::Cat => "Català",
+        Lang::Hye => "Հայերեն",
+        Lang::Nno => "Nynorsk",
+        Lang::Lao => "ພາສາລາວ",
     }
 }
 
@@ -572,6 +595,9 @@ fn lang_to_eng_name(lang: Lang) -> &'static str {
         Lang::Lat => "Latin",
         Lang::Slk => "Slovak",
         Lang::Cat => "Catalan",
+        Lang::Hye => "Armenian",
+        Lang::Nno => "Nynorsk",
+        Lang::Lao => "Lao",
     }
 }
 
@@ -629,14 +655,15 @@ impl Lang {
     ///     println!("{}", lang);
     /// }
     /// ```
-    pub fn all() -> &'static [Lang] {
+    pub const fn all() -> &'static [Lang] {
         &VALUES
     }
 }
 
+// Display ISO 639-3 code, so it can be parsed back with `FromStr`.
 impl fmt::Display for Lang {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.name())
+        write!(f, "{}", self.code())
     }
 }
 
@@ -648,6 +675,22 @@ impl FromStr for Lang {
     }
 }
 
+// The same as `FromStr`, for generic code that expects conversion traits.
+impl TryFrom<&str> for Lang {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+// ISO 639-3 code, the same as `Display` gives.
+impl From<Lang> for &'static str {
+    fn from(lang: Lang) -> Self {
+        lang.code()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -679,14 +722,41 @@ mod tests {
         assert_eq!(Lang::Rus.eng_name(), "Russian");
     }
 
+    #[test]
+    fn test_names_are_present() {
+        for &lang in Lang::all() {
+            assert!(!lang.name().is_empty());
+            assert!(!lang.eng_name().is_empty());
+        }
+    }
+
     #[test]
     fn test_all() {
-        assert_eq!(Lang::all().len(), 67);
+        assert_eq!(Lang::all().len(), 70);
         let all = Lang::all();
         assert!(all.contains(&Lang::Ukr));
         assert!(all.contains(&Lang::Swe));
     }
 
+    #[test]
+    fn test_all_contains_every_variant() {
+        // Variants are numbered from 0 without gaps, so if every variant is at
+        // the position of its number, none of them is missing or duplicated.
+        for (i, &lang) in Lang::all().iter().enumerate() {
+            assert_eq!(lang as usize, i);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Lang::Eng.to_string(), "eng");
+        assert_eq!(Lang::Cmn.to_string(), "cmn");
+
+        for &lang in Lang::all() {
+            assert_eq!(lang.to_string().parse::<Lang>().unwrap(), lang);
+        }
+    }
+
     #[test]
     fn test_from_str() {
         for &lang in Lang::all() {
@@ -699,4 +769,31 @@ mod tests {
         let result = "xyz".parse::<Lang>();
         assert!(matches!(result, Err(Error::ParseLang(_))));
     }
+
+    #[test]
+    fn test_conversion_traits() {
+        for &lang in Lang::all() {
+            let code: &'static str = lang.into();
+            assert_eq!(code, lang.to_string());
+            assert_eq!(Lang::try_from(code).unwrap(), lang);
+            assert_eq!(Lang::try_from(code).ok(), code.parse().ok());
+        }
+
+        let result = Lang::try_from("xyz");
+        assert!(matches!(result, Err(Error::ParseLang(_))));
+    }
+
+    #[test]
+    fn test_ord() {
+        // Languages are ordered by their numbers, i.e. the order of Lang::all()
+        assert!(Lang::Epo < Lang::Eng);
+        assert!(Lang::all().windows(2).all(|pair| pair[0] < pair[1]));
+
+        let mut counts = alloc::collections::BTreeMap::new();
+        for &lang in &[Lang::Rus, Lang::Eng, Lang::Epo, Lang::Eng] {
+            *counts.entry(lang).or_insert(0) += 1;
+        }
+        let counts: Vec<(Lang, i32)> = counts.into_iter().collect();
+        assert_eq!(counts, [(Lang::Epo, 1), (Lang::Eng, 2), (Lang::Rus, 1)]);
+    }
 }
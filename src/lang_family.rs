@@ -0,0 +1,116 @@
+use crate::Lang;
+
+/// Language family (or a branch of a large family, e.g. Slavic languages of Indo-European),
+/// see `Lang::family`.
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+pub enum LangFamily {
+    /// Languages invented on purpose, e.g. Esperanto.
+    Constructed,
+    Slavic,
+    Germanic,
+    /// Romance languages and Latin.
+    Romance,
+    Baltic,
+    Hellenic,
+    Armenian,
+    IndoAryan,
+    Iranian,
+    Kartvelian,
+    Semitic,
+    Uralic,
+    Turkic,
+    Dravidian,
+    SinoTibetan,
+    Japonic,
+    Koreanic,
+    Austronesian,
+    Austroasiatic,
+    TaiKadai,
+    NigerCongo,
+}
+
+impl Lang {
+    /// Get the family the language belongs to.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{Lang, LangFamily};
+    /// assert_eq!(Lang::Rus.family(), LangFamily::Slavic);
+    /// assert_eq!(Lang::Spa.family(), LangFamily::Romance);
+    /// ```
+    pub fn family(&self) -> LangFamily {
+        use LangFamily as F;
+        match self {
+            Lang::Epo => F::Constructed,
+            Lang::Rus
+            | Lang::Ukr
+            | Lang::Bel
+            | Lang::Pol
+            | Lang::Ces
+            | Lang::Slk
+            | Lang::Bul
+            | Lang::Mkd
+            | Lang::Srp
+            | Lang::Hrv
+            | Lang::Slv => F::Slavic,
+            Lang::Eng
+            | Lang::Deu
+            | Lang::Nld
+            | Lang::Afr
+            | Lang::Yid
+            | Lang::Dan
+            | Lang::Nob
+            | Lang::Nno
+            | Lang::Swe => F::Germanic,
+            Lang::Spa | Lang::Por | Lang::Ita | Lang::Fra | Lang::Ron | Lang::Cat | Lang::Lat => {
+                F::Romance
+            }
+            Lang::Lit | Lang::Lav => F::Baltic,
+            Lang::Ell => F::Hellenic,
+            Lang::Hye => F::Armenian,
+            Lang::Hin
+            | Lang::Urd
+            | Lang::Ben
+            | Lang::Mar
+            | Lang::Guj
+            | Lang::Pan
+            | Lang::Ori
+            | Lang::Nep
+            | Lang::Sin => F::IndoAryan,
+            Lang::Pes => F::Iranian,
+            Lang::Kat => F::Kartvelian,
+            Lang::Ara | Lang::Heb | Lang::Amh => F::Semitic,
+            Lang::Fin | Lang::Est | Lang::Hun => F::Uralic,
+            Lang::Tur | Lang::Aze | Lang::Uzb | Lang::Tuk => F::Turkic,
+            Lang::Tam | Lang::Tel | Lang::Kan | Lang::Mal => F::Dravidian,
+            Lang::Cmn | Lang::Mya => F::SinoTibetan,
+            Lang::Jpn => F::Japonic,
+            Lang::Kor => F::Koreanic,
+            Lang::Jav | Lang::Ind => F::Austronesian,
+            Lang::Vie | Lang::Khm => F::Austroasiatic,
+            Lang::Tha | Lang::Lao => F::TaiKadai,
+            Lang::Aka | Lang::Zul | Lang::Sna => F::NigerCongo,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_family() {
+        assert_eq!(Lang::Rus.family(), LangFamily::Slavic);
+        assert_eq!(Lang::Pol.family(), LangFamily::Slavic);
+        assert_eq!(Lang::Spa.family(), LangFamily::Romance);
+        assert_eq!(Lang::Ron.family(), LangFamily::Romance);
+        assert_eq!(Lang::Deu.family(), LangFamily::Germanic);
+        assert_eq!(Lang::Yid.family(), LangFamily::Germanic);
+        assert_eq!(Lang::Heb.family(), LangFamily::Semitic);
+        assert_eq!(Lang::Hun.family(), LangFamily::Uralic);
+        assert_eq!(Lang::Hin.family(), LangFamily::IndoAryan);
+        assert_eq!(Lang::Tam.family(), LangFamily::Dravidian);
+        assert_eq!(Lang::Cmn.family(), LangFamily::SinoTibetan);
+        assert_eq!(Lang::Epo.family(), LangFamily::Constructed);
+    }
+}
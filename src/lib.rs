@@ -37,20 +37,47 @@
 //! | Feature    | Description                                                                           |
 //! |------------|---------------------------------------------------------------------------------------|
 //! | `enum-map` | `Lang` and `Script` implement `Enum` trait from [enum-map](https://docs.rs/enum-map/) |
+//! | `serde`    | `Lang`, `Script` and `Info` implement `Serialize` and `Deserialize` from [serde](https://docs.rs/serde/) |
+//! | `std`      | Enabled by default. Without it the crate is `no_std` and needs only `alloc`; errors don't implement `std::error::Error` |
+//! | `wasm`     | `detect_json` that returns `Info` as a JSON string, handy for WebAssembly bindings (enables `serde`) |
+//! | `all-scripts` | Enabled by default. Enables all `script-*` features below |
+//! | `script-latin`, `script-cyrillic`, `script-arabic`, `script-devanagari`, `script-hebrew` | Trigram models of languages of the script. Without it the languages of the script are not detected (detection returns `None`), which makes the binary smaller: a Latin and Cyrillic only build is about 30 KB smaller. Scripts used by one language (e.g. Greek) and Mandarin have no models, so they are always detected |
 //!
 //!
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
+
+extern crate alloc;
+
 mod alphabets;
 mod combined;
 mod core;
 mod error;
 mod lang;
+mod lang_family;
 mod scripts;
+#[cfg(feature = "serde")]
+mod serialization;
 mod trigrams;
 mod utils;
+#[cfg(feature = "wasm")]
+mod wasm;
 
 #[cfg(feature = "dev")]
 pub mod dev;
 
-pub use crate::core::{detect, detect_lang, Detector, Info};
+pub use crate::core::{
+    calculate_scores, detect, detect_all, detect_batch, detect_bytes, detect_chars, detect_into,
+    detect_lang, detect_lines, detect_mixed, detect_or, detect_or_default, detect_reliable,
+    detect_reliable_with_options, detect_result, detect_top_n, detect_top_n_with_options,
+    detect_until, detect_with_options, detect_with_scores, detect_with_stats, is_multilingual,
+    score_for, Confidence, DetectStats, Detector, FilterList, Info, Options, OptionsBuilder,
+    Outcome, ScriptDetail, StreamingDetector,
+};
+pub use crate::error::{DetectError, Error};
 pub use crate::lang::Lang;
-pub use crate::scripts::{detect_script, Script};
+pub use crate::lang_family::LangFamily;
+pub use crate::scripts::{detect_script, script_segments, Direction, Script};
+pub use crate::trigrams::{extract_trigrams, Trigram};
+#[cfg(feature = "wasm")]
+pub use crate::wasm::detect_json;
@@ -1,10 +1,16 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
 use super::script::Script;
 use crate::utils::is_stop_char;
 
+type ScriptCheck = (Script, fn(char) -> bool);
 type ScriptCounter = (Script, fn(char) -> bool, usize);
 
 /// Detect only a script by a given text.
-/// Works much faster than a complete detection with `detect`.
+/// Works much faster than a complete detection with `detect`: it runs in a single pass
+/// over the characters of the text and does not allocate `Info`.
 ///
 /// # Example
 /// ```
@@ -23,8 +29,8 @@ pub struct RawScriptInfo {
 }
 
 impl RawScriptInfo {
-    fn new(mut counters: Vec<(Script, usize)>) -> Self {
-        counters.sort_by(|a, b| b.1.cmp(&a.1));
+    pub(crate) fn new(mut counters: Vec<(Script, usize)>) -> Self {
+        counters.sort_by_key(|a| core::cmp::Reverse(a.1));
         Self { counters }
     }
 
@@ -51,32 +57,8 @@ impl RawScriptInfo {
 }
 
 pub fn raw_detect_script(text: &str) -> RawScriptInfo {
-    let mut script_counters: [ScriptCounter; 24] = [
-        (Script::Latin, is_latin, 0),
-        (Script::Cyrillic, is_cyrillic, 0),
-        (Script::Arabic, is_arabic, 0),
-        (Script::Mandarin, is_mandarin, 0),
-        (Script::Devanagari, is_devanagari, 0),
-        (Script::Hebrew, is_hebrew, 0),
-        (Script::Ethiopic, is_ethiopic, 0),
-        (Script::Georgian, is_georgian, 0),
-        (Script::Bengali, is_bengali, 0),
-        (Script::Hangul, is_hangul, 0),
-        (Script::Hiragana, is_hiragana, 0),
-        (Script::Katakana, is_katakana, 0),
-        (Script::Greek, is_greek, 0),
-        (Script::Kannada, is_kannada, 0),
-        (Script::Tamil, is_tamil, 0),
-        (Script::Thai, is_thai, 0),
-        (Script::Gujarati, is_gujarati, 0),
-        (Script::Gurmukhi, is_gurmukhi, 0),
-        (Script::Telugu, is_telugu, 0),
-        (Script::Malayalam, is_malayalam, 0),
-        (Script::Oriya, is_oriya, 0),
-        (Script::Myanmar, is_myanmar, 0),
-        (Script::Sinhala, is_sinhala, 0),
-        (Script::Khmer, is_khmer, 0),
-    ];
+    let mut script_counters: [ScriptCounter; SCRIPT_CHECKS.len()] =
+        SCRIPT_CHECKS.map(|(script, check_fn)| (script, check_fn, 0));
 
     for ch in text.chars() {
         if is_stop_char(ch) {
@@ -117,6 +99,89 @@ pub fn raw_detect_script(text: &str) -> RawScriptInfo {
     RawScriptInfo::new(counters)
 }
 
+// Script of a single character, None for stop chars and chars that do not belong to
+// any known script.
+pub(crate) fn char_script(ch: char) -> Option<Script> {
+    if is_stop_char(ch) {
+        return None;
+    }
+    SCRIPT_CHECKS
+        .iter()
+        .find(|(_, check_fn)| check_fn(ch))
+        .map(|&(script, _)| script)
+}
+
+/// Split a text into maximal runs of characters of the same script and return them with
+/// their byte ranges, without detecting languages (see `detect_mixed` for that).
+/// Characters that do not belong to any script (spaces, punctuation, digits, etc)
+/// do not split a run and are not included at the edges of runs.
+/// Non-alphabetic characters of a script (e.g. `«` that belongs to the Latin-1 block)
+/// continue a run of the same script, but never start a new one.
+///
+/// # Example
+/// ```
+/// use whatlang::{script_segments, Script};
+/// let text = "Hello, 世界!";
+/// let segments = script_segments(text);
+/// assert_eq!(segments, vec![(Script::Latin, 0..5), (Script::Mandarin, 7..13)]);
+/// assert_eq!(&text[segments[1].1.clone()], "世界");
+/// ```
+pub fn script_segments(text: &str) -> Vec<(Script, Range<usize>)> {
+    let mut segments: Vec<(Script, Range<usize>)> = vec![];
+    let mut current: Option<(Script, Range<usize>)> = None;
+
+    for (pos, ch) in text.char_indices() {
+        let script = match char_script(ch) {
+            Some(script) => script,
+            None => continue,
+        };
+        let end = pos + ch.len_utf8();
+
+        match current {
+            Some((current_script, ref mut range)) if current_script == script => {
+                range.end = end;
+            }
+            _ if !ch.is_alphabetic() => continue,
+            _ => {
+                segments.extend(current.take());
+                current = Some((script, pos..end));
+            }
+        }
+    }
+    segments.extend(current);
+
+    segments
+}
+
+const SCRIPT_CHECKS: [ScriptCheck; 26] = [
+    (Script::Latin, is_latin),
+    (Script::Cyrillic, is_cyrillic),
+    (Script::Arabic, is_arabic),
+    (Script::Mandarin, is_mandarin),
+    (Script::Devanagari, is_devanagari),
+    (Script::Hebrew, is_hebrew),
+    (Script::Ethiopic, is_ethiopic),
+    (Script::Georgian, is_georgian),
+    (Script::Bengali, is_bengali),
+    (Script::Hangul, is_hangul),
+    (Script::Hiragana, is_hiragana),
+    (Script::Katakana, is_katakana),
+    (Script::Greek, is_greek),
+    (Script::Kannada, is_kannada),
+    (Script::Tamil, is_tamil),
+    (Script::Thai, is_thai),
+    (Script::Lao, is_lao),
+    (Script::Gujarati, is_gujarati),
+    (Script::Gurmukhi, is_gurmukhi),
+    (Script::Telugu, is_telugu),
+    (Script::Malayalam, is_malayalam),
+    (Script::Oriya, is_oriya),
+    (Script::Myanmar, is_myanmar),
+    (Script::Sinhala, is_sinhala),
+    (Script::Khmer, is_khmer),
+    (Script::Armenian, is_armenian),
+];
+
 fn is_cyrillic(ch: char) -> bool {
     matches!(ch,
         '\u{0400}'..='\u{0484}'
@@ -204,26 +269,32 @@ fn is_hiragana(ch: char) -> bool {
     matches!(ch, '\u{3040}'..='\u{309F}')
 }
 
+// Including halfwidth Katakana (e.g. `ｶ`)
 fn is_katakana(ch: char) -> bool {
-    matches!(ch, '\u{30A0}'..='\u{30FF}')
+    matches!(ch, '\u{30A0}'..='\u{30FF}' | '\u{FF65}'..='\u{FF9F}')
 }
 
 // Hangul is Korean Alphabet. Unicode ranges are taken from: https://en.wikipedia.org/wiki/Hangul
+// Only the Hangul parts of the shared blocks are taken: parenthesized and circled Hangul of
+// Enclosed CJK Letters and Months and halfwidth Hangul of Halfwidth and Fullwidth Forms
+// (the rest of them are Katakana, Han and fullwidth Latin characters).
 fn is_hangul(ch: char) -> bool {
     matches!(ch,
         '\u{AC00}'..='\u{D7AF}'
         | '\u{1100}'..='\u{11FF}'
         | '\u{3130}'..='\u{318F}'
-        | '\u{3200}'..='\u{32FF}'
+        | '\u{3200}'..='\u{321E}'
+        | '\u{3260}'..='\u{327E}'
         | '\u{A960}'..='\u{A97F}'
         | '\u{D7B0}'..='\u{D7FF}'
-        | '\u{FF00}'..='\u{FFEF}'
+        | '\u{FFA0}'..='\u{FFDC}'
     )
 }
 
 // Taken from: https://en.wikipedia.org/wiki/Greek_and_Coptic
+// and https://en.wikipedia.org/wiki/Greek_Extended (polytonic orthography)
 fn is_greek(ch: char) -> bool {
-    matches!(ch, '\u{0370}'..='\u{03FF}')
+    matches!(ch, '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}')
 }
 
 // Based on: https://en.wikipedia.org/wiki/Kannada_(Unicode_block)
@@ -241,6 +312,12 @@ fn is_thai(ch: char) -> bool {
     matches!(ch, '\u{0E00}'..='\u{0E7F}')
 }
 
+// Based on: https://en.wikipedia.org/wiki/Lao_(Unicode_block)
+// The block follows Thai one, and many letters look alike, but they don't overlap.
+fn is_lao(ch: char) -> bool {
+    matches!(ch, '\u{0E80}'..='\u{0EFF}')
+}
+
 // Based on: https://en.wikipedia.org/wiki/Gujarati_(Unicode_block)
 fn is_gujarati(ch: char) -> bool {
     matches!(ch, '\u{0A80}'..='\u{0AFF}')
@@ -281,6 +358,11 @@ fn is_khmer(ch: char) -> bool {
     matches!(ch, '\u{1780}'..='\u{17FF}' | '\u{19E0}'..='\u{19FF}')
 }
 
+// Based on: https://en.wikipedia.org/wiki/Armenian_(Unicode_block)
+fn is_armenian(ch: char) -> bool {
+    matches!(ch, '\u{0530}'..='\u{058F}')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -322,6 +404,11 @@ mod tests {
             detect_script("የኢትዮጵያ ፌዴራላዊ ዴሞክራሲያዊሪፐብሊክ"),
             Some(Script::Ethiopic)
         );
+        assert_eq!(detect_script("Բարեւ Ձեզ"), Some(Script::Armenian));
+        assert_eq!(detect_script("ສະບາຍດີ"), Some(Script::Lao));
+        assert_eq!(detect_script("สวัสดี"), Some(Script::Thai));
+        // Ends with danda, which is in the Devanagari block but is used by Bengali too
+        assert_eq!(detect_script("আমি বাংলায় গান গাই।"), Some(Script::Bengali));
 
         // Mixed scripts
         assert_eq!(
@@ -332,6 +419,61 @@ mod tests {
             detect_script("Russian word любовь means love."),
             Some(Script::Latin)
         );
+        // The script of most characters wins, not the one of the first character
+        assert_eq!(
+            detect_script("\"Hello\", сказал он и улыбнулся своему старому другу"),
+            Some(Script::Cyrillic)
+        );
+    }
+
+    #[test]
+    fn test_char_script() {
+        assert_eq!(char_script('z'), Some(Script::Latin));
+        assert_eq!(char_script('Ж'), Some(Script::Cyrillic));
+        assert_eq!(char_script('水'), Some(Script::Mandarin));
+        assert_eq!(char_script('ひ'), Some(Script::Hiragana));
+        assert_eq!(char_script(','), None);
+        assert_eq!(char_script('7'), None);
+        assert_eq!(char_script('—'), None);
+    }
+
+    #[test]
+    fn test_script_segments() {
+        assert_eq!(script_segments(""), vec![]);
+        assert_eq!(script_segments(" 123, !"), vec![]);
+
+        let text = "Привет, мир! Hello, world!";
+        let segments = script_segments(text);
+        assert_eq!(
+            segments,
+            vec![(Script::Cyrillic, 0..20), (Script::Latin, 22..34)]
+        );
+        assert_eq!(&text[segments[0].1.clone()], "Привет, мир");
+        assert_eq!(&text[segments[1].1.clone()], "Hello, world");
+
+        let text = "Rust 是一种 programming language, 速度快";
+        let segments = script_segments(text);
+        let scripts: Vec<Script> = segments.iter().map(|(script, _)| *script).collect();
+        assert_eq!(
+            scripts,
+            vec![
+                Script::Latin,
+                Script::Mandarin,
+                Script::Latin,
+                Script::Mandarin
+            ]
+        );
+        assert_eq!(&text[segments[0].1.clone()], "Rust");
+        assert_eq!(&text[segments[1].1.clone()], "是一种");
+        assert_eq!(&text[segments[2].1.clone()], "programming language");
+        assert_eq!(&text[segments[3].1.clone()], "速度快");
+
+        // Non-alphabetic Latin-1 chars do not create segments
+        let text = "Он сказал: «Привет»";
+        assert_eq!(
+            script_segments(text),
+            vec![(Script::Cyrillic, 0..text.len() - 2)]
+        );
     }
 
     #[test]
@@ -375,11 +517,15 @@ mod tests {
     fn test_is_bengali() {
         assert_eq!(is_bengali('ই'), true);
         assert_eq!(is_bengali('z'), false);
+        // Devanagari is a distinct block
+        assert_eq!(is_bengali('इ'), false);
+        assert_eq!(is_devanagari('ই'), false);
     }
 
     #[test]
     fn test_is_katakana() {
         assert_eq!(is_katakana('カ'), true);
+        assert_eq!(is_katakana('ｶ'), true); // halfwidth
         assert_eq!(is_katakana('f'), false);
     }
 
@@ -392,12 +538,21 @@ mod tests {
     #[test]
     fn test_is_hangul() {
         assert_eq!(is_hangul('ᄁ'), true);
+        assert_eq!(is_hangul('한'), true);
+        assert_eq!(is_hangul('ㄱ'), true);
+        assert_eq!(is_hangul('ﾡ'), true); // halfwidth
+        assert_eq!(is_hangul('㉠'), true);
         assert_eq!(is_hangul('t'), false);
+        assert_eq!(is_hangul('ｶ'), false);
+        assert_eq!(is_hangul('Ａ'), false);
+        assert_eq!(is_hangul('㋐'), false);
     }
 
     #[test]
     fn test_is_greek() {
         assert_eq!(is_greek('φ'), true);
+        assert_eq!(is_greek('ἀ'), true);
+        assert_eq!(is_greek('ῷ'), true);
         assert_eq!(is_greek('ф'), false);
     }
 
@@ -411,6 +566,11 @@ mod tests {
     fn test_is_tamil() {
         assert_eq!(is_tamil('ஐ'), true);
         assert_eq!(is_tamil('Ж'), false);
+        // Neighbouring Brahmic blocks
+        assert_eq!(is_tamil('ଓ'), false);
+        assert_eq!(is_tamil('ఁ'), false);
+        assert_eq!(is_oriya('ஐ'), false);
+        assert_eq!(is_telugu('ஐ'), false);
     }
 
     #[test]
@@ -418,6 +578,16 @@ mod tests {
         assert_eq!(is_thai('ก'), true);
         assert_eq!(is_thai('๛'), true);
         assert_eq!(is_thai('Ж'), false);
+        assert_eq!(is_thai('ກ'), false);
+    }
+
+    #[test]
+    fn test_is_lao() {
+        assert_eq!(is_lao('ກ'), true);
+        assert_eq!(is_lao('ໟ'), true);
+        // The last char of Thai block
+        assert_eq!(is_lao('\u{0E7F}'), false);
+        assert_eq!(is_lao('ก'), false);
     }
 
     #[test]
@@ -441,10 +611,55 @@ mod tests {
         assert_eq!(is_telugu('Ж'), false);
     }
 
+    #[test]
+    fn test_is_malayalam() {
+        assert_eq!(is_malayalam('അ'), true);
+        assert_eq!(is_malayalam('ൿ'), true);
+        assert_eq!(is_malayalam('ಡ'), false);
+        assert_eq!(is_kannada('അ'), false);
+    }
+
     #[test]
     fn test_is_oriya() {
         assert_eq!(is_oriya('ଐ'), true);
         assert_eq!(is_oriya('୷'), true);
         assert_eq!(is_oriya('౿'), false);
     }
+
+    #[test]
+    fn test_is_myanmar() {
+        assert_eq!(is_myanmar('က'), true);
+        assert_eq!(is_myanmar('႟'), true);
+        // Georgian block follows Myanmar one
+        assert_eq!(is_myanmar('Ⴀ'), false);
+        assert_eq!(is_myanmar('ก'), false);
+    }
+
+    #[test]
+    fn test_is_khmer() {
+        assert_eq!(is_khmer('ក'), true);
+        assert_eq!(is_khmer('៹'), true);
+        // Khmer symbols
+        assert_eq!(is_khmer('᧠'), true);
+        assert_eq!(is_khmer('ກ'), false);
+        assert_eq!(is_khmer('ก'), false);
+    }
+
+    #[test]
+    fn test_is_sinhala() {
+        assert_eq!(is_sinhala('අ'), true);
+        assert_eq!(is_sinhala('෴'), true);
+        // The last char of Malayalam block and the first one of Thai block
+        assert_eq!(is_sinhala('\u{0D7F}'), false);
+        assert_eq!(is_sinhala('\u{0E00}'), false);
+        assert_eq!(is_malayalam('අ'), false);
+        assert_eq!(is_thai('෴'), false);
+    }
+
+    #[test]
+    fn test_is_armenian() {
+        assert_eq!(is_armenian('Ա'), true);
+        assert_eq!(is_armenian('և'), true);
+        assert_eq!(is_armenian('Ж'), false);
+    }
 }
@@ -0,0 +1,44 @@
+use super::Script;
+
+/// Direction in which a script is written, see `Script::direction`.
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl Script {
+    /// Get the direction the script is written in.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{Direction, Script};
+    /// assert_eq!(Script::Hebrew.direction(), Direction::RightToLeft);
+    /// assert_eq!(Script::Latin.direction(), Direction::LeftToRight);
+    /// ```
+    pub fn direction(&self) -> Direction {
+        match self {
+            Script::Arabic | Script::Hebrew => Direction::RightToLeft,
+            _ => Direction::LeftToRight,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "script-hebrew"))]
+mod tests {
+    use super::*;
+    use crate::detect;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Script::Arabic.direction(), Direction::RightToLeft);
+        assert_eq!(Script::Hebrew.direction(), Direction::RightToLeft);
+        assert_eq!(Script::Cyrillic.direction(), Direction::LeftToRight);
+        assert_eq!(Script::Mandarin.direction(), Direction::LeftToRight);
+
+        let info = detect("אני אוהב לקרוא ספרים").unwrap();
+        assert_eq!(info.direction(), Direction::RightToLeft);
+        let info = detect("There is no reason not to learn Esperanto.").unwrap();
+        assert_eq!(info.direction(), Direction::LeftToRight);
+    }
+}
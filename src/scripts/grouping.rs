@@ -1,3 +1,4 @@
+use super::lang_mapping::unique_script_lang;
 use super::Script;
 use crate::Lang;
 
@@ -11,7 +12,7 @@ pub enum MultiLangScript {
 }
 
 impl MultiLangScript {
-    pub fn to_script(&self) -> Script {
+    pub fn to_script(self) -> Script {
         match self {
             Self::Latin => Script::Latin,
             Self::Cyrillic => Script::Cyrillic,
@@ -20,6 +21,18 @@ impl MultiLangScript {
             Self::Hebrew => Script::Hebrew,
         }
     }
+
+    // Trigram profiles of a script can be compiled out by disabling its `script-*` feature,
+    // then languages of the script are not detected.
+    pub fn is_enabled(self) -> bool {
+        match self {
+            Self::Latin => cfg!(feature = "script-latin"),
+            Self::Cyrillic => cfg!(feature = "script-cyrillic"),
+            Self::Arabic => cfg!(feature = "script-arabic"),
+            Self::Devanagari => cfg!(feature = "script-devanagari"),
+            Self::Hebrew => cfg!(feature = "script-hebrew"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -38,6 +51,10 @@ impl Script {
         use MultiLangScript as MLS;
         use ScriptLangGroup::{Mandarin, Multi, One};
 
+        if let Some(&lang) = unique_script_lang(*self) {
+            return One(lang);
+        }
+
         match *self {
             Script::Latin => Multi(MLS::Latin),
             Script::Cyrillic => Multi(MLS::Cyrillic),
@@ -45,23 +62,11 @@ impl Script {
             Script::Devanagari => Multi(MLS::Devanagari),
             Script::Hebrew => Multi(MLS::Hebrew),
             Script::Mandarin => Mandarin,
-            Script::Bengali => One(Lang::Ben),
-            Script::Hangul => One(Lang::Kor),
-            Script::Georgian => One(Lang::Kat),
-            Script::Greek => One(Lang::Ell),
-            Script::Kannada => One(Lang::Kan),
-            Script::Tamil => One(Lang::Tam),
-            Script::Thai => One(Lang::Tha),
-            Script::Gujarati => One(Lang::Guj),
-            Script::Gurmukhi => One(Lang::Pan),
-            Script::Telugu => One(Lang::Tel),
-            Script::Malayalam => One(Lang::Mal),
-            Script::Oriya => One(Lang::Ori),
-            Script::Myanmar => One(Lang::Mya),
-            Script::Sinhala => One(Lang::Sin),
-            Script::Khmer => One(Lang::Khm),
-            Script::Ethiopic => One(Lang::Amh),
-            Script::Katakana | Script::Hiragana => One(Lang::Jpn),
+            // All other scripts are in UNIQUE_SCRIPT_LANGS (checked at compile time)
+            _ => unreachable!(
+                "to_lang_group() failed because {} is not a unique script",
+                self
+            ),
         }
     }
 }
@@ -81,6 +86,15 @@ mod tests {
             Script::Georgian.to_lang_group(),
             ScriptLangGroup::One(Lang::Kat)
         );
+        assert_eq!(
+            Script::Armenian.to_lang_group(),
+            ScriptLangGroup::One(Lang::Hye)
+        );
+        assert_eq!(
+            Script::Katakana.to_lang_group(),
+            ScriptLangGroup::One(Lang::Jpn)
+        );
+        assert_eq!(Script::Mandarin.to_lang_group(), ScriptLangGroup::Mandarin);
     }
 
     #[test]
@@ -88,4 +102,16 @@ mod tests {
         assert_eq!(MultiLangScript::Latin.to_script(), Script::Latin);
         assert_eq!(MultiLangScript::Hebrew.to_script(), Script::Hebrew);
     }
+
+    #[test]
+    fn test_multi_lang_script_is_enabled() {
+        assert_eq!(
+            MultiLangScript::Latin.is_enabled(),
+            cfg!(feature = "script-latin")
+        );
+        assert_eq!(
+            MultiLangScript::Hebrew.is_enabled(),
+            cfg!(feature = "script-hebrew")
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use super::Script;
 use crate::Lang;
 
-const LATIN_LANGS: [Lang; 35] = [
+const LATIN_LANGS: [Lang; 37] = [
     Lang::Spa,
     Lang::Eng,
     Lang::Por,
@@ -30,6 +30,7 @@ const LATIN_LANGS: [Lang; 35] = [
     Lang::Tuk,
     Lang::Dan,
     Lang::Nob,
+    Lang::Nno,
     Lang::Cat,
     Lang::Lit,
     Lang::Slv,
@@ -37,19 +38,87 @@ const LATIN_LANGS: [Lang; 35] = [
     Lang::Lav,
     Lang::Est,
     Lang::Lat,
+    Lang::Srp,
 ];
-const CYRILLIC_LANGS: [Lang; 6] = [
+const CYRILLIC_LANGS: [Lang; 7] = [
     Lang::Rus,
     Lang::Ukr,
     Lang::Srp,
     Lang::Bel,
     Lang::Bul,
     Lang::Mkd,
+    Lang::Aze,
 ];
 const ARABIC_LANGS: [Lang; 3] = [Lang::Ara, Lang::Urd, Lang::Pes];
 const DEVANAGARI_LANGS: [Lang; 3] = [Lang::Hin, Lang::Mar, Lang::Nep];
 const HEBREW_LANGS: [Lang; 2] = [Lang::Heb, Lang::Yid];
 
+// Scripts used by only one language: the language is determined by the script alone.
+// Supporting a new script of this kind takes only an entry here.
+const UNIQUE_SCRIPT_LANGS: [(Script, Lang); 20] = [
+    (Script::Armenian, Lang::Hye),
+    (Script::Bengali, Lang::Ben),
+    (Script::Ethiopic, Lang::Amh),
+    (Script::Georgian, Lang::Kat),
+    (Script::Greek, Lang::Ell),
+    (Script::Gujarati, Lang::Guj),
+    (Script::Gurmukhi, Lang::Pan),
+    (Script::Hangul, Lang::Kor),
+    (Script::Hiragana, Lang::Jpn),
+    (Script::Kannada, Lang::Kan),
+    (Script::Katakana, Lang::Jpn),
+    (Script::Khmer, Lang::Khm),
+    (Script::Lao, Lang::Lao),
+    (Script::Malayalam, Lang::Mal),
+    (Script::Myanmar, Lang::Mya),
+    (Script::Oriya, Lang::Ori),
+    (Script::Sinhala, Lang::Sin),
+    (Script::Tamil, Lang::Tam),
+    (Script::Telugu, Lang::Tel),
+    (Script::Thai, Lang::Tha),
+];
+
+// Every script except the ones written with multiple languages (see `to_lang_group`)
+// is in UNIQUE_SCRIPT_LANGS exactly once, so looking it up never fails.
+const _: () = {
+    let scripts = Script::all();
+    let mut i = 0;
+    while i < scripts.len() {
+        let script = scripts[i];
+        let mut entries = 0;
+        let mut j = 0;
+        while j < UNIQUE_SCRIPT_LANGS.len() {
+            if UNIQUE_SCRIPT_LANGS[j].0 as usize == script as usize {
+                entries += 1;
+            }
+            j += 1;
+        }
+        let is_multi_lang = matches!(
+            script,
+            Script::Latin
+                | Script::Cyrillic
+                | Script::Arabic
+                | Script::Devanagari
+                | Script::Hebrew
+                | Script::Mandarin
+        );
+        assert!(
+            entries == if is_multi_lang { 0 } else { 1 },
+            "every script must be either multi-language or in UNIQUE_SCRIPT_LANGS once"
+        );
+        i += 1;
+    }
+};
+
+/// Get the only language written with the script, if the script is unique to a language.
+pub(crate) fn unique_script_lang(script: Script) -> Option<&'static Lang> {
+    let unique_script_langs: &'static [(Script, Lang)] = &UNIQUE_SCRIPT_LANGS;
+    unique_script_langs
+        .iter()
+        .find(|(s, _)| *s == script)
+        .map(|(_, lang)| lang)
+}
+
 pub fn script_langs(script: Script) -> &'static [Lang] {
     match script {
         Script::Latin => &LATIN_LANGS,
@@ -58,32 +127,75 @@ pub fn script_langs(script: Script) -> &'static [Lang] {
         Script::Hebrew => &HEBREW_LANGS,
         Script::Arabic => &ARABIC_LANGS,
         Script::Mandarin => &[Lang::Cmn],
-        Script::Bengali => &[Lang::Ben],
-        Script::Hangul => &[Lang::Kor],
-        Script::Georgian => &[Lang::Kat],
-        Script::Greek => &[Lang::Ell],
-        Script::Kannada => &[Lang::Kan],
-        Script::Tamil => &[Lang::Tam],
-        Script::Thai => &[Lang::Tha],
-        Script::Gujarati => &[Lang::Guj],
-        Script::Gurmukhi => &[Lang::Pan],
-        Script::Telugu => &[Lang::Tel],
-        Script::Malayalam => &[Lang::Mal],
-        Script::Oriya => &[Lang::Ori],
-        Script::Myanmar => &[Lang::Mya],
-        Script::Sinhala => &[Lang::Sin],
-        Script::Khmer => &[Lang::Khm],
-        Script::Ethiopic => &[Lang::Amh],
-        Script::Katakana | Script::Hiragana => &[Lang::Jpn],
+        // expect - is safe because all other scripts are in UNIQUE_SCRIPT_LANGS,
+        // it's checked at compile time
+        _ => unique_script_lang(script)
+            .map(core::slice::from_ref)
+            .expect("script_langs() failed because script is missing in UNIQUE_SCRIPT_LANGS"),
+    }
+}
+
+impl Lang {
+    /// Get the script the language is written with.
+    /// If a language is written with multiple scripts, the first one of them in
+    /// alphabetic order is returned (e.g. `Hiragana` for Japanese), except
+    /// Azerbaijani that is written with Latin nowadays (and with Cyrillic before).
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{Lang, Script};
+    /// assert_eq!(Lang::Ukr.script(), Script::Cyrillic);
+    /// ```
+    pub fn script(&self) -> Script {
+        if *self == Lang::Aze {
+            return Script::Latin;
+        }
+        // expect - is safe because every language belongs at least to one script
+        Script::all()
+            .iter()
+            .copied()
+            .find(|&script| script_langs(script).contains(self))
+            .expect("Lang::script() failed because language does not belong to any script")
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scripts::grouping::ScriptLangGroup;
 
     #[test]
     fn test_script_langs() {
-        assert_eq!(script_langs(Script::Hebrew), &[Lang::Heb, Lang::Yid])
+        assert_eq!(script_langs(Script::Hebrew), &[Lang::Heb, Lang::Yid]);
+        assert_eq!(script_langs(Script::Armenian), &[Lang::Hye]);
+        assert_eq!(script_langs(Script::Thai), &[Lang::Tha]);
+    }
+
+    #[test]
+    fn test_unique_script_lang() {
+        assert_eq!(unique_script_lang(Script::Georgian), Some(&Lang::Kat));
+        assert_eq!(unique_script_lang(Script::Ethiopic), Some(&Lang::Amh));
+        assert_eq!(unique_script_lang(Script::Latin), None);
+        assert_eq!(unique_script_lang(Script::Mandarin), None);
+
+        // Every script is either unique to a language or has a lang group of its own
+        for &script in Script::all() {
+            let is_unique = unique_script_lang(script).is_some();
+            let is_one = matches!(script.to_lang_group(), ScriptLangGroup::One(_));
+            assert_eq!(is_unique, is_one, "script: {}", script);
+        }
+    }
+
+    #[test]
+    fn test_lang_script() {
+        assert_eq!(Lang::Eng.script(), Script::Latin);
+        assert_eq!(Lang::Heb.script(), Script::Hebrew);
+        assert_eq!(Lang::Cmn.script(), Script::Mandarin);
+        assert_eq!(Lang::Jpn.script(), Script::Hiragana);
+        assert_eq!(Lang::Aze.script(), Script::Latin);
+
+        for &lang in Lang::all() {
+            assert!(lang.script().langs().contains(&lang));
+        }
     }
 }
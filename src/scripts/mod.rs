@@ -1,8 +1,11 @@
 mod detect;
+mod direction;
 pub(crate) mod grouping;
 mod lang_mapping;
 mod script;
 
-pub use self::detect::detect_script;
+pub(crate) use self::detect::char_script;
+pub use self::detect::{detect_script, script_segments};
 pub use self::detect::{raw_detect_script, RawScriptInfo};
+pub use self::direction::Direction;
 pub use self::script::Script;
@@ -1,5 +1,6 @@
-use std::fmt;
-use std::str::FromStr;
+use alloc::string::ToString;
+use core::fmt;
+use core::str::FromStr;
 
 use super::lang_mapping;
 use crate::error::Error;
@@ -10,10 +11,11 @@ use enum_map::Enum;
 
 /// Represents a writing system (Latin, Cyrillic, Arabic, etc).
 #[cfg_attr(feature = "enum-map", derive(Enum))]
-#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Clone, Copy)]
 pub enum Script {
     // Keep this in alphabetic order (for C bindings)
     Arabic,
+    Armenian,
     Bengali,
     Cyrillic,
     Devanagari,
@@ -28,6 +30,7 @@ pub enum Script {
     Kannada,
     Katakana,
     Khmer,
+    Lao,
     Latin,
     Malayalam,
     Mandarin,
@@ -40,8 +43,9 @@ pub enum Script {
 }
 
 // Array of all existing Script values.
-const VALUES: [Script; 24] = [
+const VALUES: [Script; 26] = [
     Script::Arabic,
+    Script::Armenian,
     Script::Bengali,
     Script::Cyrillic,
     Script::Devanagari,
@@ -56,6 +60,7 @@ const VALUES: [Script; 24] = [
     Script::Kannada,
     Script::Katakana,
     Script::Khmer,
+    Script::Lao,
     Script::Latin,
     Script::Malayalam,
     Script::Mandarin,
@@ -77,7 +82,7 @@ impl Script {
     ///     println!("{}", script);
     /// }
     /// ```
-    pub fn all() -> &'static [Script] {
+    pub const fn all() -> &'static [Script] {
         &VALUES
     }
 
@@ -107,12 +112,35 @@ impl Script {
             Script::Myanmar => "Myanmar",
             Script::Sinhala => "Sinhala",
             Script::Khmer => "Khmer",
+            Script::Armenian => "Armenian",
+            Script::Lao => "Lao",
         }
     }
 
-    pub fn langs(&self) -> &[Lang] {
+    /// Get languages that use the script and are considered by detection.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::{Lang, Script};
+    /// assert!(Script::Cyrillic.langs().contains(&Lang::Ukr));
+    /// ```
+    pub fn langs(&self) -> &'static [Lang] {
         lang_mapping::script_langs(*self)
     }
+
+    /// Get the script of a single character, the same way `detect_script` classifies
+    /// characters of a text. It's `None` for spaces, punctuation, digits and characters
+    /// of unknown scripts.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::Script;
+    /// assert_eq!(Script::from_char('ї'), Some(Script::Cyrillic));
+    /// assert_eq!(Script::from_char('!'), None);
+    /// ```
+    pub fn from_char(ch: char) -> Option<Script> {
+        super::char_script(ch)
+    }
 }
 
 impl fmt::Display for Script {
@@ -150,6 +178,8 @@ impl FromStr for Script {
             "myanmar" => Ok(Script::Myanmar),
             "sinhala" => Ok(Script::Sinhala),
             "khmer" => Ok(Script::Khmer),
+            "armenian" => Ok(Script::Armenian),
+            "lao" => Ok(Script::Lao),
             _ => Err(Error::ParseScript(s.to_string())),
         }
     }
@@ -161,13 +191,27 @@ mod tests {
 
     #[test]
     fn test_all() {
-        assert_eq!(Script::all().len(), 24);
+        assert_eq!(Script::all().len(), 26);
         let all = Script::all();
         assert!(all.contains(&Script::Cyrillic));
         assert!(all.contains(&Script::Arabic));
         assert!(all.contains(&Script::Latin));
     }
 
+    #[test]
+    fn test_all_contains_every_variant() {
+        for (i, &script) in Script::all().iter().enumerate() {
+            assert_eq!(script as usize, i);
+        }
+    }
+
+    #[test]
+    fn test_all_have_langs() {
+        for script in Script::all() {
+            assert!(!script.langs().is_empty(), "{} has no languages", script);
+        }
+    }
+
     #[test]
     fn test_from_str() {
         for &script in Script::all() {
@@ -181,19 +225,59 @@ mod tests {
         assert!(matches!(result, Err(Error::ParseScript(_))));
     }
 
+    #[test]
+    fn test_ord() {
+        // Scripts are declared in alphabetic order
+        assert!(Script::all().windows(2).all(|pair| pair[0] < pair[1]));
+
+        let mut counts = alloc::collections::BTreeMap::new();
+        for &script in &[Script::Latin, Script::Cyrillic, Script::Latin] {
+            *counts.entry(script).or_insert(0) += 1;
+        }
+        let counts: Vec<(Script, i32)> = counts.into_iter().collect();
+        assert_eq!(counts, [(Script::Cyrillic, 1), (Script::Latin, 2)]);
+    }
+
     #[test]
     fn test_langs() {
         // Vec of all langs obtained with script.langs()
         let script_langs: Vec<Lang> = Script::all()
             .iter()
-            .map(|script| script.langs())
-            .flatten()
+            .flat_map(|script| script.langs())
             .copied()
             .collect();
 
         // Ensure all langs belong at least to one script
         for lang in Lang::all() {
-            assert!(script_langs.contains(&lang));
+            assert!(script_langs.contains(lang));
         }
     }
+
+    #[test]
+    fn test_langs_belong_to_exactly_one_script() {
+        for &lang in Lang::all() {
+            let scripts: Vec<Script> = Script::all()
+                .iter()
+                .copied()
+                .filter(|script| script.langs().contains(&lang))
+                .collect();
+            match lang {
+                // Japanese is written with both Hiragana and Katakana
+                Lang::Jpn => assert_eq!(scripts, vec![Script::Hiragana, Script::Katakana]),
+                // Azerbaijani is written with Latin, and with Cyrillic before 1991
+                Lang::Aze => assert_eq!(scripts, vec![Script::Cyrillic, Script::Latin]),
+                Lang::Srp => assert_eq!(scripts, vec![Script::Cyrillic, Script::Latin]),
+                _ => assert_eq!(scripts.len(), 1, "{:?} belongs to {:?}", lang, scripts),
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_char() {
+        assert_eq!(Script::from_char('a'), Some(Script::Latin));
+        assert_eq!(Script::from_char('ж'), Some(Script::Cyrillic));
+        assert_eq!(Script::from_char('中'), Some(Script::Mandarin));
+        assert_eq!(Script::from_char(','), None);
+        assert_eq!(Script::from_char('7'), None);
+    }
 }
@@ -0,0 +1,104 @@
+// Lang and Script are serialized as strings (ISO 639-3 code and lowercase name respectively),
+// so the representation does not depend on the order of enum variants.
+use core::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::{Lang, Script};
+
+impl Serialize for Lang {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Lang {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("ISO 639-3 language code"))
+    }
+}
+
+impl Serialize for Script {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.name().to_lowercase())
+    }
+}
+
+impl<'de> Deserialize<'de> for Script {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("script name"))
+    }
+}
+
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    marker: core::marker::PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, T> Visitor<'de> for FromStrVisitor<T>
+where
+    T: core::str::FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{detect, Info, Lang, Script};
+
+    #[test]
+    fn test_lang() {
+        assert_eq!(serde_json::to_string(&Lang::Ukr).unwrap(), r#""ukr""#);
+        for &lang in Lang::all() {
+            let json = serde_json::to_string(&lang).unwrap();
+            assert_eq!(serde_json::from_str::<Lang>(&json).unwrap(), lang);
+        }
+        assert!(serde_json::from_str::<Lang>(r#""xyz""#).is_err());
+    }
+
+    #[test]
+    fn test_script() {
+        assert_eq!(
+            serde_json::to_string(&Script::Cyrillic).unwrap(),
+            r#""cyrillic""#
+        );
+        for &script in Script::all() {
+            let json = serde_json::to_string(&script).unwrap();
+            assert_eq!(serde_json::from_str::<Script>(&json).unwrap(), script);
+        }
+        assert!(serde_json::from_str::<Script>(r#""foobar""#).is_err());
+    }
+
+    #[test]
+    fn test_info() {
+        let info = Info::new(Script::Latin, Lang::Epo, 0.5);
+        let json = serde_json::to_string(&info).unwrap();
+        assert_eq!(json, r#"{"script":"latin","lang":"epo","confidence":0.5}"#);
+        assert_eq!(serde_json::from_str::<Info>(&json).unwrap(), info);
+
+        let info = detect("Та нічого, все нормально. А в тебе як?").unwrap();
+        let json = serde_json::to_string(&info).unwrap();
+        // Trigram count and alternative are not serialized, nor compared
+        assert_eq!(serde_json::from_str::<Info>(&json).unwrap(), info);
+    }
+}
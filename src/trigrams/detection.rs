@@ -1,13 +1,26 @@
+use alloc::vec::Vec;
+
 use hashbrown::HashMap;
 
 use super::utils::{get_trigrams_with_positions, TrigramsWithPositions};
-use super::{LangProfile, LangProfileList};
-use super::{Trigram, MAX_TOTAL_DISTANCE, MAX_TRIGRAM_DISTANCE};
-use super::{ARABIC_LANGS, CYRILLIC_LANGS, DEVANAGARI_LANGS, HEBREW_LANGS, LATIN_LANGS};
-use crate::core::{calculate_confidence, FilterList, Info, InternalQuery, Text};
-use crate::scripts::grouping::MultiLangScript;
-use crate::Lang;
+use super::LangProfileList;
+#[cfg(feature = "script-arabic")]
+use super::ARABIC_LANGS;
+#[cfg(feature = "script-cyrillic")]
+use super::CYRILLIC_LANGS;
+#[cfg(feature = "script-devanagari")]
+use super::DEVANAGARI_LANGS;
+#[cfg(feature = "script-hebrew")]
+use super::HEBREW_LANGS;
+#[cfg(feature = "script-latin")]
+use super::LATIN_LANGS;
+use super::{Trigram, TrigramModels, MAX_TOTAL_DISTANCE, MAX_TRIGRAM_DISTANCE};
+use crate::core::{confident_rate, InternalQuery, LangSet};
+use crate::scripts::grouping::{MultiLangScript, ScriptLangGroup};
+use crate::{Lang, Script};
 
+// Fields are only read by the tooling exposed with the `dev` feature.
+#[cfg_attr(not(feature = "dev"), allow(dead_code))]
 #[derive(Debug)]
 pub struct RawOutcome {
     pub trigrams_count: usize,
@@ -15,69 +28,223 @@ pub struct RawOutcome {
     pub scores: Vec<(Lang, f64)>,
 }
 
-pub fn detect(iquery: &mut InternalQuery) -> Option<Info> {
-    let raw_outcome = raw_detect(iquery);
-    let RawOutcome {
-        trigrams_count,
-        scores,
-        ..
-    } = raw_outcome;
+pub fn raw_detect(iquery: &mut InternalQuery) -> RawOutcome {
+    let settings = iquery.trigram_settings();
+    let TrigramsWithPositions {
+        trigram_positions, ..
+    } = get_trigrams_with_positions(iquery.text.lowercase(), settings);
+    raw_detect_by_positions(
+        &trigram_positions,
+        iquery.multi_lang_script,
+        iquery.allowed_langs,
+        iquery.trigram_models,
+        iquery.normalize_by_model_size,
+    )
+}
 
-    let mut raw_scores_iter = scores.into_iter();
+// `trigram_positions` are trigrams of the text ranked by frequency,
+// see `get_trigrams_with_positions`.
+pub(crate) fn raw_detect_by_positions(
+    trigram_positions: &HashMap<Trigram, u32>,
+    multi_lang_script: MultiLangScript,
+    allowed_langs: LangSet,
+    trigram_models: Option<&TrigramModels>,
+    normalize_by_model_size: bool,
+) -> RawOutcome {
+    let lang_profile_list = script_to_lang_profile_list(multi_lang_script);
+    // User-supplied models take precedence over the built-in ones
+    let lang_profiles = lang_profile_list.iter().map(|&(lang, profile)| {
+        let profile = trigram_models
+            .and_then(|models| models.get(lang))
+            .unwrap_or(profile);
+        (lang, profile)
+    });
+    let mut outcome = calculate_scores_in_profiles(
+        trigram_positions,
+        allowed_langs,
+        lang_profiles,
+        normalize_by_model_size,
+    );
+    if multi_lang_script == MultiLangScript::Latin {
+        merge_close_langs(trigram_positions, &mut outcome);
+    }
+    outcome
+}
 
-    let opt_lang_score1 = raw_scores_iter.next();
-    let opt_lang_score2 = raw_scores_iter.next();
+// Trigrams of ekavian (e.g. "gde", "vek", "deca") and of "šta", "hiljada", which are used
+// in Serbian, but not in Croatian. Ijekavian is standard in both of them, so it's no sign
+// of either.
+const EKAVIAN_TRIGRAMS: [Trigram; 5] = [
+    Trigram('g', 'd', 'e'),
+    Trigram('v', 'e', 'k'),
+    Trigram('d', 'e', 'c'),
+    Trigram('š', 't', 'a'),
+    Trigram('h', 'i', 'l'),
+];
 
-    // TODO: Logic is duplicated in alphabets. Consider refactoring
-    opt_lang_score1.map(|(lang1, score1)| {
-        let script = iquery.multi_lang_script.to_script();
-        let confidence = if let Some((_, score2)) = opt_lang_score2 {
-            calculate_confidence(score1, score2, trigrams_count)
-        } else {
-            1.0
+// Nynorsk "ikkje", "mykje" and "frå".
+// "kv" of "kva", "kvar" and "rle" of "korleis" are not among them, they are common
+// in Bokmål too (e.g. "kvalitet", "kvinne", "perle").
+const NYNORSK_TRIGRAMS: [Trigram; 2] = [Trigram('k', 'k', 'j'), Trigram('f', 'r', 'å')];
+
+// Bokmål "ikke", "hva", "hvor", "hvordan", "jeg" and "mye".
+// "fra" is not one of them, it's a part of Nynorsk "fram".
+const BOKMAL_TRIGRAMS: [Trigram; 4] = [
+    Trigram('k', 'k', 'e'),
+    Trigram(' ', 'h', 'v'),
+    Trigram('j', 'e', 'g'),
+    Trigram('m', 'y', 'e'),
+];
+
+type LangTrigrams = (Lang, &'static [Trigram]);
+
+// Languages that are so close that they get almost the same scores, with trigrams
+// of words or spellings used only in one of them (Bokmål and Nynorsk differ mostly
+// by function words), see `merge_close_langs`.
+const CLOSE_LANGS: [(LangTrigrams, LangTrigrams); 1] = [(
+    (Lang::Nob, &BOKMAL_TRIGRAMS),
+    (Lang::Nno, &NYNORSK_TRIGRAMS),
+)];
+
+// A close pair gets almost the same scores, which would make confidence of either of them
+// low, so only one of them is kept: the one which has more of its specific trigrams
+// in the text, with the better score of the two. If the numbers are equal or the scores
+// are confidently different (see `calculate_confidence`), both are kept as they are.
+//
+// Latin trigrams of Serbian are transliterated from Cyrillic ones, so the model is nearly
+// the same as the Croatian one and its score tells nothing about the text. So only one
+// of them is always kept: Serbian if the text has ekavian trigrams, Croatian otherwise.
+fn merge_close_langs(trigram_positions: &HashMap<Trigram, u32>, outcome: &mut RawOutcome) {
+    let count = |trigrams: &[Trigram]| {
+        trigrams
+            .iter()
+            .filter(|t| trigram_positions.contains_key(t))
+            .count()
+    };
+    let score = |outcome: &RawOutcome, lang: Lang| {
+        outcome
+            .scores
+            .iter()
+            .find(|&&(l, _)| l == lang)
+            .map(|&(_, score)| score)
+    };
+
+    for &((lang1, trigrams1), (lang2, trigrams2)) in CLOSE_LANGS.iter() {
+        let (score1, score2) = match (score(outcome, lang1), score(outcome, lang2)) {
+            (Some(score1), Some(score2)) => (score1, score2),
+            _ => continue,
         };
-        Info::new(script, lang1, confidence)
-    })
+        let (higher, lower) = (score1.max(score2), score1.min(score2));
+        if lower == 0.0 || (higher - lower) / lower > confident_rate(outcome.trigrams_count) {
+            continue;
+        }
+        let (count1, count2) = (count(trigrams1), count(trigrams2));
+        if count1 > count2 {
+            merge_langs(outcome, lang1, lang2);
+        } else if count2 > count1 {
+            merge_langs(outcome, lang2, lang1);
+        }
+    }
+
+    if score(outcome, Lang::Srp).is_some() && score(outcome, Lang::Hrv).is_some() {
+        if count(&EKAVIAN_TRIGRAMS) > 0 {
+            merge_langs(outcome, Lang::Srp, Lang::Hrv);
+        } else {
+            merge_langs(outcome, Lang::Hrv, Lang::Srp);
+        }
+    }
 }
 
-pub fn raw_detect(iquery: &mut InternalQuery) -> RawOutcome {
-    let lang_profile_list = script_to_lang_profile_list(iquery.multi_lang_script);
-    calculate_scores_in_profiles(&mut iquery.text, &iquery.filter_list, lang_profile_list)
+// Drops the `dropped` language from the outcome and gives the better distance of the two
+// to the `kept` one.
+fn merge_langs(outcome: &mut RawOutcome, kept: Lang, dropped: Lang) {
+    let distance = |lang: Lang| {
+        outcome
+            .raw_distances
+            .iter()
+            .find(|&&(l, _)| l == lang)
+            .map_or(u32::MAX, |&(_, distance)| distance)
+    };
+    let min_distance = distance(kept).min(distance(dropped));
+    let mut raw_distances = core::mem::take(&mut outcome.raw_distances);
+    raw_distances.retain(|&(lang, _)| lang != dropped);
+    for (lang, distance) in raw_distances.iter_mut() {
+        if *lang == kept {
+            *distance = min_distance;
+        }
+    }
+    *outcome = distances_to_outcome(raw_distances, outcome.trigrams_count);
 }
 
 fn script_to_lang_profile_list(script: MultiLangScript) -> LangProfileList {
-    use MultiLangScript as MLS;
     match script {
-        MLS::Latin => LATIN_LANGS,
-        MLS::Cyrillic => CYRILLIC_LANGS,
-        MLS::Arabic => ARABIC_LANGS,
-        MLS::Devanagari => DEVANAGARI_LANGS,
-        MLS::Hebrew => HEBREW_LANGS,
+        #[cfg(feature = "script-latin")]
+        MultiLangScript::Latin => LATIN_LANGS,
+        #[cfg(feature = "script-cyrillic")]
+        MultiLangScript::Cyrillic => CYRILLIC_LANGS,
+        #[cfg(feature = "script-arabic")]
+        MultiLangScript::Arabic => ARABIC_LANGS,
+        #[cfg(feature = "script-devanagari")]
+        MultiLangScript::Devanagari => DEVANAGARI_LANGS,
+        #[cfg(feature = "script-hebrew")]
+        MultiLangScript::Hebrew => HEBREW_LANGS,
+        // Profiles of the scripts disabled at compile time, see `MultiLangScript::is_enabled`
+        #[allow(unreachable_patterns)]
+        _ => &[],
     }
 }
 
-fn calculate_scores_in_profiles(
-    text: &mut Text,
-    filter_list: &FilterList,
-    lang_profile_list: LangProfileList,
-) -> RawOutcome {
-    let mut lang_distances: Vec<(Lang, u32)> = vec![];
+impl Lang {
+    /// Get the number of trigrams in the built-in trigram model of the language.
+    /// It's 0 for languages detected only by their script (e.g. Georgian), which have no model.
+    /// Languages written with multiple scripts (e.g. Serbian) have a model for each of them,
+    /// then the total number is returned. Models compiled out with `script-*` features are not counted.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::Lang;
+    /// assert_eq!(Lang::Eng.model_size(), 300);
+    /// assert_eq!(Lang::Kat.model_size(), 0);
+    /// ```
+    pub fn model_size(&self) -> usize {
+        Script::all()
+            .iter()
+            .filter_map(|script| match script.to_lang_group() {
+                ScriptLangGroup::Multi(multi_lang_script) => Some(multi_lang_script),
+                _ => None,
+            })
+            .flat_map(|multi_lang_script| script_to_lang_profile_list(multi_lang_script).iter())
+            .filter(|&&(lang, _)| lang == *self)
+            .map(|&(_, profile)| profile.len())
+            .sum()
+    }
+}
 
-    let TrigramsWithPositions {
-        trigram_positions, ..
-    } = get_trigrams_with_positions(&text.lowercase());
+fn calculate_scores_in_profiles<'p>(
+    trigram_positions: &HashMap<Trigram, u32>,
+    allowed_langs: LangSet,
+    lang_profiles: impl Iterator<Item = (Lang, &'p [Trigram])>,
+    normalize_by_model_size: bool,
+) -> RawOutcome {
     let unique_trigrams_count = trigram_positions.len();
 
-    for &(lang, lang_trigrams) in lang_profile_list {
-        if !filter_list.is_allowed(lang) {
-            continue;
-        }
-        let dist = calculate_distance(lang_trigrams, &trigram_positions);
-        lang_distances.push(((lang), dist));
-    }
+    // Distances are calculated as the profiles are iterated, into a vector allocated once
+    // for all the languages of the script (a filtered iterator doesn't know its length).
+    let lang_profiles = lang_profiles.filter(|&(lang, _)| allowed_langs.contains(lang));
+    let mut lang_distances = Vec::with_capacity(lang_profiles.size_hint().1.unwrap_or(0));
+    lang_distances.extend(lang_profiles.map(|(lang, lang_trigrams)| {
+        let dist = calculate_distance(lang_trigrams, trigram_positions, normalize_by_model_size);
+        (lang, dist)
+    }));
+    distances_to_outcome(lang_distances, unique_trigrams_count)
+}
 
-    // Sort languages by distance
-    lang_distances.sort_by_key(|key| key.1);
+fn distances_to_outcome(
+    mut lang_distances: Vec<(Lang, u32)>,
+    unique_trigrams_count: usize,
+) -> RawOutcome {
+    // Sort languages by distance, languages with equal distances by `Lang`
+    lang_distances.sort_by_key(|&(lang, distance)| (distance, lang));
 
     let max_dist = unique_trigrams_count as u32 * MAX_TRIGRAM_DISTANCE;
 
@@ -93,43 +260,203 @@ fn calculate_scores_in_profiles(
     }
 }
 
-fn calculate_distance(lang_trigrams: LangProfile, text_trigrams: &HashMap<Trigram, u32>) -> u32 {
-    let mut total_dist = 0u32;
+fn calculate_distance(
+    lang_trigrams: &[Trigram],
+    text_trigrams: &HashMap<Trigram, u32>,
+    normalize_by_model_size: bool,
+) -> u32 {
+    // Profiles may have less trigrams than MAX_TRIGRAM_DISTANCE (it's possible with
+    // user-supplied models), the missing trigrams are considered to be at max distance.
+    let size = lang_trigrams.len() as u32;
+    let missing_count = MAX_TRIGRAM_DISTANCE.saturating_sub(size);
+    let mut total_dist = missing_count * MAX_TRIGRAM_DISTANCE;
 
     for (i, &trigram) in lang_trigrams.iter().enumerate() {
         let dist = match text_trigrams.get(&trigram) {
-            Some(&n) => (n as i32 - i as i32).abs() as u32,
+            Some(&n) => (n as i32 - i as i32).unsigned_abs(),
             None => MAX_TRIGRAM_DISTANCE,
         };
         total_dist += dist;
     }
 
     let count = text_trigrams.len() as u32;
+    let total_dist = normalize_distance(total_dist, count);
+    // The fewer trigrams a profile has, the fewer of them are found in the text, so the similarity
+    // of a small profile is scaled up to a full one, see `Options::set_normalize_by_model_size`.
+    if normalize_by_model_size && size > 0 && size < MAX_TRIGRAM_DISTANCE {
+        let max_dist = count.min(MAX_TRIGRAM_DISTANCE) * MAX_TRIGRAM_DISTANCE;
+        let similarity = max_dist.saturating_sub(total_dist);
+        return max_dist - (similarity * MAX_TRIGRAM_DISTANCE / size).min(max_dist);
+    }
+    total_dist
+}
 
+fn normalize_distance(total_dist: u32, count: u32) -> u32 {
+    let mut total_dist = total_dist;
     if MAX_TRIGRAM_DISTANCE > count {
         let delta = MAX_TRIGRAM_DISTANCE - count;
-        total_dist -= delta * MAX_TRIGRAM_DISTANCE;
+        // It never saturates for the whole distance, only for a lower bound of it
+        total_dist = total_dist.saturating_sub(delta * MAX_TRIGRAM_DISTANCE);
     }
 
     total_dist.clamp(0, MAX_TOTAL_DISTANCE)
 }
 
 fn distance_to_raw_score(distance: u32, max_distance: u32) -> f64 {
+    // A text without trigrams (e.g. only digits when they are ignored) is similar to nothing
+    if max_distance == 0 {
+        return 0.0;
+    }
     let similarity = max_distance - distance;
     similarity as f64 / max_distance as f64
 }
 
+// Scores of languages that are calculated by comparing a few trigrams of the profiles at a time,
+// so the bounds of the final scores are known before all the trigrams are compared,
+// see `detect_until`. Only the built-in profiles are used: their trigrams are distinct.
+pub(crate) struct PartialScores<'a> {
+    trigram_positions: &'a HashMap<Trigram, u32>,
+    multi_lang_script: MultiLangScript,
+    // Language, its profile, sum of distances of the compared trigrams
+    // and number of the compared trigrams found in the text.
+    langs: Vec<(Lang, &'static [Trigram], u32, usize)>,
+    compared_count: usize,
+}
+
+impl<'a> PartialScores<'a> {
+    pub(crate) fn new(
+        trigram_positions: &'a HashMap<Trigram, u32>,
+        multi_lang_script: MultiLangScript,
+        allowed_langs: LangSet,
+    ) -> Self {
+        let langs = script_to_lang_profile_list(multi_lang_script)
+            .iter()
+            .filter(|&&(lang, _)| allowed_langs.contains(lang))
+            .map(|&(lang, profile)| (lang, profile, 0, 0))
+            .collect();
+        Self {
+            trigram_positions,
+            multi_lang_script,
+            langs,
+            compared_count: 0,
+        }
+    }
+
+    // Compares next `n` trigrams of every profile, returns false if all of them are compared.
+    // Once all of them are compared, the final scores are known, see `into_outcome`.
+    pub(crate) fn compare_next(&mut self, n: usize) -> bool {
+        let start = self.compared_count;
+        let end = (start + n).min(MAX_TRIGRAM_DISTANCE as usize);
+        if start == end {
+            return false;
+        }
+        for (_, profile, dist, found_count) in self.langs.iter_mut() {
+            for (i, trigram) in profile.iter().enumerate().take(end).skip(start) {
+                *dist += match self.trigram_positions.get(trigram) {
+                    Some(&n) => {
+                        *found_count += 1;
+                        (n as i32 - i as i32).unsigned_abs()
+                    }
+                    None => MAX_TRIGRAM_DISTANCE,
+                };
+            }
+        }
+        self.compared_count = end;
+        true
+    }
+
+    // Lower and upper bounds of the final score of every language.
+    pub(crate) fn bounds(&self) -> impl Iterator<Item = (Lang, f64, f64)> + '_ {
+        let count = self.trigram_positions.len();
+        let max_dist = count as u32 * MAX_TRIGRAM_DISTANCE;
+        self.langs
+            .iter()
+            .map(move |&(lang, profile, dist, found_count)| {
+                let missing_count = MAX_TRIGRAM_DISTANCE as usize - profile.len();
+                let left_count = profile.len().saturating_sub(self.compared_count);
+                // The text has only `count` distinct trigrams, so the rest of the trigrams
+                // left are not found for sure.
+                let not_found_count = left_count.saturating_sub(count - found_count);
+
+                let max_total = dist + ((missing_count + left_count) as u32) * MAX_TRIGRAM_DISTANCE;
+                let min_total =
+                    dist + ((missing_count + not_found_count) as u32) * MAX_TRIGRAM_DISTANCE;
+                let min_score =
+                    distance_to_raw_score(normalize_distance(max_total, count as u32), max_dist);
+                let max_score =
+                    distance_to_raw_score(normalize_distance(min_total, count as u32), max_dist);
+                (lang, min_score, max_score)
+            })
+    }
+
+    // The same outcome `raw_detect` gives, if all the trigrams are compared.
+    pub(crate) fn into_outcome(self) -> RawOutcome {
+        debug_assert_eq!(self.compared_count, MAX_TRIGRAM_DISTANCE as usize);
+        let count = self.trigram_positions.len();
+        let lang_distances = self
+            .langs
+            .iter()
+            .map(|&(lang, profile, dist, _)| {
+                let missing_count = MAX_TRIGRAM_DISTANCE - profile.len() as u32;
+                let total_dist = dist + missing_count * MAX_TRIGRAM_DISTANCE;
+                (lang, normalize_distance(total_dist, count as u32))
+            })
+            .collect();
+        let mut outcome = distances_to_outcome(lang_distances, count);
+        if self.multi_lang_script == MultiLangScript::Latin {
+            merge_close_langs(self.trigram_positions, &mut outcome);
+        }
+        outcome
+    }
+}
+
+// Whether the language or a close one may be dropped after scoring, see `merge_close_langs`.
+pub(crate) fn is_close_lang(lang: Lang) -> bool {
+    let is_latin_serbian_or_croatian = lang == Lang::Srp || lang == Lang::Hrv;
+    is_latin_serbian_or_croatian
+        || CLOSE_LANGS
+            .iter()
+            .any(|&((lang1, _), (lang2, _))| lang == lang1 || lang == lang2)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{LowercaseText, Text, TextSettings};
+
+    #[test]
+    #[cfg(feature = "all-scripts")]
+    fn test_profiles_match_script_langs() {
+        let scripts = [
+            MultiLangScript::Latin,
+            MultiLangScript::Cyrillic,
+            MultiLangScript::Arabic,
+            MultiLangScript::Devanagari,
+            MultiLangScript::Hebrew,
+        ];
+        for &mls in scripts.iter() {
+            let mut profile_langs: Vec<Lang> = script_to_lang_profile_list(mls)
+                .iter()
+                .map(|&(lang, _)| lang)
+                .collect();
+            let mut script_langs = mls.to_script().langs().to_vec();
+            profile_langs.sort_by_key(|&lang| lang as usize);
+            script_langs.sort_by_key(|&lang| lang as usize);
+            assert_eq!(profile_langs, script_langs);
+        }
+    }
 
     #[test]
     fn test_when_german_is_given() {
         let text = "Die Ordnung muss für immer in diesem Codebase bleiben";
         let mut iq = InternalQuery {
-            text: Text::new(text),
-            filter_list: &FilterList::default(),
+            text: Text::new(text, TextSettings::default()),
+            allowed_langs: LangSet::all(),
             multi_lang_script: MultiLangScript::Latin,
+            trigram_models: None,
+            normalize_by_model_size: false,
+            boundary_padding: true,
+            ignore_digits: false,
         };
         let raw_outcome = raw_detect(&mut iq);
 
@@ -146,4 +473,50 @@ mod tests {
         assert!(last_score >= 0.0);
         assert!(last_score <= 1.0);
     }
+
+    #[test]
+    fn test_model_size() {
+        assert_eq!(Lang::Eng.model_size(), 300);
+        assert_eq!(Lang::Rus.model_size(), 300);
+        assert_eq!(Lang::Srp.model_size(), 600);
+        assert_eq!(Lang::Jpn.model_size(), 0);
+    }
+
+    #[test]
+    fn test_partial_scores() {
+        let text = "Та нічого, все нормально. А в тебе як?";
+        let text = LowercaseText::new(text);
+        let trigram_positions =
+            get_trigrams_with_positions(&text, Default::default()).trigram_positions;
+        let outcome = raw_detect_by_positions(
+            &trigram_positions,
+            MultiLangScript::Cyrillic,
+            LangSet::all(),
+            None,
+            false,
+        );
+
+        let mut partial_scores = PartialScores::new(
+            &trigram_positions,
+            MultiLangScript::Cyrillic,
+            LangSet::all(),
+        );
+        while partial_scores.compare_next(70) {
+            // Bounds always contain the final scores
+            for (lang, min, max) in partial_scores.bounds() {
+                let &(_, score) = outcome.scores.iter().find(|&&(l, _)| l == lang).unwrap();
+                assert!(
+                    min <= score && score <= max,
+                    "{}: {} {} {}",
+                    lang,
+                    min,
+                    score,
+                    max
+                );
+            }
+        }
+        let partial_outcome = partial_scores.into_outcome();
+        assert_eq!(partial_outcome.scores, outcome.scores);
+        assert_eq!(partial_outcome.trigrams_count, outcome.trigrams_count);
+    }
 }
@@ -0,0 +1,86 @@
+// Cheap check whether a pure ASCII text is obviously English, so the full trigram
+// scoring of all Latin languages can be skipped. Texts that don't clear the bar
+// are detected the usual way.
+
+// Texts shorter than this (in bytes) don't give enough trigrams for a reliable check.
+const MIN_TEXT_LENGTH: usize = 64;
+
+// Share of text trigrams that must belong to the signature.
+// Other Latin languages stay below 0.08 even when diacritics are stripped.
+const MIN_SIGNATURE_RATIO: f64 = 0.15;
+
+// The most frequent English trigrams, that are rare in other languages.
+const ENGLISH_SIGNATURE: [[u8; 3]; 36] = [
+    *b" th", *b"the", *b"he ", *b" an", *b"and", *b"nd ", *b" of", *b"of ", *b" to", *b"to ",
+    *b"ing", *b"ng ", *b" is", *b"is ", *b" wh", *b"hat", *b"tha", *b"at ", *b" it", *b"it ",
+    *b" wa", *b"was", *b" yo", *b"you", *b"ou ", *b" be", *b" fo", *b"for", *b"or ", *b"ion",
+    *b"tio", *b"ith", *b"wit", *b" wi", *b"her", *b"ere",
+];
+
+pub fn is_english_ascii(text: &str) -> bool {
+    if text.len() < MIN_TEXT_LENGTH || !text.is_ascii() {
+        return false;
+    }
+
+    let mut total: usize = 0;
+    let mut hits: usize = 0;
+
+    // Trigrams are built the same way as in `utils::count`: non-letters are spaces
+    // and trigrams consisting mostly of spaces are skipped.
+    let mut bytes = text.bytes().map(to_trigram_byte).chain(Some(b' '));
+    let mut b1 = b' ';
+    // unwrap is safe, because the text is not empty
+    let mut b2 = bytes.next().unwrap();
+    for b3 in bytes {
+        if !(b2 == b' ' && (b1 == b' ' || b3 == b' ')) {
+            total += 1;
+            if ENGLISH_SIGNATURE.contains(&[b1, b2, b3]) {
+                hits += 1;
+            }
+        }
+        b1 = b2;
+        b2 = b3;
+    }
+
+    total > 0 && hits as f64 / total as f64 >= MIN_SIGNATURE_RATIO
+}
+
+#[inline]
+fn to_trigram_byte(b: u8) -> u8 {
+    if b.is_ascii_alphabetic() {
+        b.to_ascii_lowercase()
+    } else {
+        b' '
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_english_ascii() {
+        assert!(is_english_ascii(
+            "I think that it is going to rain today, so you should take an umbrella with you."
+        ));
+        assert!(is_english_ascii(
+            "The quick brown fox jumps over the lazy dog and then runs into the forest."
+        ));
+
+        // Too short
+        assert!(!is_english_ascii("The fox and the dog"));
+
+        // Not ASCII
+        assert!(!is_english_ascii(
+            "I think that it is going to rain today, so you should take an umbrella — with you."
+        ));
+
+        // Not English
+        assert!(!is_english_ascii(
+            "De snelle bruine vos springt over de luie hond en rent dan het bos in."
+        ));
+        assert!(!is_english_ascii(
+            "Der schnelle braune Fuchs springt ueber den faulen Hund und rennt in den Wald."
+        ));
+    }
+}
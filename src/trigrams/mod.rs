@@ -1,14 +1,44 @@
 pub mod detection;
+mod fast_english;
+mod models;
 mod profiles;
 pub mod utils;
 
 pub use profiles::*;
 
-pub use detection::{detect, raw_detect, RawOutcome};
+pub use detection::raw_detect;
+pub use detection::RawOutcome;
+pub(crate) use detection::{is_close_lang, raw_detect_by_positions, PartialScores};
+pub use fast_english::is_english_ascii;
+pub use models::TrigramModels;
+pub use utils::extract_trigrams;
 
+use core::fmt;
+
+// Version of the built-in models, see `Detector::model_version`.
+// It must be changed whenever the profiles, the alphabets or the way they are scored change
+// (`test_model_version` fails when the profiles or the alphabets change).
+pub(crate) const MODEL_VERSION: &str = "0.13.0";
+
+// Versions of the models compiled in, see `Options::set_model_version`.
+pub(crate) const MODEL_VERSIONS: [&str; 1] = [MODEL_VERSION];
+
+/// Sequence of three characters, the unit trigram models are built of.
 #[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Clone, Copy)]
 pub struct Trigram(pub(crate) char, pub(crate) char, pub(crate) char);
 
+impl Trigram {
+    pub fn new(c1: char, c2: char, c3: char) -> Self {
+        Self(c1, c2, c3)
+    }
+}
+
+impl fmt::Display for Trigram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}{}", self.0, self.1, self.2)
+    }
+}
+
 // Maximum distance(difference) for a trigram in a language profile and text profile.
 pub const MAX_TRIGRAM_DISTANCE: u32 = 300;
 
@@ -17,3 +47,63 @@ pub const MAX_TOTAL_DISTANCE: u32 = MAX_TRIGRAM_DISTANCE * MAX_TRIGRAM_DISTANCE;
 
 // Double MAX_TRIGRAM_DISTANCE
 pub const TEXT_TRIGRAMS_SIZE: usize = 600;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "all-scripts")]
+    use crate::{alphabets::alphabets_of, Lang};
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Trigram::new(' ', 'h', 'e').to_string(), " he");
+        assert_eq!(Trigram::new('ж', 'ы', 'ц').to_string(), "жыц");
+    }
+
+    // FNV-1a hash of the built-in profiles and alphabets. It's implemented here
+    // rather than taken from std, so it stays the same across Rust releases.
+    #[cfg(feature = "all-scripts")]
+    fn models_fingerprint() -> u64 {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut add = |ch: char| {
+            for byte in u32::from(ch).to_le_bytes() {
+                hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        };
+
+        let profile_lists = [
+            LATIN_LANGS,
+            CYRILLIC_LANGS,
+            ARABIC_LANGS,
+            DEVANAGARI_LANGS,
+            HEBREW_LANGS,
+        ];
+        for &(lang, profile) in profile_lists.iter().flat_map(|list| list.iter()) {
+            lang.code().chars().for_each(&mut add);
+            for trigram in profile.iter() {
+                add(trigram.0);
+                add(trigram.1);
+                add(trigram.2);
+            }
+        }
+        for &lang in Lang::all() {
+            lang.code().chars().for_each(&mut add);
+            for alphabet in alphabets_of(lang) {
+                alphabet.chars().for_each(&mut add);
+            }
+        }
+        hash
+    }
+
+    #[test]
+    #[cfg(feature = "all-scripts")]
+    fn test_model_version() {
+        // If the fingerprint changed, the models changed too: bump `MODEL_VERSION`
+        // and update the fingerprint along with it.
+        assert_eq!(
+            (MODEL_VERSION, models_fingerprint()),
+            ("0.13.0", 0x9974_8011_1646_e7ad)
+        );
+    }
+}
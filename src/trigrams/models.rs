@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+
+use super::{Trigram, MAX_TRIGRAM_DISTANCE};
+use crate::Lang;
+
+/// User-supplied trigram models of languages, that are used instead of the built-in ones.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrigramModels {
+    profiles: HashMap<Lang, Vec<Trigram>>,
+}
+
+impl TrigramModels {
+    /// Build models from trigrams of every language with their ranks
+    /// (0 is the most frequent trigram).
+    /// Only the first 300 trigrams by rank are taken into account.
+    pub fn new(models: impl IntoIterator<Item = (Lang, Vec<(Trigram, u32)>)>) -> Self {
+        let profiles = models
+            .into_iter()
+            .map(|(lang, mut trigrams)| {
+                trigrams.sort_by_key(|&(_, rank)| rank);
+                let profile = trigrams
+                    .into_iter()
+                    .map(|(trigram, _)| trigram)
+                    .take(MAX_TRIGRAM_DISTANCE as usize)
+                    .collect();
+                (lang, profile)
+            })
+            .collect();
+        Self { profiles }
+    }
+
+    pub(crate) fn get(&self, lang: Lang) -> Option<&[Trigram]> {
+        self.profiles.get(&lang).map(|profile| profile.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_new() {
+        let mut map = HashMap::new();
+        map.insert(
+            Lang::Eng,
+            vec![
+                (Trigram::new('h', 'e', ' '), 1),
+                (Trigram::new(' ', 't', 'h'), 0),
+            ],
+        );
+        let models = TrigramModels::new(map);
+        assert_eq!(
+            models.get(Lang::Eng).unwrap(),
+            &[Trigram::new(' ', 't', 'h'), Trigram::new('h', 'e', ' ')]
+        );
+        assert_eq!(models.get(Lang::Rus), None);
+    }
+}
@@ -8,6 +8,7 @@ pub type LangProfile = &'static [Trigram];
 pub type LangProfileList = &'static [(Lang, LangProfile)];
 
 /// Languages for script Latin
+#[cfg(feature = "script-latin")]
 pub static LATIN_LANGS: LangProfileList = &[
     (
         Lang::Spa,
@@ -8550,178 +8551,483 @@ pub static LATIN_LANGS: LangProfileList = &[
         ],
     ),
     (
-        Lang::Cat,
+        Lang::Nno,
         &[
+            Trigram(' ', 'o', 'g'),
+            Trigram('o', 'g', ' '),
             Trigram(' ', 'd', 'e'),
-            Trigram(' ', 'i', ' '),
-            Trigram('e', 's', ' '),
-            Trigram('d', 'e', ' '),
-            Trigram('l', 'a', ' '),
-            Trigram(' ', 'l', 'a'),
-            Trigram(' ', 'a', ' '),
-            Trigram(' ', 'p', 'e'),
-            Trigram('p', 'e', 'r'),
-            Trigram('i', 'ó', ' '),
-            Trigram('e', 'n', 't'),
-            Trigram('t', 'a', 't'),
-            Trigram(' ', 's', 'e'),
-            Trigram('n', 't', ' '),
-            Trigram('r', 'e', 't'),
-            Trigram('t', 's', ' '),
-            Trigram('d', 'r', 'e'),
-            Trigram('a', 't', ' '),
-            Trigram(' ', 'e', 'l'),
-            Trigram('l', 's', ' '),
-            Trigram(' ', 'd', 'r'),
-            Trigram('m', 'e', 'n'),
-            Trigram('a', 'c', 'i'),
-            Trigram('a', ' ', 'p'),
-            Trigram('c', 'i', 'ó'),
-            Trigram('o', 'n', 'a'),
-            Trigram(' ', 'c', 'o'),
-            Trigram('a', ' ', 'l'),
-            Trigram('a', 'l', ' '),
-            Trigram('n', 'a', ' '),
-            Trigram('s', ' ', 'd'),
-            Trigram('q', 'u', 'e'),
+            Trigram(' ', 'h', 'a'),
+            Trigram('e', 'r', ' '),
             Trigram('e', 'n', ' '),
-            Trigram('e', 'l', ' '),
-            Trigram(' ', 't', 'o'),
-            Trigram('s', ' ', 'i'),
-            Trigram(' ', 'q', 'u'),
-            Trigram(' ', 'e', 'n'),
-            Trigram('e', ' ', 'l'),
-            Trigram('n', 's', ' '),
-            Trigram('t', 'o', 't'),
+            Trigram('a', 'r', ' '),
+            Trigram('t', 'i', 'l'),
+            Trigram(' ', 't', 'i'),
+            Trigram('l', 'l', 'e'),
+            Trigram('e', 't', 't'),
+            Trigram('i', 'l', ' '),
+            Trigram('r', 'e', 't'),
+            Trigram('o', 'm', ' '),
             Trigram('e', 't', ' '),
-            Trigram('t', ' ', 'a'),
-            Trigram('e', 'r', 's'),
-            Trigram(' ', 'p', 'r'),
-            Trigram('t', ' ', 'd'),
-            Trigram('o', 'n', 's'),
-            Trigram('e', 'r', ' '),
-            Trigram(' ', 'l', 'l'),
-            Trigram('i', 'o', 'n'),
-            Trigram('a', ' ', 's'),
-            Trigram('t', 'a', ' '),
-            Trigram('a', ' ', 't'),
-            Trigram('c', 'o', 'n'),
-            Trigram('e', 'l', 's'),
-            Trigram('s', ' ', 'e'),
-            Trigram(' ', 'l', '’'),
-            Trigram('r', 's', 'o'),
-            Trigram('r', 'e', 's'),
-            Trigram('a', 'l', 's'),
-            Trigram('s', 'o', 'n'),
-            Trigram(' ', 'u', 'n'),
-            Trigram('e', 's', 't'),
-            Trigram('c', 'i', 'o'),
             Trigram(' ', 'r', 'e'),
-            Trigram('p', 'r', 'o'),
-            Trigram('i', 't', 'a'),
-            Trigram('c', 'i', 'a'),
-            Trigram(' ', 'i', 'n'),
-            Trigram('l', 'e', 's'),
-            Trigram(' ', 'o', ' '),
-            Trigram('u', 'e', ' '),
-            Trigram('d', 'e', 'l'),
-            Trigram('l', 'l', 'i'),
-            Trigram('t', 'é', ' '),
-            Trigram(' ', 't', 'é'),
-            Trigram('i', 'a', ' '),
-            Trigram('a', 'm', 'e'),
-            Trigram('é', ' ', 'd'),
-            Trigram('s', 'e', 'v'),
-            Trigram('o', 't', 'a'),
-            Trigram('n', 'a', 'c'),
-            Trigram('i', ' ', 'l'),
+            Trigram('l', 'e', ' '),
+            Trigram('h', 'a', 'r'),
+            Trigram('e', 'n', 'n'),
+            Trigram(' ', 'm', 'e'),
             Trigram(' ', 'a', 'l'),
-            Trigram('s', ' ', 'p'),
-            Trigram('a', ' ', 'd'),
-            Trigram('a', 'r', ' '),
-            Trigram('a', ' ', 'i'),
-            Trigram('u', 'a', 'l'),
-            Trigram('n', 'a', 'l'),
-            Trigram('a', ' ', 'c'),
-            Trigram('a', 'n', 't'),
-            Trigram('n', 'c', 'i'),
-            Trigram(' ', 'l', 'e'),
-            Trigram('e', 'r', 't'),
-            Trigram('s', 't', 'a'),
-            Trigram('r', 't', 'a'),
-            Trigram('s', 'e', 'r'),
-            Trigram('t', ' ', 'i'),
-            Trigram('i', ' ', 'a'),
-            Trigram('l', ' ', 'd'),
-            Trigram(' ', 'n', 'o'),
-            Trigram('v', 'a', ' '),
-            Trigram('a', 't', 's'),
-            Trigram(' ', 'd', '’'),
-            Trigram('s', ' ', 'n'),
+            Trigram('a', 'l', 'l'),
+            Trigram(' ', 'f', 'r'),
+            Trigram('n', 'e', ' '),
+            Trigram('t', 't', ' '),
             Trigram('r', 'e', ' '),
-            Trigram('s', ' ', 'a'),
-            Trigram('e', ' ', 'c'),
-            Trigram('e', 'v', 'a'),
-            Trigram(' ', 'n', 'a'),
-            Trigram('r', 'à', ' '),
-            Trigram(' ', 'c', 'a'),
-            Trigram('u', 'e', 's'),
-            Trigram('c', 'o', 'm'),
-            Trigram('l', 'i', 'b'),
-            Trigram('é', 's', ' '),
+            Trigram(' ', 'å', ' '),
+            Trigram(' ', 'i', ' '),
+            Trigram('n', 'n', 'e'),
+            Trigram('a', 'n', 'd'),
+            Trigram('i', 'n', 'g'),
+            Trigram('s', 'k', 'a'),
+            Trigram(' ', 's', 'k'),
+            Trigram('m', 'e', 'n'),
+            Trigram(' ', 'f', 'o'),
+            Trigram('d', 'e', 't'),
+            Trigram('d', 'e', 'n'),
+            Trigram('v', 'e', 'r'),
+            Trigram('f', 'o', 'r'),
+            Trigram('e', 'l', 'l'),
+            Trigram('t', ' ', 't'),
+            Trigram('d', 'o', 'm'),
             Trigram(' ', 's', 'o'),
-            Trigram('i', 'b', 'e'),
-            Trigram(' ', 'e', 's'),
-            Trigram('e', 't', 's'),
-            Trigram('b', 'e', 'r'),
-            Trigram('d', 'a', ' '),
-            Trigram('r', ' ', 'a'),
-            Trigram('n', 'o', ' '),
-            Trigram('u', 'n', 'a'),
-            Trigram('l', '’', 'e'),
-            Trigram('s', ' ', 'l'),
+            Trigram('d', 'e', ' '),
+            Trigram('e', ' ', 's'),
+            Trigram(' ', 'v', 'e'),
+            Trigram(' ', 'e', 'i'),
+            Trigram('e', 'r', 'e'),
+            Trigram(' ', 'p', 'å'),
+            Trigram('a', 'l', ' '),
+            Trigram('a', 'n', ' '),
+            Trigram('e', ' ', 'o'),
+            Trigram('e', ' ', 'h'),
+            Trigram('f', 'r', 'i'),
+            Trigram('s', 'a', 'm'),
+            Trigram(' ', 's', 'a'),
+            Trigram('l', ' ', 'å'),
+            Trigram('p', 'å', ' '),
+            Trigram('l', 'e', 'g'),
+            Trigram(' ', 'e', 'l'),
+            Trigram('l', 'e', 'r'),
+            Trigram('s', 'o', 'm'),
+            Trigram('e', 'i', 'n'),
+            Trigram('e', 'i', ' '),
+            Trigram('n', 'd', 'e'),
+            Trigram('a', 'v', ' '),
+            Trigram(' ', 's', 't'),
+            Trigram('d', 'e', 'i'),
+            Trigram('o', 'r', ' '),
+            Trigram('t', 'e', 'n'),
+            Trigram('e', 's', 'k'),
+            Trigram('k', 'a', 'l'),
+            Trigram('g', 'j', 'e'),
+            Trigram('n', ' ', 's'),
+            Trigram('t', 't', 'e'),
+            Trigram('j', 'e', ' '),
+            Trigram('s', 'k', 'e'),
+            Trigram('r', 'i', 'd'),
+            Trigram('r', ' ', 'r'),
+            Trigram('i', ' ', 's'),
+            Trigram('t', 'e', ' '),
+            Trigram('n', 'e', 's'),
+            Trigram(' ', 'g', 'j'),
+            Trigram('e', 'g', ' '),
+            Trigram('i', 'd', 'o'),
+            Trigram('m', 'e', 'd'),
+            Trigram('e', ' ', 'f'),
+            Trigram('r', ' ', 's'),
+            Trigram('s', 't', ' '),
+            Trigram('k', 'e', ' '),
+            Trigram('j', 'o', 'n'),
+            Trigram(' ', 'i', 'n'),
+            Trigram('r', ' ', 'f'),
+            Trigram('s', 'j', 'o'),
+            Trigram('a', 's', 'j'),
+            Trigram('n', 'a', 's'),
             Trigram('t', 'e', 'r'),
-            Trigram('s', 'e', 'n'),
-            Trigram('r', 'a', 'n'),
-            Trigram('u', 'r', 'e'),
-            Trigram('d', 'e', 's'),
-            Trigram('m', 'a', 'n'),
-            Trigram('i', ' ', 'e'),
-            Trigram('l', ' ', 'p'),
+            Trigram('u', 'n', 'n'),
+            Trigram('e', 'd', ' '),
+            Trigram('k', 'j', 'e'),
+            Trigram('h', 'a', 'n'),
+            Trigram('o', 'n', 'a'),
+            Trigram(' ', 'e', 'r'),
+            Trigram('t', ' ', 'o'),
             Trigram('t', ' ', 'e'),
-            Trigram('n', ' ', 'd'),
-            Trigram('e', ' ', 'd'),
-            Trigram('e', ' ', 'e'),
-            Trigram('o', 'm', ' '),
-            Trigram(' ', 'd', 'i'),
-            Trigram('c', 'c', 'i'),
-            Trigram('i', 'g', 'u'),
-            Trigram('a', ' ', 'a'),
-            Trigram('s', ' ', 't'),
-            Trigram(' ', 'p', 'a'),
-            Trigram('i', ' ', 'd'),
-            Trigram('t', 'r', 'a'),
-            Trigram('s', ' ', 'o'),
-            Trigram('a', 'q', 'u'),
-            Trigram('t', 'r', 'e'),
-            Trigram('v', 'o', 'l'),
-            Trigram('e', 'c', 't'),
-            Trigram('a', ' ', 'u'),
-            Trigram('l', ' ', 'i'),
-            Trigram('g', 'u', 'a'),
-            Trigram('i', 'd', 'e'),
-            Trigram('s', ' ', 's'),
-            Trigram('a', 'd', 'a'),
-            Trigram('e', 'n', 'e'),
-            Trigram('i', 'a', 'l'),
-            Trigram('n', 't', 'a'),
-            Trigram('n', 't', 'r'),
-            Trigram('e', 'n', 's'),
-            Trigram('s', 'o', 'c'),
-            Trigram('c', 't', 'e'),
-            Trigram('r', 'a', ' '),
-            Trigram('o', 'c', 'i'),
-            Trigram('h', 'u', 'm'),
+            Trigram('g', ' ', 'f'),
+            Trigram('s', 'k', 'i'),
+            Trigram('e', ' ', 'm'),
+            Trigram('a', 's', 't'),
+            Trigram('a', 'n', 'e'),
+            Trigram('e', ' ', 't'),
+            Trigram(' ', 'a', 'v'),
+            Trigram(' ', 'g', 'r'),
+            Trigram('l', 'a', 'n'),
+            Trigram('s', 't', 'e'),
+            Trigram('t', 'a', 'n'),
+            Trigram('å', ' ', 'f'),
+            Trigram(' ', 'n', 'a'),
+            Trigram('d', 'e', 'r'),
+            Trigram(' ', 's', 'l'),
+            Trigram('t', ' ', 's'),
+            Trigram('s', 'e', 'g'),
+            Trigram('n', ' ', 'o'),
+            Trigram('r', ' ', 'k'),
+            Trigram('n', 'g', 'a'),
+            Trigram('g', 'e', ' '),
+            Trigram(' ', 'a', 'n'),
+            Trigram('g', ' ', 'o'),
+            Trigram('a', 't', ' '),
+            Trigram('n', 'a', ' '),
+            Trigram('e', 'r', 'n'),
+            Trigram('n', 't', 'e'),
+            Trigram('n', 'g', ' '),
+            Trigram(' ', 'u', 't'),
+            Trigram('l', 'i', 'k'),
+            Trigram('e', ' ', 'a'),
+            Trigram('b', 'e', 'i'),
+            Trigram('g', 'r', 'u'),
+            Trigram('e', ' ', 'i'),
+            Trigram('a', 'r', 'b'),
+            Trigram('k', 'i', 'l'),
+            Trigram('g', ' ', 's'),
+            Trigram('l', 'a', 'g'),
+            Trigram('e', 'i', 'd'),
+            Trigram('r', ' ', 'a'),
+            Trigram('e', ' ', 'd'),
+            Trigram('g', ' ', 'd'),
+            Trigram(' ', 's', 'i'),
+            Trigram(' ', 'f', 'å'),
+            Trigram('a', 'm', 'e'),
+            Trigram('a', ' ', 's'),
+            Trigram('e', ' ', 'r'),
+            Trigram('r', 'b', 'e'),
+            Trigram('j', 'e', 'n'),
+            Trigram('n', ' ', 'm'),
+            Trigram('r', ' ', 'd'),
+            Trigram('n', ' ', 'e'),
+            Trigram('n', 'n', ' '),
+            Trigram('e', ' ', 'n'),
+            Trigram('e', 'r', 'd'),
+            Trigram(' ', 't', 'r'),
+            Trigram(' ', 'm', 'å'),
+            Trigram(' ', 'b', 'l'),
+            Trigram(' ', 'm', 'o'),
+            Trigram('r', 'e', 'n'),
+            Trigram('r', 'u', 'n'),
+            Trigram('n', 'i', 'n'),
+            Trigram('b', 'l', 'i'),
+            Trigram('k', 'r', 'a'),
+            Trigram(' ', 'k', 'r'),
+            Trigram(' ', 'a', 't'),
+            Trigram('e', 'g', 'e'),
+            Trigram('n', ' ', 'i'),
+            Trigram('m', 'e', ' '),
+            Trigram('n', 's', 'k'),
+            Trigram('i', 'n', 's'),
+            Trigram('å', 'r', ' '),
+            Trigram('f', 'r', 'å'),
+            Trigram('i', 'n', ' '),
+            Trigram('l', 'o', 'v'),
+            Trigram('v', ' ', 'p'),
+            Trigram('e', 'n', 'd'),
+            Trigram('m', 'o', 't'),
+            Trigram('a', 'l', 'e'),
+            Trigram('e', ' ', 'v'),
+            Trigram('å', ' ', 'a'),
+            Trigram('f', 'å', ' '),
+            Trigram('r', 'a', 'v'),
+            Trigram('i', 'n', 't'),
+            Trigram('n', 'a', 'l'),
+            Trigram(' ', 'a', 'r'),
+            Trigram('s', 't', 'a'),
+            Trigram('e', ' ', 'k'),
+            Trigram('t', ' ', 'f'),
+            Trigram('o', 'm', 'e'),
+            Trigram(' ', 'l', 'a'),
+            Trigram('o', 't', ' '),
+            Trigram('t', ' ', 'a'),
+            Trigram('s', 'l', 'a'),
+            Trigram(' ', 'i', 'k'),
+            Trigram('n', 'l', 'e'),
+            Trigram('i', 't', 't'),
+            Trigram(' ', 'l', 'i'),
+            Trigram(' ', 'k', 'v'),
+            Trigram('i', 'd', ' '),
+            Trigram('k', 'k', 'j'),
+            Trigram('i', 'k', 'k'),
+            Trigram(' ', 'l', 'o'),
+            Trigram('n', 'a', 'd'),
+            Trigram('å', ' ', 'v'),
+            Trigram('t', 't', 'a'),
+            Trigram(' ', 'f', 'a'),
+            Trigram(' ', 's', 'e'),
+            Trigram('g', 'e', 'n'),
+            Trigram('l', 'd', ' '),
+            Trigram('å', ' ', 's'),
+            Trigram('k', 'a', 'n'),
+            Trigram('g', ' ', 't'),
+            Trigram(' ', 'k', 'a'),
+            Trigram('r', ' ', 'l'),
+            Trigram('g', 'o', 'd'),
+            Trigram('n', ' ', 'a'),
+            Trigram('l', 'i', 'n'),
+            Trigram('j', 'e', 'l'),
+            Trigram('i', 'l', 'd'),
+            Trigram('d', 'i', 'g'),
+            Trigram('h', 'a', ' '),
+            Trigram('l', ' ', 'd'),
+            Trigram('k', 'a', 'p'),
+            Trigram('v', 'e', ' '),
+            Trigram('n', 'd', 'r'),
+            Trigram('g', ' ', 'i'),
+            Trigram('g', ' ', 'a'),
+            Trigram('i', 'n', 'n'),
+            Trigram('v', 'a', 'r'),
+            Trigram('r', 'n', 'a'),
+            Trigram('r', ' ', 'm'),
+            Trigram('r', ' ', 'g'),
+            Trigram('a', ' ', 'o'),
+            Trigram('d', 'r', 'e'),
+            Trigram('d', ' ', 'a'),
+            Trigram('n', ' ', 't'),
+            Trigram('a', 'g', ' '),
+            Trigram('k', 'å', 'r'),
+            Trigram('m', 'å', 'l'),
+            Trigram('i', 'g', ' '),
+            Trigram('v', 'a', ' '),
+            Trigram('i', ' ', 'd'),
+            Trigram('t', ' ', 'm'),
+            Trigram('e', ' ', 'e'),
+            Trigram('n', ' ', 'd'),
+            Trigram('t', 'y', 'r'),
+            Trigram(' ', 'o', 'm'),
+            Trigram('g', ' ', 'e'),
+            Trigram('e', 'v', 'e'),
+            Trigram('d', 'å', ' '),
+            Trigram('e', ' ', 'u'),
+            Trigram(' ', 'd', 'å'),
+            Trigram('u', 'n', 'd'),
+            Trigram(' ', 'n', 'o'),
+            Trigram('i', 'r', ' '),
+            Trigram('g', 'a', 'r'),
+            Trigram('g', ' ', 'g'),
+            Trigram('l', ' ', 'h'),
+            Trigram('s', 'e', ' '),
+            Trigram('g', 'a', ' '),
+            Trigram('d', ' ', 'd'),
+            Trigram('l', ' ', 'f'),
+            Trigram('k', 'e', 'r'),
+            Trigram('r', ' ', 'o'),
+            Trigram('å', ' ', 'd'),
+            Trigram('e', 'l', 'd'),
+            Trigram('i', 'g', 'e'),
+            Trigram('t', ' ', 'd'),
+            Trigram('t', ' ', 'i'),
+            Trigram('t', ' ', 'h'),
+            Trigram('o', 'k', 'o'),
+            Trigram('n', 'n', 'l'),
+            Trigram('r', 'e', 'l'),
+            Trigram('n', 'o', 'k'),
+            Trigram('r', 't', ' '),
+            Trigram('l', 't', ' '),
+            Trigram('å', 's', 'e'),
+            Trigram('j', 'e', 'r'),
+            Trigram('t', 'a', ' '),
+            Trigram('i', 'k', ' '),
+            Trigram('i', 'a', 'l'),
+            Trigram('e', 'i', 'g'),
+            Trigram('r', ' ', 'p'),
+            Trigram('i', ' ', 'e'),
+            Trigram('o', 'l', 'k'),
+            Trigram('b', 'a', 'r'),
+            Trigram('o', 's', 'i'),
+            Trigram('k', 't', 'e'),
+            Trigram('s', 'o', 's'),
+            Trigram('l', 'i', 'r'),
+            Trigram('o', 'p', 'p'),
+            Trigram(' ', 'u', 'n'),
+            Trigram('a', 'd', ' '),
+            Trigram(' ', 'b', 'e'),
+        ],
+    ),
+    (
+        Lang::Cat,
+        &[
+            Trigram(' ', 'd', 'e'),
+            Trigram(' ', 'i', ' '),
+            Trigram('e', 's', ' '),
+            Trigram('d', 'e', ' '),
+            Trigram('l', 'a', ' '),
+            Trigram(' ', 'l', 'a'),
+            Trigram(' ', 'a', ' '),
+            Trigram(' ', 'p', 'e'),
+            Trigram('p', 'e', 'r'),
+            Trigram('i', 'ó', ' '),
+            Trigram('e', 'n', 't'),
+            Trigram('t', 'a', 't'),
+            Trigram(' ', 's', 'e'),
+            Trigram('n', 't', ' '),
+            Trigram('r', 'e', 't'),
+            Trigram('t', 's', ' '),
+            Trigram('d', 'r', 'e'),
+            Trigram('a', 't', ' '),
+            Trigram(' ', 'e', 'l'),
+            Trigram('l', 's', ' '),
+            Trigram(' ', 'd', 'r'),
+            Trigram('m', 'e', 'n'),
+            Trigram('a', 'c', 'i'),
+            Trigram('a', ' ', 'p'),
+            Trigram('c', 'i', 'ó'),
+            Trigram('o', 'n', 'a'),
+            Trigram(' ', 'c', 'o'),
+            Trigram('a', ' ', 'l'),
+            Trigram('a', 'l', ' '),
+            Trigram('n', 'a', ' '),
+            Trigram('s', ' ', 'd'),
+            Trigram('q', 'u', 'e'),
+            Trigram('e', 'n', ' '),
+            Trigram('e', 'l', ' '),
+            Trigram(' ', 't', 'o'),
+            Trigram('s', ' ', 'i'),
+            Trigram(' ', 'q', 'u'),
+            Trigram(' ', 'e', 'n'),
+            Trigram('e', ' ', 'l'),
+            Trigram('n', 's', ' '),
+            Trigram('t', 'o', 't'),
+            Trigram('e', 't', ' '),
+            Trigram('t', ' ', 'a'),
+            Trigram('e', 'r', 's'),
+            Trigram(' ', 'p', 'r'),
+            Trigram('t', ' ', 'd'),
+            Trigram('o', 'n', 's'),
+            Trigram('e', 'r', ' '),
+            Trigram(' ', 'l', 'l'),
+            Trigram('i', 'o', 'n'),
+            Trigram('a', ' ', 's'),
+            Trigram('t', 'a', ' '),
+            Trigram('a', ' ', 't'),
+            Trigram('c', 'o', 'n'),
+            Trigram('e', 'l', 's'),
+            Trigram('s', ' ', 'e'),
+            Trigram(' ', 'l', '’'),
+            Trigram('r', 's', 'o'),
+            Trigram('r', 'e', 's'),
+            Trigram('a', 'l', 's'),
+            Trigram('s', 'o', 'n'),
+            Trigram(' ', 'u', 'n'),
+            Trigram('e', 's', 't'),
+            Trigram('c', 'i', 'o'),
+            Trigram(' ', 'r', 'e'),
+            Trigram('p', 'r', 'o'),
+            Trigram('i', 't', 'a'),
+            Trigram('c', 'i', 'a'),
+            Trigram(' ', 'i', 'n'),
+            Trigram('l', 'e', 's'),
+            Trigram(' ', 'o', ' '),
+            Trigram('u', 'e', ' '),
+            Trigram('d', 'e', 'l'),
+            Trigram('l', 'l', 'i'),
+            Trigram('t', 'é', ' '),
+            Trigram(' ', 't', 'é'),
+            Trigram('i', 'a', ' '),
+            Trigram('a', 'm', 'e'),
+            Trigram('é', ' ', 'd'),
+            Trigram('s', 'e', 'v'),
+            Trigram('o', 't', 'a'),
+            Trigram('n', 'a', 'c'),
+            Trigram('i', ' ', 'l'),
+            Trigram(' ', 'a', 'l'),
+            Trigram('s', ' ', 'p'),
+            Trigram('a', ' ', 'd'),
+            Trigram('a', 'r', ' '),
+            Trigram('a', ' ', 'i'),
+            Trigram('u', 'a', 'l'),
+            Trigram('n', 'a', 'l'),
+            Trigram('a', ' ', 'c'),
+            Trigram('a', 'n', 't'),
+            Trigram('n', 'c', 'i'),
+            Trigram(' ', 'l', 'e'),
+            Trigram('e', 'r', 't'),
+            Trigram('s', 't', 'a'),
+            Trigram('r', 't', 'a'),
+            Trigram('s', 'e', 'r'),
+            Trigram('t', ' ', 'i'),
+            Trigram('i', ' ', 'a'),
+            Trigram('l', ' ', 'd'),
+            Trigram(' ', 'n', 'o'),
+            Trigram('v', 'a', ' '),
+            Trigram('a', 't', 's'),
+            Trigram(' ', 'd', '’'),
+            Trigram('s', ' ', 'n'),
+            Trigram('r', 'e', ' '),
+            Trigram('s', ' ', 'a'),
+            Trigram('e', ' ', 'c'),
+            Trigram('e', 'v', 'a'),
+            Trigram(' ', 'n', 'a'),
+            Trigram('r', 'à', ' '),
+            Trigram(' ', 'c', 'a'),
+            Trigram('u', 'e', 's'),
+            Trigram('c', 'o', 'm'),
+            Trigram('l', 'i', 'b'),
+            Trigram('é', 's', ' '),
+            Trigram(' ', 's', 'o'),
+            Trigram('i', 'b', 'e'),
+            Trigram(' ', 'e', 's'),
+            Trigram('e', 't', 's'),
+            Trigram('b', 'e', 'r'),
+            Trigram('d', 'a', ' '),
+            Trigram('r', ' ', 'a'),
+            Trigram('n', 'o', ' '),
+            Trigram('u', 'n', 'a'),
+            Trigram('l', '’', 'e'),
+            Trigram('s', ' ', 'l'),
+            Trigram('t', 'e', 'r'),
+            Trigram('s', 'e', 'n'),
+            Trigram('r', 'a', 'n'),
+            Trigram('u', 'r', 'e'),
+            Trigram('d', 'e', 's'),
+            Trigram('m', 'a', 'n'),
+            Trigram('i', ' ', 'e'),
+            Trigram('l', ' ', 'p'),
+            Trigram('t', ' ', 'e'),
+            Trigram('n', ' ', 'd'),
+            Trigram('e', ' ', 'd'),
+            Trigram('e', ' ', 'e'),
+            Trigram('o', 'm', ' '),
+            Trigram(' ', 'd', 'i'),
+            Trigram('c', 'c', 'i'),
+            Trigram('i', 'g', 'u'),
+            Trigram('a', ' ', 'a'),
+            Trigram('s', ' ', 't'),
+            Trigram(' ', 'p', 'a'),
+            Trigram('i', ' ', 'd'),
+            Trigram('t', 'r', 'a'),
+            Trigram('s', ' ', 'o'),
+            Trigram('a', 'q', 'u'),
+            Trigram('t', 'r', 'e'),
+            Trigram('v', 'o', 'l'),
+            Trigram('e', 'c', 't'),
+            Trigram('a', ' ', 'u'),
+            Trigram('l', ' ', 'i'),
+            Trigram('g', 'u', 'a'),
+            Trigram('i', 'd', 'e'),
+            Trigram('s', ' ', 's'),
+            Trigram('a', 'd', 'a'),
+            Trigram('e', 'n', 'e'),
+            Trigram('i', 'a', 'l'),
+            Trigram('n', 't', 'a'),
+            Trigram('n', 't', 'r'),
+            Trigram('e', 'n', 's'),
+            Trigram('s', 'o', 'c'),
+            Trigram('c', 't', 'e'),
+            Trigram('r', 'a', ' '),
+            Trigram('o', 'c', 'i'),
+            Trigram('h', 'u', 'm'),
             Trigram('u', 'm', 'a'),
             Trigram('c', 'l', 'a'),
             Trigram('a', 'l', 'i'),
@@ -10488,205 +10794,511 @@ pub static LATIN_LANGS: LangProfileList = &[
             Trigram(' ', 'g', 'e'),
             Trigram('a', ' ', 'e'),
             Trigram('i', ' ', 'p'),
-            Trigram('n', 't', ' '),
-            Trigram('o', 'm', 'i'),
-            Trigram('a', 't', 'u'),
-            Trigram('t', 'u', 'r'),
-            Trigram('r', 'i', 't'),
-            Trigram(' ', 's', 'i'),
+            Trigram('n', 't', ' '),
+            Trigram('o', 'm', 'i'),
+            Trigram('a', 't', 'u'),
+            Trigram('t', 'u', 'r'),
+            Trigram('r', 'i', 't'),
+            Trigram(' ', 's', 'i'),
+            Trigram('n', 'e', ' '),
+            Trigram('p', 's', 'i'),
+            Trigram('i', 'n', ' '),
+            Trigram('i', 'a', ' '),
+            Trigram('r', 'a', ' '),
+            Trigram('a', 'r', 'i'),
+            Trigram(' ', 'c', 'u'),
+            Trigram('v', 'i', 't'),
+            Trigram('r', 't', 'a'),
+            Trigram('m', 'o', ' '),
+            Trigram('t', 'o', ' '),
+            Trigram('m', 'n', 'i'),
+            Trigram('s', ' ', 'h'),
+            Trigram('e', ' ', 'e'),
+            Trigram('i', 'n', 't'),
+            Trigram('s', 'i', 'u'),
+            Trigram('m', ' ', 'c'),
+            Trigram('q', 'u', 'a'),
+            Trigram('t', ' ', 'p'),
+            Trigram('i', 'v', 'i'),
+            Trigram('i', 'n', 'i'),
+            Trigram('u', 't', ' '),
+            Trigram('r', 'e', ' '),
+            Trigram('e', 'r', 's'),
+            Trigram('i', 't', ' '),
+            Trigram('s', ' ', 's'),
+            Trigram('i', 'a', 'e'),
+            Trigram(' ', 'e', 's'),
+            Trigram('t', ' ', 's'),
+            Trigram('a', 'n', 'd'),
+            Trigram(' ', 'n', 'e'),
+            Trigram('p', 'r', 'o'),
+            Trigram(' ', 'n', 'u'),
+            Trigram('s', 't', ' '),
+            Trigram(' ', 'e', 'x'),
+            Trigram('n', 'd', 'a'),
+            Trigram('c', 'i', 'e'),
+            Trigram('n', 'i', 'b'),
+            Trigram('t', ' ', 'a'),
+            Trigram('e', 'r', 'e'),
+            Trigram('t', 'r', 'i'),
+            Trigram('n', 'i', 't'),
+            Trigram(' ', 'a', 't'),
+            Trigram('t', 'i', 'u'),
+            Trigram('t', 'a', ' '),
+            Trigram('r', 'i', 's'),
+            Trigram(' ', 'c', 'i'),
+            Trigram('c', 'i', 'v'),
+            Trigram('n', 'i', ' '),
+            Trigram('u', 'r', 'i'),
+            Trigram('u', 'r', ' '),
+            Trigram('r', 'i', 'm'),
+            Trigram(' ', 'v', 'i'),
+            Trigram('p', 'a', 'r'),
+            Trigram('a', 'd', ' '),
+            Trigram('e', 's', 's'),
+            Trigram('l', 'i', 'c'),
+            Trigram('i', ' ', 'i'),
+            Trigram(' ', 's', 'o'),
+            Trigram(' ', 'p', 'u'),
+            Trigram(' ', 'o', 'p'),
+            Trigram('r', 'a', 'e'),
+            Trigram(' ', 'f', 'a'),
+            Trigram('s', ' ', 'v'),
+            Trigram(' ', 'u', 't'),
+            Trigram('d', 'e', 'm'),
+            Trigram('s', 'e', ' '),
+            Trigram('o', 'n', 's'),
+            Trigram('o', ' ', 'e'),
+            Trigram('r', 'i', 'a'),
+            Trigram(' ', 's', 'e'),
+            Trigram('e', ' ', 'a'),
+            Trigram(' ', 'm', 'o'),
+            Trigram('l', 'e', 'g'),
+            Trigram('a', 't', 'q'),
+            Trigram('t', 'q', 'u'),
+            Trigram('c', 'o', 'm'),
+            Trigram('t', 'e', ' '),
+            Trigram('n', 'i', 'u'),
+            Trigram('i', 'e', 'n'),
+            Trigram('v', 'e', 'l'),
+            Trigram('e', 'l', ' '),
+            Trigram(' ', 'm', 'a'),
+            Trigram('t', ' ', 'e'),
+            Trigram('i', 'i', 's'),
+            Trigram('g', 'n', 'i'),
+            Trigram('e', 'q', 'u'),
+            Trigram('o', 'c', 'i'),
+            Trigram('c', 'i', 'p'),
+            Trigram('u', 'r', 'a'),
+            Trigram('u', 'n', 't'),
+            Trigram('s', ' ', 'd'),
+            Trigram('t', ' ', 'i'),
+            Trigram('a', 'l', 'i'),
+            Trigram('q', 'u', 'o'),
+            Trigram('e', 'c', 't'),
+            Trigram(' ', 't', 'e'),
+            Trigram('a', ' ', 's'),
+            Trigram('t', ' ', 'd'),
+            Trigram(' ', 'd', 'o'),
+            Trigram('t', 'u', 't'),
+            Trigram('a', 'n', 't'),
+            Trigram('i', 's', 'c'),
+            Trigram('i', 'n', 'a'),
+            Trigram('m', 'e', 'n'),
+            Trigram('s', 'i', 'n'),
+            Trigram('u', 'a', ' '),
+            Trigram('p', 'r', 'a'),
+            Trigram('o', 'r', 'u'),
+            Trigram('o', 'm', 'm'),
+            Trigram('e', 't', 'a'),
+            Trigram('s', ' ', 'n'),
+            Trigram('a', ' ', 'p'),
+            Trigram('t', 'u', 'm'),
+            Trigram('i', 'a', 'm'),
+            Trigram('i', 'o', ' '),
+            Trigram('i', ' ', 'c'),
+            Trigram('s', 't', 'i'),
+            Trigram(' ', 'a', 'u'),
+            Trigram('v', 'e', 'r'),
+            Trigram(' ', 'a', 'e'),
+            Trigram('i', 't', 'o'),
+            Trigram('d', 'i', 'c'),
+            Trigram('i', 'm', 'i'),
+            Trigram('s', ' ', 'l'),
+            Trigram('e', ' ', 'd'),
+            Trigram('f', 'i', 'c'),
+            Trigram('c', 'i', 'a'),
+            Trigram('t', ' ', 'o'),
+            Trigram('p', 'u', 'b'),
+            Trigram('u', 'b', 'l'),
+            Trigram('b', 'l', 'i'),
+            Trigram('m', 'u', 'n'),
+            Trigram('i', ' ', 's'),
+            Trigram('s', 'o', 'c'),
+            Trigram('a', 'r', 'u'),
+            Trigram('l', 'a', 'r'),
+            Trigram('u', 'l', 'l'),
+            Trigram('o', 'r', 'i'),
+            Trigram('t', ' ', 'h'),
+            Trigram('i', ' ', 'e'),
+            Trigram('s', 's', 'e'),
+            Trigram('o', 'm', 'o'),
+            Trigram('c', 't', 'o'),
+            Trigram('i', 't', 'u'),
+            Trigram('t', 'u', 's'),
+            Trigram(' ', 'e', 'a'),
+            Trigram('e', 'a', ' '),
+            Trigram('a', 'e', 'q'),
+            Trigram('g', 'i', 'o'),
+            Trigram('u', 'i', ' '),
+            Trigram('m', ' ', 's'),
+            Trigram('e', 'r', ' '),
+            Trigram('m', ' ', 'r'),
+            Trigram(' ', 'r', 'a'),
+            Trigram(' ', 'f', 'i'),
+            Trigram('f', 'f', 'i'),
+            Trigram('c', 'o', 'g'),
+            Trigram('d', 'a', ' '),
+            Trigram(' ', 'l', 'e'),
+            Trigram('m', 'o', 'd'),
+            Trigram('a', ' ', 'c'),
+            Trigram('m', 'q', 'u'),
+            Trigram('n', 'u', 'l'),
+            Trigram('e', ' ', 'o'),
+            Trigram('e', 'r', 'a'),
+            Trigram('t', 'e', 'n'),
+            Trigram('n', 't', 'u'),
+            Trigram('s', 'p', 'e'),
+            Trigram('o', ' ', 'n'),
+            Trigram('e', 'm', 'o'),
+            Trigram('c', 'r', 'i'),
+            Trigram('s', ' ', 'f'),
+            Trigram(' ', 'c', 'a'),
+            Trigram('d', 'e', ' '),
+            Trigram('a', ' ', 'd'),
+            Trigram('r', 'e', 'l'),
+            Trigram('i', 'i', ' '),
+            Trigram('e', 'n', 'e'),
+            Trigram(' ', 't', 'u'),
+            Trigram('s', 'u', 'i'),
+            Trigram('r', 't', 'i'),
+            Trigram('s', 'c', 'i'),
+            Trigram('n', 'a', 'e'),
+            Trigram('m', ' ', 'q'),
+            Trigram('m', ' ', 'a'),
+            Trigram('e', 'g', 'i'),
+            Trigram('c', 'e', 's'),
+        ],
+    ),
+    (
+        Lang::Srp,
+        &[
+            Trigram(' ', 'p', 'r'),
+            Trigram(' ', 'i', ' '),
+            Trigram('r', 'a', 'v'),
+            Trigram('p', 'r', 'a'),
+            Trigram(' ', 'n', 'a'),
+            Trigram('n', 'a', ' '),
+            Trigram(' ', 'p', 'o'),
+            Trigram('m', 'a', ' '),
+            Trigram(' ', 's', 'v'),
+            Trigram('d', 'a', ' '),
+            Trigram('i', 'm', 'a'),
+            Trigram('a', ' ', 'p'),
+            Trigram('a', ' ', 'i'),
+            Trigram('v', 'o', ' '),
+            Trigram('k', 'o', ' '),
+            Trigram('v', 'a', ' '),
+            Trigram('t', 'i', ' '),
+            Trigram('i', ' ', 'p'),
+            Trigram(' ', 'u', ' '),
+            Trigram('a', 'k', 'o'),
+            Trigram(' ', 'd', 'a'),
+            Trigram('a', ' ', 's'),
+            Trigram('a', 'v', 'o'),
+            Trigram('i', ' ', 's'),
+            Trigram('o', 's', 't'),
+            Trigram(' ', 'z', 'a'),
+            Trigram('o', ' ', 'i'),
+            Trigram('s', 'v', 'a'),
+            Trigram(' ', 'i', 'm'),
+            Trigram('v', 'a', 'k'),
+            Trigram('a', 'v', 'a'),
+            Trigram('j', 'e', ' '),
+            Trigram('e', ' ', 's'),
+            Trigram(' ', 's', 'l'),
+            Trigram(' ', 'k', 'o'),
+            Trigram('o', ' ', 'n'),
+            Trigram('n', 'j', 'a'),
+            Trigram('j', 'a', ' '),
+            Trigram('n', 'o', ' '),
             Trigram('n', 'e', ' '),
-            Trigram('p', 's', 'i'),
-            Trigram('i', 'n', ' '),
-            Trigram('i', 'a', ' '),
-            Trigram('r', 'a', ' '),
-            Trigram('a', 'r', 'i'),
-            Trigram(' ', 'c', 'u'),
-            Trigram('v', 'i', 't'),
-            Trigram('r', 't', 'a'),
-            Trigram('m', 'o', ' '),
-            Trigram('t', 'o', ' '),
-            Trigram('m', 'n', 'i'),
-            Trigram('s', ' ', 'h'),
-            Trigram('e', ' ', 'e'),
-            Trigram('i', 'n', 't'),
-            Trigram('s', 'i', 'u'),
-            Trigram('m', ' ', 'c'),
-            Trigram('q', 'u', 'a'),
-            Trigram('t', ' ', 'p'),
-            Trigram('i', 'v', 'i'),
-            Trigram('i', 'n', 'i'),
-            Trigram('u', 't', ' '),
-            Trigram('r', 'e', ' '),
-            Trigram('e', 'r', 's'),
-            Trigram('i', 't', ' '),
-            Trigram('s', ' ', 's'),
-            Trigram('i', 'a', 'e'),
-            Trigram(' ', 'e', 's'),
-            Trigram('t', ' ', 's'),
-            Trigram('a', 'n', 'd'),
             Trigram(' ', 'n', 'e'),
-            Trigram('p', 'r', 'o'),
-            Trigram(' ', 'n', 'u'),
-            Trigram('s', 't', ' '),
-            Trigram(' ', 'e', 'x'),
-            Trigram('n', 'd', 'a'),
-            Trigram('c', 'i', 'e'),
-            Trigram('n', 'i', 'b'),
-            Trigram('t', ' ', 'a'),
-            Trigram('e', 'r', 'e'),
-            Trigram('t', 'r', 'i'),
-            Trigram('n', 'i', 't'),
-            Trigram(' ', 'a', 't'),
-            Trigram('t', 'i', 'u'),
-            Trigram('t', 'a', ' '),
-            Trigram('r', 'i', 's'),
-            Trigram(' ', 'c', 'i'),
-            Trigram('c', 'i', 'v'),
+            Trigram('o', 'm', ' '),
+            Trigram('l', 'i', ' '),
+            Trigram(' ', 'd', 'r'),
+            Trigram('i', 'l', 'i'),
+            Trigram('u', ' ', 's'),
+            Trigram('s', 'l', 'o'),
+            Trigram('o', 'b', 'o'),
+            Trigram('k', 'o', 'j'),
+            Trigram('i', 'h', ' '),
+            Trigram('l', 'o', 'b'),
+            Trigram('b', 'o', 'd'),
+            Trigram('i', 'm', ' '),
+            Trigram('a', ' ', 'n'),
+            Trigram('j', 'u', ' '),
+            Trigram(' ', 'i', 'l'),
+            Trigram('s', 't', 'v'),
+            Trigram(' ', 'b', 'i'),
+            Trigram('s', 't', 'i'),
+            Trigram('a', ' ', 'o'),
+            Trigram('p', 'r', 'i'),
+            Trigram('a', ' ', 'u'),
+            Trigram(' ', 'r', 'a'),
+            Trigram('j', 'e', 'd'),
+            Trigram('o', 'g', ' '),
+            Trigram(' ', 'j', 'e'),
+            Trigram('e', ' ', 'p'),
+            Trigram('n', 'j', 'e'),
             Trigram('n', 'i', ' '),
-            Trigram('u', 'r', 'i'),
-            Trigram('u', 'r', ' '),
-            Trigram('r', 'i', 'm'),
-            Trigram(' ', 'v', 'i'),
-            Trigram('p', 'a', 'r'),
-            Trigram('a', 'd', ' '),
-            Trigram('e', 's', 's'),
-            Trigram('l', 'i', 'c'),
+            Trigram('u', ' ', 'p'),
+            Trigram('a', ' ', 'd'),
+            Trigram('e', 'd', 'n'),
+            Trigram('i', 't', 'i'),
+            Trigram('a', ' ', 'k'),
+            Trigram('n', 'o', 's'),
+            Trigram('i', ' ', 'u'),
+            Trigram('o', ' ', 'd'),
+            Trigram('p', 'r', 'o'),
+            Trigram(' ', 's', 'u'),
+            Trigram('a', 'n', 'j'),
+            Trigram('o', 'v', 'a'),
+            Trigram('e', ' ', 'i'),
+            Trigram('v', 'a', 'n'),
             Trigram('i', ' ', 'i'),
-            Trigram(' ', 's', 'o'),
-            Trigram(' ', 'p', 'u'),
-            Trigram(' ', 'o', 'p'),
-            Trigram('r', 'a', 'e'),
-            Trigram(' ', 'f', 'a'),
-            Trigram('s', ' ', 'v'),
-            Trigram(' ', 'u', 't'),
-            Trigram('d', 'e', 'm'),
+            Trigram('c', 'i', 'j'),
+            Trigram(' ', 'o', 's'),
             Trigram('s', 'e', ' '),
-            Trigram('o', 'n', 's'),
-            Trigram('o', ' ', 'e'),
-            Trigram('r', 'i', 'a'),
+            Trigram('d', 'r', 'u'),
+            Trigram('s', 't', 'a'),
+            Trigram('a', 'j', 'u'),
+            Trigram('i', ' ', 'o'),
+            Trigram(' ', 'o', 'b'),
+            Trigram('r', 'o', 'd'),
+            Trigram('o', 'v', 'e'),
+            Trigram(' ', 'k', 'a'),
+            Trigram(' ', 'd', 'e'),
+            Trigram('e', ' ', 'o'),
+            Trigram('a', 'c', 'i'),
+            Trigram('o', 'v', 'o'),
+            Trigram(' ', 'n', 'i'),
+            Trigram(' ', 'o', 'd'),
+            Trigram('i', ' ', 'd'),
             Trigram(' ', 's', 'e'),
-            Trigram('e', ' ', 'a'),
-            Trigram(' ', 'm', 'o'),
-            Trigram('l', 'e', 'g'),
-            Trigram('a', 't', 'q'),
-            Trigram('t', 'q', 'u'),
-            Trigram('c', 'o', 'm'),
-            Trigram('t', 'e', ' '),
-            Trigram('n', 'i', 'u'),
-            Trigram('i', 'e', 'n'),
-            Trigram('v', 'e', 'l'),
-            Trigram('e', 'l', ' '),
-            Trigram(' ', 'm', 'a'),
-            Trigram('t', ' ', 'e'),
-            Trigram('i', 'i', 's'),
-            Trigram('g', 'n', 'i'),
-            Trigram('e', 'q', 'u'),
-            Trigram('o', 'c', 'i'),
-            Trigram('c', 'i', 'p'),
-            Trigram('u', 'r', 'a'),
-            Trigram('u', 'n', 't'),
-            Trigram('s', ' ', 'd'),
-            Trigram('t', ' ', 'i'),
-            Trigram('a', 'l', 'i'),
-            Trigram('q', 'u', 'o'),
-            Trigram('e', 'c', 't'),
-            Trigram(' ', 't', 'e'),
-            Trigram('a', ' ', 's'),
-            Trigram('t', ' ', 'd'),
+            Trigram('v', 'e', ' '),
+            Trigram('u', 'j', 'e'),
+            Trigram('e', 'n', 'i'),
+            Trigram('i', 'j', 'a'),
+            Trigram('a', 'v', 'n'),
+            Trigram('ž', 'a', 'v'),
+            Trigram(' ', 's', 't'),
+            Trigram('u', ' ', 'i'),
+            Trigram('m', ' ', 'i'),
+            Trigram('d', 'n', 'a'),
+            Trigram('s', 'u', ' '),
+            Trigram('r', 'e', 'd'),
+            Trigram('i', ' ', 'n'),
+            Trigram('o', 'j', 'a'),
+            Trigram('e', ' ', 'b'),
+            Trigram('a', 'r', 'a'),
+            Trigram('š', 't', 'o'),
+            Trigram('n', 'o', 'v'),
+            Trigram('r', 'ž', 'a'),
+            Trigram('v', 'o', 'j'),
+            Trigram('d', 'r', 'ž'),
+            Trigram('t', 'v', 'a'),
+            Trigram('o', 'd', 'i'),
+            Trigram('u', ' ', 'o'),
+            Trigram('a', ' ', 'b'),
+            Trigram('o', 'd', 'n'),
+            Trigram('p', 'o', 'š'),
+            Trigram('o', 'š', 't'),
+            Trigram('n', 'i', 'm'),
+            Trigram('a', ' ', 'j'),
+            Trigram('k', 'a', ' '),
+            Trigram('r', 'a', 'n'),
+            Trigram('u', ' ', 'u'),
+            Trigram(' ', 'o', 'v'),
+            Trigram('a', 'r', 'o'),
+            Trigram('e', ' ', 'd'),
+            Trigram('s', 'n', 'o'),
+            Trigram('e', 'n', 'j'),
+            Trigram('u', ' ', 'z'),
+            Trigram('r', 'a', 'z'),
+            Trigram(' ', 'i', 'z'),
+            Trigram('o', 's', 'n'),
+            Trigram('a', ' ', 'z'),
+            Trigram('o', ' ', 'p'),
+            Trigram('a', 'v', 'e'),
+            Trigram('p', 'r', 'e'),
+            Trigram('d', 'e', ' '),
+            Trigram('b', 'i', 't'),
+            Trigram('n', 'i', 'h'),
+            Trigram('š', 't', 'i'),
+            Trigram('v', 'u', ' '),
+            Trigram('u', ' ', 'd'),
+            Trigram('d', 'u', ' '),
+            Trigram('t', 'u', ' '),
+            Trigram(' ', 't', 'r'),
+            Trigram('n', 'a', 'r'),
+            Trigram(' ', 's', 'a'),
+            Trigram('g', 'o', 'v'),
+            Trigram('z', 'a', ' '),
+            Trigram('b', 'e', 'z'),
+            Trigram('o', 'j', 'i'),
+            Trigram('u', ' ', 'n'),
+            Trigram('v', 'n', 'o'),
+            Trigram('i', 'č', 'n'),
+            Trigram('e', 'đ', 'u'),
+            Trigram('l', 'o', ' '),
+            Trigram('a', 'n', ' '),
+            Trigram('č', 'n', 'o'),
+            Trigram('j', 'i', ' '),
+            Trigram('n', 'a', 'k'),
+            Trigram('o', 'd', 'a'),
+            Trigram(' ', 'm', 'e'),
+            Trigram('v', 'i', 'm'),
+            Trigram('t', 'o', ' '),
+            Trigram('s', 'v', 'o'),
+            Trigram('a', 'n', 'i'),
+            Trigram('n', 'a', 'c'),
+            Trigram(' ', 'n', 'j'),
+            Trigram('n', 'i', 'k'),
+            Trigram('j', 'e', 'g'),
+            Trigram('t', 'i', 't'),
+            Trigram('o', 'j', ' '),
+            Trigram('m', 'e', ' '),
+            Trigram('n', 'o', 'm'),
+            Trigram('m', ' ', 's'),
+            Trigram('e', ' ', 'u'),
+            Trigram('o', ' ', 'k'),
+            Trigram('k', 'u', ' '),
             Trigram(' ', 'd', 'o'),
-            Trigram('t', 'u', 't'),
-            Trigram('a', 'n', 't'),
-            Trigram('i', 's', 'c'),
-            Trigram('i', 'n', 'a'),
-            Trigram('m', 'e', 'n'),
-            Trigram('s', 'i', 'n'),
-            Trigram('u', 'a', ' '),
-            Trigram('p', 'r', 'a'),
-            Trigram('o', 'r', 'u'),
-            Trigram('o', 'm', 'm'),
-            Trigram('e', 't', 'a'),
-            Trigram('s', ' ', 'n'),
-            Trigram('a', ' ', 'p'),
-            Trigram('t', 'u', 'm'),
-            Trigram('i', 'a', 'm'),
-            Trigram('i', 'o', ' '),
-            Trigram('i', ' ', 'c'),
-            Trigram('s', 't', 'i'),
-            Trigram(' ', 'a', 'u'),
+            Trigram('i', 'k', 'a'),
+            Trigram('i', 'k', 'o'),
+            Trigram('e', ' ', 'k'),
+            Trigram('p', 'o', 's'),
+            Trigram('a', 'š', 't'),
+            Trigram('t', 'r', 'e'),
+            Trigram('a', 'l', 'n'),
+            Trigram('n', 'o', 'g'),
+            Trigram(' ', 'v', 'r'),
+            Trigram('r', 'e', 'b'),
+            Trigram('n', 's', 't'),
+            Trigram(' ', 'k', 'r'),
+            Trigram('s', 't', 'u'),
+            Trigram('d', 'n', 'o'),
+            Trigram('e', 'm', ' '),
+            Trigram('v', 'a', 'r'),
+            Trigram('e', ' ', 'n'),
+            Trigram('r', 'i', 'v'),
+            Trigram('t', 'u', 'p'),
+            Trigram('ž', 'i', 'v'),
+            Trigram('t', 'e', ' '),
+            Trigram('č', 'o', 'v'),
+            Trigram('s', 't', ' '),
+            Trigram('o', 'v', 'i'),
+            Trigram('d', 'n', 'i'),
+            Trigram('a', 'o', ' '),
+            Trigram('s', 'm', 'e'),
+            Trigram('b', 'r', 'a'),
+            Trigram('a', 'v', 'i'),
+            Trigram(' ', 'l', 'i'),
+            Trigram('k', 'a', 'o'),
+            Trigram('v', 'o', 'l'),
+            Trigram('o', 'l', 'j'),
+            Trigram('i', 'l', 'o'),
+            Trigram('o', ' ', 's'),
+            Trigram('š', 't', 'v'),
+            Trigram('i', ' ', 'm'),
+            Trigram('z', 'a', 'š'),
+            Trigram('n', 'j', 'u'),
+            Trigram('r', 'u', 'g'),
+            Trigram('t', 'a', 'v'),
+            Trigram('a', 'n', 's'),
+            Trigram('e', 'n', 'o'),
+            Trigram('p', 'o', 'r'),
+            Trigram('k', 'r', 'i'),
+            Trigram('i', ' ', 'b'),
+            Trigram('o', 'd', 'u'),
+            Trigram('a', ' ', 'r'),
+            Trigram('l', 'a', ' '),
+            Trigram(' ', 'č', 'o'),
+            Trigram('a', ' ', 't'),
+            Trigram('r', 'u', 'š'),
+            Trigram('u', 'š', 't'),
+            Trigram(' ', 'b', 'u'),
+            Trigram('b', 'u', 'd'),
+            Trigram('a', 'v', 'l'),
+            Trigram('v', 'l', 'j'),
+            Trigram('u', 'g', 'i'),
+            Trigram('m', ' ', 'p'),
+            Trigram('k', 'o', 'm'),
+            Trigram('o', 'j', 'e'),
             Trigram('v', 'e', 'r'),
-            Trigram(' ', 'a', 'e'),
-            Trigram('i', 't', 'o'),
-            Trigram('d', 'i', 'c'),
-            Trigram('i', 'm', 'i'),
-            Trigram('s', ' ', 'l'),
-            Trigram('e', ' ', 'd'),
-            Trigram('f', 'i', 'c'),
-            Trigram('c', 'i', 'a'),
-            Trigram('t', ' ', 'o'),
-            Trigram('p', 'u', 'b'),
-            Trigram('u', 'b', 'l'),
-            Trigram('b', 'l', 'i'),
-            Trigram('m', 'u', 'n'),
-            Trigram('i', ' ', 's'),
-            Trigram('s', 'o', 'c'),
-            Trigram('a', 'r', 'u'),
-            Trigram('l', 'a', 'r'),
-            Trigram('u', 'l', 'l'),
-            Trigram('o', 'r', 'i'),
-            Trigram('t', ' ', 'h'),
-            Trigram('i', ' ', 'e'),
-            Trigram('s', 's', 'e'),
-            Trigram('o', 'm', 'o'),
-            Trigram('c', 't', 'o'),
-            Trigram('i', 't', 'u'),
-            Trigram('t', 'u', 's'),
-            Trigram(' ', 'e', 'a'),
-            Trigram('e', 'a', ' '),
-            Trigram('a', 'e', 'q'),
-            Trigram('g', 'i', 'o'),
-            Trigram('u', 'i', ' '),
-            Trigram('m', ' ', 's'),
-            Trigram('e', 'r', ' '),
-            Trigram('m', ' ', 'r'),
-            Trigram(' ', 'r', 'a'),
-            Trigram(' ', 'f', 'i'),
-            Trigram('f', 'f', 'i'),
-            Trigram('c', 'o', 'g'),
-            Trigram('d', 'a', ' '),
-            Trigram(' ', 'l', 'e'),
-            Trigram('m', 'o', 'd'),
-            Trigram('a', ' ', 'c'),
-            Trigram('m', 'q', 'u'),
-            Trigram('n', 'u', 'l'),
-            Trigram('e', ' ', 'o'),
-            Trigram('e', 'r', 'a'),
-            Trigram('t', 'e', 'n'),
-            Trigram('n', 't', 'u'),
-            Trigram('s', 'p', 'e'),
-            Trigram('o', ' ', 'n'),
-            Trigram('e', 'm', 'o'),
-            Trigram('c', 'r', 'i'),
-            Trigram('s', ' ', 'f'),
-            Trigram(' ', 'c', 'a'),
-            Trigram('d', 'e', ' '),
-            Trigram('a', ' ', 'd'),
-            Trigram('r', 'e', 'l'),
-            Trigram('i', 'i', ' '),
-            Trigram('e', 'n', 'e'),
-            Trigram(' ', 't', 'u'),
-            Trigram('s', 'u', 'i'),
-            Trigram('r', 't', 'i'),
-            Trigram('s', 'c', 'i'),
-            Trigram('n', 'a', 'e'),
-            Trigram('m', ' ', 'q'),
-            Trigram('m', ' ', 'a'),
-            Trigram('e', 'g', 'i'),
-            Trigram('c', 'e', 's'),
+            Trigram(' ', 'v', 'e'),
+            Trigram('p', 'o', 'd'),
+            Trigram('i', ' ', 'v'),
+            Trigram('m', 'e', 'đ'),
+            Trigram('e', 'g', 'o'),
+            Trigram('v', 'r', 'e'),
+            Trigram('a', 'k', 'v'),
+            Trigram('e', 'd', 'i'),
+            Trigram('t', 'v', 'o'),
+            Trigram(' ', 's', 'm'),
+            Trigram('o', 'd', ' '),
+            Trigram('d', 'e', 'l'),
+            Trigram('e', 'n', 'a'),
+            Trigram('r', 'a', 'd'),
+            Trigram('b', 'a', ' '),
+            Trigram(' ', 'm', 'o'),
+            Trigram('n', 'u', ' '),
+            Trigram('o', ' ', 'j'),
+            Trigram('d', 's', 't'),
+            Trigram('k', 'l', 'a'),
+            Trigram(' ', 'o', 'p'),
+            Trigram('k', 'a', 'k'),
+            Trigram('s', 'a', 'm'),
+            Trigram('e', 'r', 'e'),
+            Trigram('r', 'i', 'm'),
+            Trigram('v', 'i', 'č'),
+            Trigram('i', 'v', 'a'),
+            Trigram('o', ' ', 'o'),
+            Trigram(' ', 'o', 'n'),
+            Trigram('v', 'n', 'i'),
+            Trigram('t', 'e', 'r'),
+            Trigram('z', 'b', 'e'),
+            Trigram('h', ' ', 'p'),
+            Trigram('n', 'i', 'c'),
+            Trigram('e', 'b', 'a'),
+            Trigram('e', ' ', 'r'),
+            Trigram('u', ' ', 'v'),
+            Trigram('i', 's', 't'),
+            Trigram('v', 'e', 'k'),
+            Trigram('r', 'e', 'm'),
+            Trigram('s', 'v', 'i'),
+            Trigram('b', 'i', 'l'),
+            Trigram('š', 't', 'e'),
+            Trigram('e', 'z', 'b'),
+            Trigram('j', 'u', 'ć'),
+            Trigram('j', 'e', 'n'),
         ],
     ),
 ];
 
 /// Languages for script Cyrillic
+#[cfg(feature = "script-cyrillic")]
 pub static CYRILLIC_LANGS: LangProfileList = &[
     (
         Lang::Rus,
@@ -11603,6 +12215,311 @@ pub static CYRILLIC_LANGS: LangProfileList = &[
             Trigram('г', 'л', 'а'),
         ],
     ),
+    (
+        Lang::Aze,
+        &[
+            Trigram(' ', 'в', 'ә'),
+            Trigram('в', 'ә', ' '),
+            Trigram('ә', 'р', ' '),
+            Trigram('л', 'а', 'р'),
+            Trigram(' ', 'һ', 'ә'),
+            Trigram('и', 'н', ' '),
+            Trigram('и', 'р', ' '),
+            Trigram(' ', 'о', 'л'),
+            Trigram(' ', 'һ', 'ү'),
+            Trigram(' ', 'б', 'и'),
+            Trigram('һ', 'ү', 'г'),
+            Trigram('ү', 'г', 'у'),
+            Trigram('г', 'у', 'г'),
+            Trigram('н', 'а', ' '),
+            Trigram('л', 'ә', 'р'),
+            Trigram('д', 'ә', ' '),
+            Trigram('һ', 'ә', 'р'),
+            Trigram(' ', 'ш', 'ә'),
+            Trigram('б', 'и', 'р'),
+            Trigram('а', 'н', ' '),
+            Trigram(' ', 'т', 'ә'),
+            Trigram('л', 'и', 'к'),
+            Trigram('р', ' ', 'б'),
+            Trigram('м', 'а', 'л'),
+            Trigram('л', 'м', 'а'),
+            Trigram('а', 'с', 'ы'),
+            Trigram('и', 'н', 'и'),
+            Trigram('р', ' ', 'һ'),
+            Trigram('ш', 'ә', 'х'),
+            Trigram('ә', 'н', ' '),
+            Trigram('ә', 'х', 'с'),
+            Trigram('а', 'р', 'ы'),
+            Trigram('г', 'л', 'а'),
+            Trigram('д', 'и', 'р'),
+            Trigram('а', ' ', 'м'),
+            Trigram('а', 'л', 'и'),
+            Trigram('у', 'г', 'у'),
+            Trigram('а', 'г', ' '),
+            Trigram(' ', 'м', 'а'),
+            Trigram('ы', 'н', ' '),
+            Trigram('и', 'л', 'ә'),
+            Trigram('у', 'н', 'а'),
+            Trigram('ј', 'ә', 'т'),
+            Trigram(' ', 'ј', 'а'),
+            Trigram('и', 'к', 'д'),
+            Trigram('а', 'р', 'а'),
+            Trigram('а', 'р', ' '),
+            Trigram('ә', 'р', 'и'),
+            Trigram('ә', 'с', 'и'),
+            Trigram('р', 'и', 'н'),
+            Trigram('ә', 'т', 'и'),
+            Trigram('р', ' ', 'ш'),
+            Trigram('н', 'и', 'н'),
+            Trigram('д', 'ә', 'н'),
+            Trigram('ј', 'ј', 'ә'),
+            Trigram('н', ' ', 'һ'),
+            Trigram(' ', 'а', 'з'),
+            Trigram('н', 'и', ' '),
+            Trigram('ә', 'р', 'ә'),
+            Trigram(' ', 'м', 'ә'),
+            Trigram('з', 'а', 'д'),
+            Trigram('м', 'ә', 'к'),
+            Trigram('и', 'ј', 'ј'),
+            Trigram(' ', 'м', 'ү'),
+            Trigram('с', 'и', 'н'),
+            Trigram('т', 'и', 'н'),
+            Trigram('ү', 'н', ' '),
+            Trigram('о', 'л', 'у'),
+            Trigram('и', ' ', 'в'),
+            Trigram('н', 'д', 'ә'),
+            Trigram('г', 'у', 'н'),
+            Trigram('р', 'ы', 'н'),
+            Trigram('а', 'з', 'а'),
+            Trigram('н', 'д', 'а'),
+            Trigram('ә', ' ', 'а'),
+            Trigram('ә', 'т', ' '),
+            Trigram('ы', 'н', 'ы'),
+            Trigram('н', 'ы', 'н'),
+            Trigram('л', 'ы', 'г'),
+            Trigram('и', 'л', 'м'),
+            Trigram(' ', 'г', 'а'),
+            Trigram(' ', 'е', 'т'),
+            Trigram('ә', ' ', 'ј'),
+            Trigram('к', 'д', 'и'),
+            Trigram('ә', 'к', ' '),
+            Trigram('л', 'ә', ' '),
+            Trigram('л', 'м', 'ә'),
+            Trigram('о', 'л', 'м'),
+            Trigram('ы', 'н', 'а'),
+            Trigram('и', 'н', 'д'),
+            Trigram('л', 'у', 'н'),
+            Trigram(' ', 'и', 'н'),
+            Trigram('м', 'а', 'с'),
+            Trigram('х', 'с', ' '),
+            Trigram('с', 'ы', 'н'),
+            Trigram('ә', ' ', 'б'),
+            Trigram('г', ' ', 'в'),
+            Trigram('н', ' ', 'м'),
+            Trigram('а', 'д', 'л'),
+            Trigram('ј', 'а', ' '),
+            Trigram('т', 'м', 'ә'),
+            Trigram('н', ' ', 'т'),
+            Trigram('ә', 'м', 'и'),
+            Trigram('н', 'ә', ' '),
+            Trigram('д', 'л', 'ы'),
+            Trigram('д', 'а', ' '),
+            Trigram(' ', 'б', 'ә'),
+            Trigram('н', 'у', 'н'),
+            Trigram('б', 'ә', 'р'),
+            Trigram('с', 'ы', ' '),
+            Trigram(' ', 'о', 'н'),
+            Trigram('ә', 'ј', 'а'),
+            Trigram('ә', ' ', 'һ'),
+            Trigram('м', 'а', 'г'),
+            Trigram('д', 'а', 'н'),
+            Trigram('у', 'н', ' '),
+            Trigram('е', 'т', 'м'),
+            Trigram('и', 'н', 'ә'),
+            Trigram('н', ' ', 'а'),
+            Trigram('р', 'л', 'ә'),
+            Trigram('с', 'и', ' '),
+            Trigram(' ', 'в', 'а'),
+            Trigram('ә', ' ', 'в'),
+            Trigram('р', 'а', 'г'),
+            Trigram('н', ' ', 'б'),
+            Trigram('ә', ' ', 'м'),
+            Trigram('а', 'м', 'а'),
+            Trigram('р', 'ы', ' '),
+            Trigram('н', ' ', 'и'),
+            Trigram('ә', 'р', 'а'),
+            Trigram('н', 'м', 'а'),
+            Trigram('ы', 'н', 'д'),
+            Trigram('и', 'н', 'с'),
+            Trigram(' ', 'ө', 'з'),
+            Trigram('а', 'н', 'ы'),
+            Trigram('а', 'л', 'а'),
+            Trigram(' ', 'а', 'л'),
+            Trigram('и', 'к', ' '),
+            Trigram('ә', ' ', 'д'),
+            Trigram('л', 'ә', 'т'),
+            Trigram('и', 'р', 'л'),
+            Trigram('и', 'л', ' '),
+            Trigram(' ', 'д', 'и'),
+            Trigram('б', 'и', 'л'),
+            Trigram('ы', 'ғ', 'ы'),
+            Trigram('л', 'и', ' '),
+            Trigram('а', ' ', 'б'),
+            Trigram('ә', 'л', 'ә'),
+            Trigram('д', 'и', 'л'),
+            Trigram('ә', ' ', 'е'),
+            Trigram('у', 'н', 'м'),
+            Trigram('а', 'л', 'ы'),
+            Trigram('м', 'ү', 'д'),
+            Trigram(' ', 'с', 'ә'),
+            Trigram('н', 'ы', ' '),
+            Trigram('ә', ' ', 'и'),
+            Trigram('н', ' ', 'в'),
+            Trigram('ы', 'г', ' '),
+            Trigram('н', 'л', 'а'),
+            Trigram('ү', 'д', 'а'),
+            Trigram('а', 'с', 'и'),
+            Trigram('и', 'л', 'и'),
+            Trigram(' ', 'д', 'ә'),
+            Trigram('н', 'с', 'а'),
+            Trigram('с', 'а', 'н'),
+            Trigram('у', 'г', 'л'),
+            Trigram('у', 'г', ' '),
+            Trigram('ә', 'т', 'л'),
+            Trigram('ә', ' ', 'о'),
+            Trigram('х', 'с', 'и'),
+            Trigram(' ', 'һ', 'е'),
+            Trigram('о', 'л', 'а'),
+            Trigram('к', 'и', 'л'),
+            Trigram('е', 'ј', 'н'),
+            Trigram('т', 'ә', 'р'),
+            Trigram('ј', 'и', 'н'),
+            Trigram(' ', 'б', 'у'),
+            Trigram('м', 'и', ' '),
+            Trigram('м', 'ә', 'с'),
+            Trigram('д', 'ы', 'р'),
+            Trigram('һ', 'ә', 'м'),
+            Trigram(' ', 'д', 'а'),
+            Trigram('м', 'и', 'н'),
+            Trigram('и', 'ш', ' '),
+            Trigram(' ', 'һ', 'а'),
+            Trigram(' ', 'к', 'и'),
+            Trigram('у', ' ', 'в'),
+            Trigram('л', 'а', 'н'),
+            Trigram('ә', 'н', 'и'),
+            Trigram(' ', 'а', 'с'),
+            Trigram('х', 'а', 'л'),
+            Trigram('б', 'у', ' '),
+            Trigram('л', 'ы', 'ғ'),
+            Trigram('р', ' ', 'в'),
+            Trigram(' ', 'е', 'д'),
+            Trigram('ј', 'а', 'н'),
+            Trigram('р', 'ә', ' '),
+            Trigram('һ', 'е', 'ч'),
+            Trigram('а', 'л', 'г'),
+            Trigram(' ', 'т', 'а'),
+            Trigram('е', 'ч', ' '),
+            Trigram('и', ' ', 'с'),
+            Trigram('ы', ' ', 'һ'),
+            Trigram('с', 'и', 'а'),
+            Trigram('о', 'с', 'и'),
+            Trigram('с', 'о', 'с'),
+            Trigram('ф', 'и', 'ә'),
+            Trigram('г', ' ', 'һ'),
+            Trigram('а', 'ф', 'и'),
+            Trigram('к', 'и', 'м'),
+            Trigram('д', 'а', 'ф'),
+            Trigram(' ', 'ә', 'с'),
+            Trigram('ә', ' ', 'г'),
+            Trigram(' ', 'и', 'ш'),
+            Trigram('н', ' ', 'ә'),
+            Trigram('и', 'ј', 'и'),
+            Trigram('ы', 'г', 'л'),
+            Trigram('ә', 'м', 'ә'),
+            Trigram('ы', ' ', 'о'),
+            Trigram('ә', 'д', 'ә'),
+            Trigram('ә', 'с', 'а'),
+            Trigram(' ', 'с', 'о'),
+            Trigram('а', ' ', 'г'),
+            Trigram('л', 'ы', 'д'),
+            Trigram('и', 'л', 'л'),
+            Trigram('м', 'и', 'л'),
+            Trigram('а', ' ', 'һ'),
+            Trigram('ы', 'д', 'ы'),
+            Trigram('с', 'а', 'с'),
+            Trigram('л', 'ы', ' '),
+            Trigram('и', 'с', 'т'),
+            Trigram(' ', 'и', 'с'),
+            Trigram('и', 'ф', 'а'),
+            Trigram('м', 'ә', 'з'),
+            Trigram('ы', 'р', ' '),
+            Trigram('ј', 'а', 'р'),
+            Trigram('т', 'л', 'ә'),
+            Trigram('л', 'и', 'ј'),
+            Trigram('т', 'ү', 'н'),
+            Trigram('и', 'н', 'а'),
+            Trigram('ә', ' ', 'т'),
+            Trigram('с', 'и', 'ј'),
+            Trigram('а', 'л', ' '),
+            Trigram('р', 'и', 'л'),
+            Trigram(' ', 'б', 'ү'),
+            Trigram('и', 'ә', ' '),
+            Trigram('б', 'ү', 'т'),
+            Trigram(' ', 'ү', 'ч'),
+            Trigram('ү', 'т', 'ү'),
+            Trigram('ө', 'з', ' '),
+            Trigram('о', 'н', 'у'),
+            Trigram(' ', 'м', 'и'),
+            Trigram('и', 'ј', 'а'),
+            Trigram(' ', 'н', 'ә'),
+            Trigram('а', 'д', 'ә'),
+            Trigram('м', 'а', 'н'),
+            Trigram('ү', 'ч', 'ү'),
+            Trigram('ч', 'ү', 'н'),
+            Trigram('с', 'е', 'ч'),
+            Trigram('ы', 'л', 'ы'),
+            Trigram('т', ' ', 'в'),
+            Trigram(' ', 'с', 'е'),
+            Trigram('и', 'а', 'л'),
+            Trigram('д', 'а', 'х'),
+            Trigram('с', 'и', 'л'),
+            Trigram('е', 'д', 'и'),
+            Trigram('н', ' ', 'е'),
+            Trigram('ә', 'ј', 'и'),
+            Trigram('а', 'х', 'и'),
+            Trigram('х', 'и', 'л'),
+            Trigram(' ', 'ҹ', 'ә'),
+            Trigram('м', 'и', 'ј'),
+            Trigram('м', 'ә', 'н'),
+            Trigram('р', ' ', 'а'),
+            Trigram('ә', 'з', ' '),
+            Trigram('а', ' ', 'в'),
+            Trigram('и', 'л', 'д'),
+            Trigram('и', ' ', 'һ'),
+            Trigram('т', 'ә', 'һ'),
+            Trigram('ә', 'һ', 'с'),
+            Trigram('ы', ' ', 'в'),
+            Trigram('һ', 'с', 'и'),
+            Trigram('в', 'а', 'р'),
+            Trigram('ш', 'ә', 'р'),
+            Trigram('а', 'б', 'ә'),
+            Trigram('г', 'у', ' '),
+            Trigram('р', 'а', 'б'),
+            Trigram('а', 'ј', 'а'),
+            Trigram('з', ' ', 'һ'),
+            Trigram('а', 'м', 'ә'),
+            Trigram('т', 'а', 'м'),
+            Trigram('ғ', 'ы', 'н'),
+            Trigram('а', 'д', ' '),
+            Trigram('у', 'ғ', 'у'),
+            Trigram('н', ' ', 'д'),
+            Trigram('м', 'ә', 'һ'),
+            Trigram('т', 'ә', 'м'),
+            Trigram(' ', 'н', 'и'),
+            Trigram('и', ' ', 'т'),
+            Trigram(' ', 'х', 'а'),
+        ],
+    ),
     (
         Lang::Bel,
         &[
@@ -12521,6 +13438,7 @@ pub static CYRILLIC_LANGS: LangProfileList = &[
 ];
 
 /// Languages for script Arabic
+#[cfg(feature = "script-arabic")]
 pub static ARABIC_LANGS: LangProfileList = &[
     (
         Lang::Ara,
@@ -13440,6 +14358,7 @@ pub static ARABIC_LANGS: LangProfileList = &[
 ];
 
 /// Languages for script Devanagari
+#[cfg(feature = "script-devanagari")]
 pub static DEVANAGARI_LANGS: LangProfileList = &[
     (
         Lang::Hin,
@@ -14359,6 +15278,7 @@ pub static DEVANAGARI_LANGS: LangProfileList = &[
 ];
 
 /// Languages for script Hebrew
+#[cfg(feature = "script-hebrew")]
 pub static HEBREW_LANGS: LangProfileList = &[
     (
         Lang::Heb,
@@ -1,9 +1,11 @@
+use alloc::vec::Vec;
+
 use hashbrown::HashMap;
 
 use super::Trigram;
 use super::TEXT_TRIGRAMS_SIZE;
 use crate::core::LowercaseText;
-use crate::utils::is_stop_char;
+use crate::utils::is_ignored_char;
 
 const MAX_INITIAL_HASH_CAPACITY: usize = 2048;
 
@@ -12,12 +14,15 @@ pub struct TrigramsWithPositions {
     pub(crate) trigram_positions: HashMap<Trigram, u32>,
 }
 
-pub fn get_trigrams_with_positions(text: &LowercaseText) -> TrigramsWithPositions {
+pub fn get_trigrams_with_positions(
+    text: &LowercaseText,
+    settings: TrigramSettings,
+) -> TrigramsWithPositions {
     let CountResult {
         total_trigrams,
         trigram_occurances,
-    } = count(text);
-    let trigram_positions = trigram_occurances_to_positions(trigram_occurances);
+    } = count(text, settings);
+    let trigram_positions = trigram_occurances_to_positions(trigram_occurances.into_iter());
     TrigramsWithPositions {
         _total_trigrams: total_trigrams,
         trigram_positions,
@@ -26,11 +31,10 @@ pub fn get_trigrams_with_positions(text: &LowercaseText) -> TrigramsWithPosition
 
 #[allow(clippy::unnecessary_sort_by)]
 fn trigram_occurances_to_positions(
-    trigram_occurances: HashMap<Trigram, u32>,
+    trigram_occurances: impl Iterator<Item = (Trigram, u32)>,
 ) -> HashMap<Trigram, u32> {
     // Sort in descending order by number of occurrences and trigrams
     let mut count_vec: Vec<_> = trigram_occurances
-        .into_iter()
         .map(|(trigram, count)| (count, trigram))
         .collect();
     count_vec.sort_by(|a, b| b.cmp(a));
@@ -43,47 +47,150 @@ fn trigram_occurances_to_positions(
         .collect()
 }
 
+/// Extract trigrams of a text the same way it's done for detection, along with
+/// the number of their occurrences. Trigrams are sorted by the number of occurrences
+/// in descending order (trigrams with the same number of occurrences are sorted
+/// in descending order too, the same way as in the text profile used by detection).
+///
+/// The text is normalized before the extraction:
+/// * all characters are lowercased;
+/// * ASCII punctuation, digits, spaces and control characters are treated as spaces;
+/// * the text is surrounded by spaces, so the first and the last words get trigrams
+///   that start or end with a space (e.g. `" he"` and `"lo "` for `"hello"`);
+/// * sequences of spaces count as one, so no trigram has two spaces next to each other.
+///
+/// # Example
+/// ```
+/// use whatlang::{extract_trigrams, Trigram};
+/// let trigrams = extract_trigrams("Yes, yes!");
+/// assert_eq!(trigrams[0], (Trigram::new('y', 'e', 's'), 2));
+/// assert_eq!(trigrams.len(), 3);
+/// ```
+pub fn extract_trigrams(text: &str) -> Vec<(Trigram, u32)> {
+    let CountResult {
+        trigram_occurances, ..
+    } = count(&LowercaseText::new(text), TrigramSettings::default());
+    let mut trigrams: Vec<_> = trigram_occurances.into_iter().collect();
+    trigrams.sort_by_key(|&(trigram, count)| core::cmp::Reverse((count, trigram)));
+    trigrams
+}
+
 struct CountResult {
     total_trigrams: u32,
     trigram_occurances: HashMap<Trigram, u32>,
 }
 
-fn count(text: &LowercaseText) -> CountResult {
+fn count(text: &LowercaseText, settings: TrigramSettings) -> CountResult {
     let hash_capacity = calculate_initial_hash_capacity(text);
-    let mut trigram_occurances: HashMap<Trigram, u32> = HashMap::with_capacity(hash_capacity);
-    let mut total_trigrams = 0;
-
-    // iterate through the string and count trigrams
-    let mut chars_iter = text
-        .chars()
-        .map(to_trigram_char)
-        //.flat_map(char::to_lowercase)
-        .chain(Some(' '));
-    let mut c1 = ' ';
-    // unwrap is safe, because we always chain a space character on the end of the iterator
-    let mut c2 = chars_iter.next().unwrap();
-    for cur_char in chars_iter {
-        let c3 = cur_char;
-        if !(c2 == ' ' && (c1 == ' ' || c3 == ' ')) {
-            let trigram = Trigram(c1, c2, c3);
-            let count = trigram_occurances.entry(trigram).or_insert(0);
+    let mut counter = TrigramCounter::new(hash_capacity, settings);
+    counter.push_text(text);
+    counter.finish()
+}
+
+// How a text is split into trigrams,
+// see `Options::set_boundary_padding` and `Options::set_ignore_digits`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TrigramSettings {
+    pub(crate) boundary_padding: bool,
+    pub(crate) ignore_digits: bool,
+}
+
+impl Default for TrigramSettings {
+    fn default() -> Self {
+        Self {
+            boundary_padding: true,
+            ignore_digits: false,
+        }
+    }
+}
+
+// Counts trigrams of a text that may be given in multiple chunks.
+// The text is surrounded by spaces, so the last trigram (that ends with a space)
+// is pending until the text is over.
+// Without boundary padding trigrams that have a space are skipped.
+#[derive(Debug, Clone)]
+pub(crate) struct TrigramCounter {
+    total_trigrams: u32,
+    trigram_occurances: HashMap<Trigram, u32>,
+    settings: TrigramSettings,
+    c1: char,
+    c2: char,
+}
+
+impl TrigramCounter {
+    pub(crate) fn new(capacity: usize, settings: TrigramSettings) -> Self {
+        Self {
+            total_trigrams: 0,
+            trigram_occurances: HashMap::with_capacity(capacity),
+            settings,
+            c1: ' ',
+            c2: ' ',
+        }
+    }
+
+    pub(crate) fn push_text(&mut self, text: &LowercaseText) {
+        let ignore_digits = self.settings.ignore_digits;
+        for ch in text.chars() {
+            self.push(to_trigram_char(ch, ignore_digits));
+        }
+    }
+
+    fn push(&mut self, c3: char) {
+        let (c1, c2) = (self.c1, self.c2);
+        let is_skipped = if self.settings.boundary_padding {
+            c2 == ' ' && (c1 == ' ' || c3 == ' ')
+        } else {
+            c1 == ' ' || c2 == ' ' || c3 == ' '
+        };
+        if !is_skipped {
+            let count = self
+                .trigram_occurances
+                .entry(Trigram(c1, c2, c3))
+                .or_insert(0);
             *count += 1;
-            total_trigrams += 1;
+            self.total_trigrams += 1;
         }
-        c1 = c2;
-        c2 = c3;
+        self.c1 = c2;
+        self.c2 = c3;
     }
 
-    CountResult {
-        total_trigrams,
-        trigram_occurances,
+    fn pending_trigram(&self) -> Option<Trigram> {
+        if self.c2 == ' ' || !self.settings.boundary_padding {
+            None
+        } else {
+            Some(Trigram(self.c1, self.c2, ' '))
+        }
+    }
+
+    // Positions of trigrams of the text pushed so far, as if it was over.
+    pub(crate) fn trigram_positions(&self) -> HashMap<Trigram, u32> {
+        let pending = self.pending_trigram();
+        let occurances = self.trigram_occurances.iter().map(|(&trigram, &count)| {
+            if Some(trigram) == pending {
+                (trigram, count + 1)
+            } else {
+                (trigram, count)
+            }
+        });
+        let new_pending = pending
+            .filter(|trigram| !self.trigram_occurances.contains_key(trigram))
+            .map(|trigram| (trigram, 1));
+        trigram_occurances_to_positions(occurances.chain(new_pending))
+    }
+
+    fn finish(mut self) -> CountResult {
+        self.push(' ');
+        CountResult {
+            total_trigrams: self.total_trigrams,
+            trigram_occurances: self.trigram_occurances,
+        }
     }
 }
 
 // Convert punctuations and digits to a space.
 #[inline]
-fn to_trigram_char(ch: char) -> char {
-    if is_stop_char(ch) {
+fn to_trigram_char(ch: char, ignore_digits: bool) -> char {
+    if is_ignored_char(ch, ignore_digits) {
         ' '
     } else {
         ch
@@ -107,13 +214,13 @@ mod tests {
 
     fn assert_valuable_trigram_chars(chars: &[char]) {
         for &ch in chars.iter() {
-            assert_eq!(to_trigram_char(ch), ch);
+            assert_eq!(to_trigram_char(ch, false), ch);
         }
     }
 
     fn assert_not_valuable_trigram_chars(chars: &[char]) {
         for &ch in chars.iter() {
-            assert_eq!(to_trigram_char(ch), ' ');
+            assert_eq!(to_trigram_char(ch, false), ' ');
         }
     }
 
@@ -137,9 +244,9 @@ mod tests {
         let CountResult {
             total_trigrams: _,
             trigram_occurances,
-        } = count(&lowercase_text);
+        } = count(&lowercase_text, TrigramSettings::default());
         for &(trigram_str, expected_n) in pairs.iter() {
-            let chars: Vec<char> = trigram_str.clone().chars().collect();
+            let chars: Vec<char> = trigram_str.chars().collect();
             let trigram = Trigram(chars[0], chars[1], chars[2]);
             let actual_n = trigram_occurances[&trigram];
             assert_eq!(
@@ -171,13 +278,117 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_count_without_boundary_padding() {
+        let trigrams = |text, boundary_padding| {
+            let settings = TrigramSettings {
+                boundary_padding,
+                ignore_digits: false,
+            };
+            let mut trigrams: Vec<Trigram> = count(&LowercaseText::new(text), settings)
+                .trigram_occurances
+                .into_iter()
+                .map(|(trigram, _)| trigram)
+                .collect();
+            trigrams.sort();
+            trigrams
+        };
+
+        assert_eq!(
+            trigrams("Hi there", true),
+            vec![
+                Trigram(' ', 'h', 'i'),
+                Trigram(' ', 't', 'h'),
+                Trigram('e', 'r', 'e'),
+                Trigram('h', 'e', 'r'),
+                Trigram('h', 'i', ' '),
+                Trigram('i', ' ', 't'),
+                Trigram('r', 'e', ' '),
+                Trigram('t', 'h', 'e'),
+            ]
+        );
+        // "hi" is too short to have a trigram without padding
+        assert_eq!(
+            trigrams("Hi there", false),
+            vec![
+                Trigram('e', 'r', 'e'),
+                Trigram('h', 'e', 'r'),
+                Trigram('t', 'h', 'e'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trigram_counter() {
+        let text = "Give - IT, give it... Yes";
+        let expected =
+            get_trigrams_with_positions(&LowercaseText::new(text), TrigramSettings::default())
+                .trigram_positions;
+
+        // Chunks are split in the middle of words
+        let mut counter = TrigramCounter::new(0, TrigramSettings::default());
+        for chunk in ["Gi", "ve - I", "T, give i", "t... Y", "es"] {
+            counter.push_text(&LowercaseText::new(chunk));
+        }
+        assert_eq!(counter.trigram_positions(), expected);
+
+        let settings = TrigramSettings {
+            boundary_padding: false,
+            ignore_digits: false,
+        };
+        let expected =
+            get_trigrams_with_positions(&LowercaseText::new(text), settings).trigram_positions;
+        let mut counter = TrigramCounter::new(0, settings);
+        for chunk in ["Gi", "ve - I", "T, give i", "t... Y", "es"] {
+            counter.push_text(&LowercaseText::new(chunk));
+        }
+        assert_eq!(counter.trigram_positions(), expected);
+
+        // Positions can be taken at any moment
+        let mut counter = TrigramCounter::new(0, TrigramSettings::default());
+        counter.push_text(&LowercaseText::new("yes y"));
+        let positions = counter.trigram_positions();
+        assert_eq!(positions.len(), 5);
+        assert_eq!(positions[&Trigram(' ', 'y', ' ')], 4);
+        counter.push_text(&LowercaseText::new("es"));
+        let positions = counter.trigram_positions();
+        assert_eq!(positions.len(), 4);
+        assert!(!positions.contains_key(&Trigram(' ', 'y', ' ')));
+        assert_eq!(positions[&Trigram('y', 'e', 's')], 0);
+    }
+
+    #[test]
+    fn test_extract_trigrams() {
+        assert_eq!(extract_trigrams(""), vec![]);
+        assert_eq!(extract_trigrams("12, 34"), vec![]);
+        assert_eq!(
+            extract_trigrams("Yes, YES! No."),
+            vec![
+                (Trigram('y', 'e', 's'), 2),
+                (Trigram('e', 's', ' '), 2),
+                (Trigram(' ', 'y', 'e'), 2),
+                (Trigram('n', 'o', ' '), 1),
+                (Trigram(' ', 'n', 'o'), 1),
+            ]
+        );
+
+        // The same trigrams are used by detection
+        let text = "Give - IT, give it... Yes";
+        let positions =
+            get_trigrams_with_positions(&LowercaseText::new(text), TrigramSettings::default())
+                .trigram_positions;
+        for (i, (trigram, _)) in extract_trigrams(text).into_iter().enumerate() {
+            assert_eq!(positions[&trigram], i as u32);
+        }
+    }
+
     #[test]
     fn test_get_trigrams_with_positions() {
         let lowercase_text = LowercaseText::new("xaaaaabbbb    d");
         let TrigramsWithPositions {
             _total_trigrams,
             trigram_positions,
-        } = get_trigrams_with_positions(&lowercase_text);
+        } = get_trigrams_with_positions(&lowercase_text, TrigramSettings::default());
 
         assert_eq!(trigram_positions[&Trigram('a', 'a', 'a')], 0);
         assert_eq!(trigram_positions[&Trigram('b', 'b', 'b')], 1);
@@ -1,9 +1,20 @@
 // Is it space, punctuation or digit?
 // Stop character is a character that does not give any value for script
 // or language detection.
+// Zero-width characters (space, non-joiner, joiner) and BOM are invisible,
+// but often come with texts copied from various sources.
 #[inline]
 pub fn is_stop_char(ch: char) -> bool {
-    matches!(ch, '\u{0000}'..='\u{0040}' | '\u{005B}'..='\u{0060}' | '\u{007B}'..='\u{007E}')
+    matches!(ch,
+        '\u{0000}'..='\u{0040}' | '\u{005B}'..='\u{0060}' | '\u{007B}'..='\u{007E}' |
+        '\u{200B}'..='\u{200D}' | '\u{FEFF}'
+    )
+}
+
+// Is it a stop char or a digit (of any script, e.g. Arabic-Indic) when digits are ignored?
+#[inline]
+pub fn is_ignored_char(ch: char, ignore_digits: bool) -> bool {
+    is_stop_char(ch) || (ignore_digits && ch.is_numeric())
 }
 
 #[cfg(test)]
@@ -19,6 +30,9 @@ mod tests {
         assert!(is_stop_char('-'));
         assert!(is_stop_char('9'));
         assert!(is_stop_char('0'));
+        assert!(is_stop_char('\u{FEFF}')); // BOM
+        assert!(is_stop_char('\u{200B}')); // zero-width space
+        assert!(is_stop_char('\u{200D}')); // zero-width joiner
 
         // non-stop chars
         assert!(!is_stop_char('a'));
@@ -28,4 +42,18 @@ mod tests {
         assert!(!is_stop_char('я'));
         assert!(!is_stop_char('А')); // cyrillic A
     }
+
+    #[test]
+    fn test_is_ignored_char() {
+        assert!(is_ignored_char(' ', false));
+        assert!(is_ignored_char('7', false));
+        assert!(!is_ignored_char('٧', false)); // Arabic-Indic 7
+        assert!(!is_ignored_char('a', false));
+
+        assert!(is_ignored_char(' ', true));
+        assert!(is_ignored_char('7', true));
+        assert!(is_ignored_char('٧', true));
+        assert!(is_ignored_char('७', true)); // Devanagari 7
+        assert!(!is_ignored_char('a', true));
+    }
 }
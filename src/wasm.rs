@@ -0,0 +1,37 @@
+use alloc::string::String;
+
+use crate::detect;
+
+/// Detect a language and return `Info` as a JSON string, or `"null"` if nothing is detected.
+/// The JSON has the same shape as the serde representation of `Info`:
+/// the language is its ISO 639-3 code and the script is its lowercase name.
+/// It's meant for WebAssembly bindings, so `Lang` and `Script` don't need to be exposed to JS.
+///
+/// # Example
+/// ```
+/// use whatlang::detect_json;
+///
+/// let json = detect_json("Та нічого, все нормально. А в тебе як?");
+/// assert!(json.starts_with(r#"{"script":"cyrillic","lang":"ukr","confidence":"#));
+/// assert_eq!(detect_json("123"), "null");
+/// ```
+pub fn detect_json(text: &str) -> String {
+    // Serialization of Info can not fail: it has no maps and no custom fallible impls
+    serde_json::to_string(&detect(text)).expect("Info is always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Info;
+
+    #[test]
+    fn test_detect_json() {
+        let text = "Та нічого, все нормально. А в тебе як?";
+        let json = detect_json(text);
+        let info: Info = serde_json::from_str(&json).unwrap();
+        assert_eq!(info, detect(text).unwrap());
+
+        assert_eq!(detect_json(""), "null");
+    }
+}
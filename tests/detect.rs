@@ -1,12 +1,14 @@
 extern crate serde_json;
 extern crate whatlang;
 
-use whatlang::{detect, detect_lang, Lang, Script};
-
-use std::collections::HashMap;
+use whatlang::{detect, Lang, Script};
 
 #[test]
+#[cfg(feature = "all-scripts")]
 fn test_with_multiple_examples() {
+    use std::collections::HashMap;
+    use whatlang::detect_lang;
+
     let example_data = include_str!("examples.json");
 
     let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();